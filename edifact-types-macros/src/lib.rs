@@ -0,0 +1,1263 @@
+extern crate proc_macro;
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericArgument, PathArguments, Type};
+
+#[proc_macro_derive(DisplayInnerSegment)]
+pub fn display_inner(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse_macro_input!(input as DeriveInput);
+    let toks = generate_inner_display(&ast).unwrap_or_else(|err| err.to_compile_error());
+    toks.into()
+}
+
+#[proc_macro_derive(DisplayOuterSegment)]
+pub fn display_outer(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse_macro_input!(input as DeriveInput);
+    let toks = generate_outer_display(&ast).unwrap_or_else(|err| err.to_compile_error());
+    toks.into()
+}
+
+#[proc_macro_derive(DisplayEdifact)]
+pub fn display_edifact(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse_macro_input!(input as DeriveInput);
+    let toks = generate_edifact(&ast).unwrap_or_else(|err| err.to_compile_error());
+    toks.into()
+}
+
+#[proc_macro_derive(DisplayEdifactSg)]
+pub fn display_edifact_sg(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse_macro_input!(input as DeriveInput);
+    let toks = generate_edifact_sg(&ast).unwrap_or_else(|err| err.to_compile_error());
+    toks.into()
+}
+
+fn generate_inner_display(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let output = gen_types(ast);
+    let xml = gen_xml(ast)?;
+    Ok(quote! {
+        #xml
+
+        impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt<'x>(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let delimiters = crate::util::delimiters();
+                let mut str: Vec<String> = vec![];
+                #(#output)*
+                // skip empty trailing components
+                while str.last().is_some_and(|s| s.is_empty()) {
+                    str.pop();
+                }
+                let joined = str.join(&delimiters.component.to_string());
+                write!(f, "{}", joined)
+            }
+        }
+    })
+}
+
+fn generate_outer_display(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let output = gen_types(ast);
+    let s = format_ident!("{}", name).to_string().to_uppercase();
+    let xml = gen_xml(ast)?;
+    Ok(quote! {
+        #xml
+
+        impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt<'x>(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let delimiters = crate::util::delimiters();
+                let mut str: Vec<String> = vec![];
+                str.push(#s.to_string());
+                #(#output)*
+                // skip empty trailing elements
+                while str.last().is_some_and(|s| s.is_empty()) {
+                    str.pop();
+                }
+                if str.len() > 1 {
+                    write!(f, "{}", str.join(&delimiters.element.to_string()))
+                }else{
+                    write!(f, "")
+                }
+            }
+        }
+    })
+}
+
+fn generate_edifact(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let output = gen_types(ast);
+    let xml = gen_xml(ast)?;
+    let root = name.to_string();
+    Ok(quote! {
+        #xml
+
+        #[cfg(feature = "xml")]
+        impl #impl_generics crate::xml::XmlMessage for #name #ty_generics #where_clause {
+            const ROOT: &'static str = #root;
+        }
+
+        impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt<'x>(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut str: Vec<String> = vec![];
+                #(#output)*
+                // filter empty lines
+                let str: Vec<String> = str
+                    .iter()
+                    .map(|v| v.clone())
+                    .filter(|s| !s.is_empty())
+                    .collect();
+                let delimiters = crate::util::delimiters();
+                let joined = str.join(&format!("{}\n", delimiters.segment));
+                write!(f, "{}{}", joined, delimiters.segment)
+            }
+        }
+
+        impl #impl_generics crate::control::Finalize for #name #ty_generics #where_clause {
+            fn finalize(&mut self) {
+                self.unt._020 = self.unh._010.clone();
+                // UNT without any value would not be rendered and counted
+                self.unt._010 = "0".to_string();
+                let count = crate::control::segment_count(&self.to_string());
+                self.unt._010 = count.to_string();
+            }
+        }
+    })
+}
+
+fn generate_edifact_sg(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let output = gen_types(ast);
+    let xml = gen_xml(ast)?;
+    Ok(quote! {
+        #xml
+
+        impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt<'x>(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut str: Vec<String> = vec![];
+                #(#output)*
+                // filter empty lines
+                let str: Vec<String> = str
+                    .iter()
+                    .map(|v| v.clone())
+                    .filter(|s| !s.is_empty())
+                    .collect();
+                let delimiters = crate::util::delimiters();
+                let joined = str.join(&format!("{}\n", delimiters.segment));
+                write!(f, "{}", joined)
+            }
+        }
+    })
+}
+
+fn gen_types(ast: &DeriveInput) -> Vec<TokenStream> {
+    let x = &ast.data;
+    let mut output = vec![];
+    if let Data::Struct(s) = x {
+        let f = &s.fields;
+        for o in f {
+            let id = o.ident.clone().unwrap();
+            let t = &o.ty;
+            if let syn::Type::Path(p) = t {
+                let s = &p.path.segments;
+                let w = &s.first().unwrap().ident;
+                let ty = w.to_string();
+                // values are escaped, types do it themselves
+                let is_string = match &s.first().unwrap().arguments {
+                    PathArguments::AngleBracketed(args) => matches!(
+                        args.args.first(),
+                        Some(GenericArgument::Type(Type::Path(t))) if t.path.is_ident("String")
+                    ),
+                    _ => ty == "String",
+                };
+                match ty.as_str() {
+                    "Option" if is_string => {
+                        let ts = quote! {
+                            str.push(self.#id.as_deref().map_or(String::new(), crate::util::escape));
+                        };
+                        output.push(ts);
+                    }
+                    "String" => {
+                        let ts = quote! {
+                            str.push(crate::util::escape(&self.#id));
+                        };
+                        output.push(ts);
+                    }
+                    "Vec" => {
+                        let ts = quote! {
+                            if self.#id.is_empty() {
+                                str.push("".to_string());
+                            }else{
+                                self.#id.iter().for_each(|x| str.push(format!("{}",x)));
+                            }
+                        };
+                        output.push(ts);
+                    }
+                    "Option" => {
+                        let ts = quote! {
+                            str.push(self.#id.as_ref().map_or("".to_string(),|x| format!("{}",x)));
+                        };
+                        output.push(ts);
+                    }
+                    _ => {
+                        let ts = quote! {
+                            str.push(format!("{}",self.#id));
+                        };
+                        output.push(ts);
+                    }
+                }
+            }
+        }
+    }
+    output
+}
+
+// #[cfg(feature = "xml")]
+// impl crate::xml::ToXml for DTM {
+//     fn write_xml(&self, name: &str, out: &mut String) {
+//         out.push('<');
+//         ..
+//         let value = &self._010;
+//         let xml_name = "C507";
+//         crate::xml::ToXml::write_xml(value, xml_name, out);
+//         ..
+//     }
+// }
+//
+// #[cfg(feature = "xml")]
+// impl crate::xml::FromXml for C507 {
+//     fn read_xml(element: &crate::xml::Element) -> Result<Self, crate::xml::XmlError> {
+//         let mut children = crate::xml::Children::new(element);
+//         let e = children.required("E2005")?;
+//         let _010 = (crate::xml::code::<_2005>(e)).map_err(|err| err.within("E2005"))?;
+//         let _020 = match children.next("E2380") { .. };
+//         ..
+//         children.finish()?;
+//         Ok(C507 { _010, _020, _030 })
+//     }
+// }
+/// Generates `ToXml` and `FromXml`. Segment groups are named `SG4`, segments and composites
+/// by their type, code lists by their number (`E2005`) and text by `#[edifact(element = "2380")]`.
+fn gen_xml(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let mut fields = vec![];
+    let mut writes = vec![];
+    let mut reads = vec![];
+    let mut ids = vec![];
+    if let Data::Struct(s) = &ast.data {
+        for field in &s.fields {
+            let Some(id) = &field.ident else {
+                continue;
+            };
+            let Type::Path(tp) = &field.ty else {
+                continue;
+            };
+            let outer = &tp.path.segments[0];
+            let inner = match &outer.arguments {
+                PathArguments::AngleBracketed(args) => match args.args.first() {
+                    Some(GenericArgument::Type(Type::Path(t))) => t.path.segments[0].ident.clone(),
+                    _ => outer.ident.clone(),
+                },
+                _ => outer.ident.clone(),
+            };
+            let inner_name = inner.to_string();
+            let (xml_name, write, read) = if inner_name == "String" {
+                let Some(element) = field_attr(field)?.element else {
+                    return Err(syn::Error::new_spanned(
+                        id,
+                        "text needs the number of its data element, e.g. #[edifact(element = \"3036\")]",
+                    ));
+                };
+                (
+                    format!("E{element}"),
+                    quote! { crate::xml::write_text(out, xml_name, value) },
+                    quote! { Ok::<_, crate::xml::XmlError>(e.text.clone()) },
+                )
+            } else if let Some(number) = inner_name.strip_prefix('_') {
+                (
+                    format!("E{number}"),
+                    quote! { crate::xml::write_text(out, xml_name, &value.to_string()) },
+                    quote! { crate::xml::code::<#inner>(e) },
+                )
+            } else {
+                let xml_name = match id.to_string().strip_prefix("segment_group_") {
+                    Some(number) => format!("SG{number}"),
+                    None => inner_name,
+                };
+                (
+                    xml_name,
+                    quote! { crate::xml::ToXml::write_xml(value, xml_name, out) },
+                    quote! { <#inner as crate::xml::FromXml>::read_xml(e) },
+                )
+            };
+            let read = quote! { (#read).map_err(|err| err.within(#xml_name))? };
+            fields.push((id.clone(), outer.ident.to_string(), xml_name, write, read));
+        }
+    }
+    for (idx, (id, outer, xml_name, write, read)) in fields.iter().enumerate() {
+        // an absent value is kept as empty element, a later field of the same name
+        // would be read in its place otherwise
+        let shared = fields[idx + 1..].iter().any(|f| &f.2 == xml_name);
+        match outer.as_str() {
+            "Vec" => {
+                writes.push(quote! {
+                    for value in &self.#id {
+                        let xml_name = #xml_name;
+                        #write;
+                    }
+                });
+                reads.push(quote! {
+                    let mut #id = vec![];
+                    while let Some(e) = children.next(#xml_name) {
+                        #id.push(#read);
+                    }
+                });
+            }
+            "Option" if shared => {
+                writes.push(quote! {
+                    match &self.#id {
+                        Some(value) => {
+                            let xml_name = #xml_name;
+                            #write;
+                        }
+                        None => crate::xml::write_empty(out, #xml_name),
+                    }
+                });
+                reads.push(quote! {
+                    let #id = match children.next(#xml_name) {
+                        Some(e) if e.is_empty() => None,
+                        Some(e) => Some(#read),
+                        None => None,
+                    };
+                });
+            }
+            "Option" => {
+                writes.push(quote! {
+                    if let Some(value) = &self.#id {
+                        let xml_name = #xml_name;
+                        #write;
+                    }
+                });
+                reads.push(quote! {
+                    let #id = match children.next(#xml_name) {
+                        Some(e) => Some(#read),
+                        None => None,
+                    };
+                });
+            }
+            _ => {
+                writes.push(quote! {
+                    let value = &self.#id;
+                    let xml_name = #xml_name;
+                    #write;
+                });
+                reads.push(quote! {
+                    let e = children.required(#xml_name)?;
+                    let #id = #read;
+                });
+            }
+        }
+        ids.push(id.clone());
+    }
+    Ok(quote! {
+        #[cfg(feature = "xml")]
+        impl #impl_generics crate::xml::ToXml for #name #ty_generics #where_clause {
+            fn write_xml(&self, name: &str, out: &mut String) {
+                out.push('<');
+                out.push_str(name);
+                out.push('>');
+                #(#writes)*
+                out.push_str("</");
+                out.push_str(name);
+                out.push('>');
+            }
+        }
+
+        #[cfg(feature = "xml")]
+        impl #impl_generics crate::xml::FromXml for #name #ty_generics #where_clause {
+            fn read_xml(element: &crate::xml::Element) -> Result<Self, crate::xml::XmlError> {
+                let mut children = crate::xml::Children::new(element);
+                #(#reads)*
+                children.finish()?;
+                Ok(#name { #(#ids),* })
+            }
+        }
+    })
+}
+
+#[proc_macro_derive(ParseInnerSegment)]
+pub fn parse_inner(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse_macro_input!(input as DeriveInput);
+    let toks = generate_inner_parse(&ast).unwrap_or_else(|err| err.to_compile_error());
+    toks.into()
+}
+
+fn generate_inner_parse(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let output = gen_inner_props(ast);
+    let prop_count = output.len();
+    Ok(quote! {
+        impl #impl_generics ::core::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let parts: Vec<&str> = s.split(':').collect();
+                if parts.len() > #prop_count {
+                    Err(ParseError {
+                        msg: "too many segments".to_string(),
+                        ..Default::default()
+                    })
+                } else {
+                    Ok(#name {
+                        #(#output)*
+                    })
+                }
+            }
+        }
+    })
+}
+
+fn gen_inner_props(ast: &DeriveInput) -> Vec<TokenStream> {
+    let x = &ast.data;
+    let mut output = vec![];
+    let mut idx: usize = 0;
+    if let Data::Struct(s) = x {
+        let f = &s.fields;
+        for o in f {
+            let id = o.ident.clone().unwrap();
+            let t = &o.ty;
+            if let syn::Type::Path(p) = t {
+                let s = &p.path.segments;
+                let w = &s.first().unwrap().ident;
+                let ty = w.to_string();
+                match ty.as_str() {
+                    "Option" => {
+                        let chunk = quote! {
+                            #id: parts.get(#idx).map(|x| x.to_string()),
+                        };
+                        output.push(chunk);
+                    }
+                    _ => {
+                        let chunk = quote! {
+                            #id: parts.get(#idx).map(|x| x.to_string()).unwrap_or_default(),
+                        };
+                        output.push(chunk);
+                    }
+                }
+            }
+            idx += 1;
+        }
+    }
+    output
+}
+
+#[proc_macro_derive(ParseElement)]
+pub fn parse_element(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let output = generate_element_parser(&input).unwrap_or_else(|err| err.to_compile_error());
+    #[cfg(feature = "debug")]
+    println!("{output}");
+    proc_macro::TokenStream::from(output)
+}
+
+fn generate_element_parser(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let tok = parse_all(ast, None);
+    let s = format_ident!("{}", name).to_string().to_uppercase();
+    let res = quote! {
+        impl<'a> crate::util::Parser<&'a str, #name, crate::util::Error<&'a str>> for #name {
+            fn parse(input: &'a str) -> crate::util::IResult<&'a str, #name> {
+                #[cfg(feature = "logging")]
+                log::debug!("Parser is inside {}", #s);
+                let (_, vars) = crate::util::parse_colon_section(input)?;
+                #[cfg(feature = "logging")]
+                log::debug!("Variables created {vars:?}");
+                let output = #name {
+                    #(#tok)*
+                };
+                Ok(("", output))
+            }
+        }
+    };
+    Ok(res)
+}
+
+#[proc_macro_derive(ParseSg)]
+pub fn parse_sg(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let output = generate_sg_parser(&input, true).unwrap_or_else(|err| err.to_compile_error());
+    proc_macro::TokenStream::from(output)
+}
+
+#[proc_macro_derive(ParseMsg)]
+pub fn parse_msg(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let output = generate_sg_parser(&input, false).unwrap_or_else(|err| err.to_compile_error());
+    #[cfg(feature = "debug")]
+    println!("{output}");
+    proc_macro::TokenStream::from(output)
+}
+
+fn generate_sg_parser(ast: &DeriveInput, is_sg: bool) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let mut lefties = vec![];
+    let mut attries = vec![];
+    if let Data::Struct(left_vec) = &ast.data {
+        if let Fields::Named(f) = &left_vec.fields {
+            for (idx, ff) in (&f.named).into_iter().enumerate() {
+                let left = match &ff.ident {
+                    Some(l) => l.clone(),
+                    None => Ident::new("", Span::call_site()),
+                };
+
+                // right side can be any of String, Struct, Enum, Option<..>, Vec<..>
+
+                let all = if let Type::Path(tyty) = &ff.ty {
+                    let inside = &tyty.path.segments[0];
+                    let inside_str = inside.ident.to_string();
+                    // First occurence, can be Option, Vec or Type
+                    match inside_str.as_str() {
+                        "Vec" | "Option" => {
+                            if let PathArguments::AngleBracketed(inside_optvec) = &inside.arguments
+                            {
+                                if let GenericArgument::Type(Type::Path(t)) = &inside_optvec.args[0]
+                                {
+                                    let ti = &t.path.segments[0].ident;
+                                    (
+                                        quote! {
+                                            #left
+                                        },
+                                        if inside_str == "Vec" {
+                                            // if we are in msg_type stage, or if vec is the first element of struct
+                                            // many0 will come back positive and the surroung element will not collaps
+                                            if !is_sg || idx != 0 {
+                                                quote! {
+                                                // let (outer_rest, dtm) = many0(DTM::parse)(outer_rest)?;
+                                                    let (outer_rest, #left) = nom::multi::many0(#ti::parse).parse(outer_rest)?;
+                                                }
+                                            } else {
+                                                quote! {
+                                                    let (outer_rest, #left) = nom::multi::many1(#ti::parse).parse(outer_rest)?;
+                                                }
+                                            }
+                                        } else {
+                                            quote! {
+                                                let (outer_rest, #left) = nom::combinator::opt(#ti::parse).parse(outer_rest)?;
+                                            }
+                                        },
+                                    )
+                                } else {
+                                    (quote! {}, quote! {})
+                                }
+                            } else {
+                                (quote! {}, quote! {})
+                            }
+                        }
+                        _ => {
+                            let i = inside.ident.clone();
+                            (
+                                quote! {
+                                    #left
+                                },
+                                quote! {
+                                    // let (outer_rest, loc) = LOC::parse(input)?;
+                                    let (outer_rest, #left) = #i::parse(outer_rest)?;
+                                },
+                            )
+                        }
+                    }
+                } else {
+                    (quote! {}, quote! {})
+                };
+                lefties.push(all.0);
+                attries.push(all.1);
+            }
+        }
+    };
+    let s = format_ident!("{}", name).to_string().to_uppercase();
+    let res = quote! {
+        // impl<'a> Parser<&'a str, IftminSg1, crate::util::Error<&'a str>> for IftminSg1 {
+        //     fn parse(input: &'a str) -> IResult<&'a str, IftminSg1> {
+        //         let (outer_rest, loc) = LOC::parse(input)?;
+        //         let (outer_rest, dtm) = many0(DTM::parse)(outer_rest)?;
+        //         Ok((outer_rest, IftminSg1 { loc, dtm }))
+        //     }
+        // }
+        impl<'a> crate::util::Parser<&'a str, #name, crate::util::Error<&'a str>> for #name {
+            fn parse(input: &'a str) -> crate::util::IResult<&'a str, #name> {
+                #[cfg(feature = "logging")]
+                log::debug!("Parser is inside {}", #s);
+                let outer_rest = input;
+                #(#attries)*
+                Ok((outer_rest, #name { #(#lefties),* }))
+            }
+        }
+    };
+    #[cfg(feature = "debug")]
+    println!("{res}");
+    Ok(res)
+}
+
+// impl<'a> Parser<&'a str, C002, crate::util::Error<&'a str>> for C002 {
+//     fn parse(input: &'a str) -> IResult<&'a str, C002> {
+//         let (_, vars) = crate::util::parse_colon_section(input)?;
+//         let output = C002 {
+//             _010: vars.first().map(|x| _1001::from_str(clean_num(x)).unwrap()),
+//             _020: vars.get(1).map(|x| _1131::from_str(clean_num(x)).unwrap()),
+//             _030: vars.get(2).map(|x| _3055::from_str(clean_num(x)).unwrap()),
+//             _040: vars.get(3).map(|x| x.to_string()),
+//         };
+//         Ok(("", output))
+//     }
+// }
+
+#[proc_macro_derive(ParseSegment)]
+pub fn parse_segment(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let output = generate_segment_parser(&input).unwrap_or_else(|err| err.to_compile_error());
+    #[cfg(feature = "debug")]
+    println!("{output}");
+    proc_macro::TokenStream::from(output)
+}
+
+// impl<'a> Parser<&'a str, COM, crate::util::Error<&'a str>> for COM {
+//     fn parse(input: &'a str) -> IResult<&'a str, COM> {
+//         let (output_rest, vars) = crate::util::parse_line(input, "COM")?;
+//         let output = COM {
+//             _010: vars.first().map(|x| C076::parse(x).unwrap().1).unwrap(),
+//         };
+//         Ok((output_rest, output))
+//     }
+// }
+
+fn generate_segment_parser(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let s = format_ident!("{}", name).to_string().to_uppercase();
+    let tok = parse_all(ast, Some(&s));
+    let res = quote! {
+        impl<'a> crate::util::Parser<&'a str, #name, crate::util::Error<&'a str>> for #name {
+            fn parse(input: &'a str) -> crate::util::IResult<&'a str, #name> {
+                #[cfg(feature = "logging")]
+                log::debug!("Parser is inside {}", #s);
+                let (output_rest, vars) = crate::util::parse_line(input, #s)?;
+                #[cfg(feature = "logging")]
+                log::debug!("Variables created {vars:?}");
+                #[cfg(feature = "logging")]
+                log::debug!("Left over string {output_rest:?}");
+                let output = #name {
+                    #(#tok)*
+                };
+                Ok((output_rest, output))
+            }
+        }
+    };
+    Ok(res)
+}
+
+/// Generates the field initializers, errors are returned as `nom::Err::Failure`
+/// with the position of the element. `tag` is set for segments, which adds
+/// the segment name to the position.
+fn parse_all(ast: &DeriveInput, tag: Option<&str>) -> Vec<TokenStream> {
+    let x = &ast.data;
+    let name = format!("{}", &ast.ident);
+    let within_tag = match tag {
+        Some(tag) => quote! { .within(#tag) },
+        None => quote! {},
+    };
+    let fail_within_tag = |inner: TokenStream| match tag {
+        Some(tag) => quote! { crate::util::fail_within(#inner, #tag) },
+        None => inner,
+    };
+    let mut output = vec![];
+    if let Data::Struct(s) = x {
+        let f = &s.fields;
+        for (idx, o) in f.into_iter().enumerate() {
+            // _010, _020, etc
+            let struct_field = o.ident.clone().unwrap();
+            let sf_string = struct_field.to_string();
+            let position = sf_string.trim_start_matches('_').to_string();
+            let fail_element = fail_within_tag(quote! { crate::util::fail_within(e, #position) });
+            let syn::Type::Path(tp) = &o.ty else {
+                panic!("Path type not found!")
+            };
+            let s = tp.path.segments.first().unwrap();
+            let opt_vec = s.ident.clone();
+            let ov_string = opt_vec.to_string();
+            let missing = quote! {
+                return Err(nom::Err::Failure(crate::util::Error::missing(input, #ov_string).within(#position)#within_tag))
+            };
+            match opt_vec.to_string().as_str() {
+                "Option" | "Vec" => {
+                    // List, String, Segment inside option or vec
+                    let mut inside_opt_vec: Ident = Ident::new("placeholder", Span::call_site());
+                    if let PathArguments::AngleBracketed(abga) = &s.arguments {
+                        if let GenericArgument::Type(syn::Type::Path(tp)) =
+                            abga.args.first().unwrap()
+                        {
+                            inside_opt_vec = tp.path.segments.first().unwrap().ident.clone();
+                        }
+                    }
+                    let iov_string = inside_opt_vec.to_string();
+
+                    // Can be String, _XXX (List), or CXXX,SXXX (Segment)
+                    if inside_opt_vec == "String" {
+                        output.push(quote! {
+                            #struct_field: vars.get(#idx).map(|x| crate::util::unescape(x)),
+                        });
+                    } else if inside_opt_vec.to_string().starts_with('_') {
+                        // List (types.rs)
+                        output.push(quote! {
+                                #struct_field: match vars.get(#idx).filter(|&f| !f.is_empty()) {
+                                    Some(x) => match #inside_opt_vec::from_str(clean_num(x)) {
+                                        // the code as received, not without its leading zeros
+                                        #[cfg(feature = "lenient-codes")]
+                                        Ok(#inside_opt_vec::Unknown(_)) => Some(#inside_opt_vec::Unknown(x.to_string())),
+                                        Ok(f) => Some(f),
+                                        Err(e) => {
+                                            #[cfg(feature = "logging")]
+                                            log::error!("Line: {input}\nFor struct {}, parsing optional list item {} failed. Enum {} encountered the following error: {}", #name, #sf_string, #iov_string, e);
+                                            return Err(nom::Err::Failure(crate::util::Error::unknown_code(*x, x, #iov_string).within(#position)#within_tag));
+                                        },
+                                    },
+                                    None => None,
+                                },
+                            });
+                    } else {
+                        // Segment or Element
+                        output.push(quote! {
+                                #struct_field: match vars.get(#idx).filter(|&f| !f.is_empty()) {
+                                    Some(x) => match #inside_opt_vec::parse(x) {
+                                        Ok((_,r)) => Some(r),
+                                        Err(e) => {
+                                            #[cfg(feature = "logging")]
+                                            log::error!("Line: {input}\nFor struct {}, parsing optional segment or element {} failed. Struct {} encountered the following error: {}", #name, #sf_string, #iov_string, e);
+                                            return Err(#fail_element);
+                                        },
+                                    },
+                                    None => None,
+                                },
+                            });
+                    }
+                }
+                "String" => {
+                    output.push(quote! {
+                        #struct_field: match vars.get(#idx).filter(|&f| !f.is_empty()).map(|x| crate::util::unescape(x)) {
+                            Some(f) => f,
+                            None => {
+                                #[cfg(feature = "logging")]
+                                log::error!("Line: {input}\nFor struct {}, parsing mandatory {}.to_string() was not found", #name, #sf_string);
+                                #missing;
+                            },
+                        },
+                    });
+                }
+                _ => {
+                    // Can be _XXX (List), or CXXX,SXXX (Segment)
+                    if opt_vec.to_string().starts_with('_') {
+                        // List (types.rs)
+                        output.push(quote! {
+                            #struct_field: match vars.get(#idx).filter(|&f| !f.is_empty()) {
+                                Some(x) => match #opt_vec::from_str(clean_num(x)) {
+                                    // the code as received, not without its leading zeros
+                                    #[cfg(feature = "lenient-codes")]
+                                    Ok(#opt_vec::Unknown(_)) => #opt_vec::Unknown(x.to_string()),
+                                    Ok(f) => f,
+                                    Err(e) => {
+                                        #[cfg(feature = "logging")]
+                                        log::error!("Line: {input}\nFor struct {}, parsing list item {} failed. Enum {} encountered the following error: {}", #name, #sf_string, #ov_string, e);
+                                        return Err(nom::Err::Failure(crate::util::Error::unknown_code(*x, x, #ov_string).within(#position)#within_tag));
+                                    },
+                                },
+                                None => #missing,
+                            },
+                        });
+                    } else {
+                        // Segment or Element
+                        output.push(quote! {
+                            #struct_field: match vars.get(#idx).filter(|&f| !f.is_empty()) {
+                                Some(x) => match #opt_vec::parse(x) {
+                                    Ok((_,r)) => r,
+                                    Err(e) => {
+                                        #[cfg(feature = "logging")]
+                                        log::error!("Line: {input}\nFor struct {}, parsing segment or element {} failed. Struct {} encountered the following error: {}", #name, #sf_string, #ov_string, e);
+                                        return Err(#fail_element);
+                                    },
+                                },
+                                None => #missing,
+                            },
+                        });
+                    }
+                }
+            }
+        }
+    }
+    output
+}
+
+#[proc_macro_derive(ParseOuterSegment)]
+pub fn parse_outer(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse_macro_input!(input as DeriveInput);
+    let toks = generate_outer_parse(&ast).unwrap_or_else(|err| err.to_compile_error());
+    toks.into()
+}
+
+fn generate_outer_parse(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let output = gen_outer_props(ast);
+    let prop_count = output.len();
+    let upper_name = format_ident!("{}", name).to_string().to_uppercase();
+    Ok(quote! {
+        impl #impl_generics ::core::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let x = s.trim_end_matches('\'');
+                let parts: Vec<&str> = x.split('+').collect();
+                if parts[0] == #upper_name {
+                    if parts.len() > #prop_count +1  {
+                        Err(ParseError {
+                            msg: "too many segments".to_string(),
+                            ..Default::default()
+                        })
+                    } else {
+                        let mut obj = #name::default();
+                        #(#output)*
+                        Ok(obj)
+                    }
+                } else {
+                    Err(ParseError {
+                        msg: "segment name wrong".to_string(),
+                        ..Default::default()
+                    })
+                }
+            }
+        }
+    })
+}
+
+fn gen_outer_props(ast: &DeriveInput) -> Vec<TokenStream> {
+    let x = &ast.data;
+    let mut output = vec![];
+    let mut idx: usize = 1;
+    if let Data::Struct(s) = x {
+        let f = &s.fields;
+        for o in f {
+            let id = o.ident.clone().unwrap();
+            let t = &o.ty;
+            if let syn::Type::Path(p) = t {
+                let s = &p.path.segments;
+                let we = s.first().unwrap();
+                let w = &we.ident;
+                let arg = &we.arguments;
+                let sub_id = &we.ident;
+                let ty = w.to_string();
+                match ty.as_str() {
+                    "Option" => {
+                        // look for type inside
+                        if let PathArguments::AngleBracketed(c) = arg {
+                            let o = c.args.first().unwrap();
+                            if let syn::GenericArgument::Type(syn::Type::Path(tpo)) = o {
+                                let sg = tpo.path.segments.first().unwrap();
+                                let ident = &sg.ident;
+                                let type_name = ident.to_string();
+                                match type_name.as_str() {
+                                    "String" => {
+                                        let chunk = quote! {
+                                            if let Some(val) = parts.get(#idx) {
+                                                obj.#id = Some(val.to_string());
+                                            }
+                                        };
+                                        output.push(chunk);
+                                    }
+                                    _ => {
+                                        let chunk = quote! {
+                                            if let Some(val) = parts.get(#idx) {
+                                                let t = #ident::from_str(val).unwrap();
+                                                obj.#id = Some(t);
+                                            }
+                                        };
+                                        output.push(chunk);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    "String" => {
+                        let chunk = quote! {
+                            if let Some(val) = parts.get(#idx) {
+                                obj.#id = val.to_string();
+                            }
+                        };
+                        output.push(chunk);
+                    }
+                    _ => {
+                        let chunk = quote! {
+                            if let Some(val) = parts.get(#idx) {
+                                obj.#id = #sub_id::from_str(val).unwrap();
+                            }
+                        };
+                        output.push(chunk);
+                    }
+                }
+            }
+            idx += 1;
+        }
+    }
+    output
+}
+
+/// Rules of a field, taken from `#[edifact(min = 1, max = 9)]` or `#[edifact(element = "3036", repr = "an..35")]`.
+#[derive(Default)]
+struct FieldAttr {
+    min: usize,
+    max: Option<usize>,
+    /// number of the simple data element, code lists are named by their type
+    element: Option<String>,
+    repr: Option<TokenStream>,
+}
+
+fn field_attr(field: &syn::Field) -> syn::Result<FieldAttr> {
+    let mut attr = FieldAttr::default();
+    for a in field.attrs.iter().filter(|a| a.path().is_ident("edifact")) {
+        a.parse_nested_meta(|meta| {
+            if meta.path.is_ident("min") {
+                attr.min = meta.value()?.parse::<syn::LitInt>()?.base10_parse()?;
+            } else if meta.path.is_ident("max") {
+                attr.max = Some(meta.value()?.parse::<syn::LitInt>()?.base10_parse()?);
+            } else if meta.path.is_ident("element") {
+                attr.element = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+            } else if meta.path.is_ident("repr") {
+                let lit = meta.value()?.parse::<syn::LitStr>()?;
+                attr.repr = Some(representation(&lit)?);
+            } else {
+                return Err(meta.error("unsupported edifact attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(attr)
+}
+
+/// Parses a representation like `an..35`, `n3` or `an1..6` into a `crate::validate::Representation`.
+fn representation(lit: &syn::LitStr) -> syn::Result<TokenStream> {
+    let value = lit.value();
+    let (kind, rest) = if let Some(rest) = value.strip_prefix("an") {
+        (quote! { Alphanumeric }, rest)
+    } else if let Some(rest) = value.strip_prefix('a') {
+        (quote! { Alphabetic }, rest)
+    } else if let Some(rest) = value.strip_prefix('n') {
+        (quote! { Numeric }, rest)
+    } else {
+        return Err(syn::Error::new(
+            lit.span(),
+            "representation must start with a, n or an",
+        ));
+    };
+    let number = |s: &str| {
+        s.parse::<usize>()
+            .map_err(|_| syn::Error::new(lit.span(), format!("invalid representation {value}")))
+    };
+    let (min, max) = match rest.split_once("..") {
+        Some(("", max)) => (0, number(max)?),
+        Some((min, max)) => (number(min)?, number(max)?),
+        None => (number(rest)?, number(rest)?),
+    };
+    Ok(quote! {
+        crate::validate::Representation {
+            kind: crate::validate::RepresentationKind::#kind,
+            min: #min,
+            max: #max,
+        }
+    })
+}
+
+/// Name of a field in the message structure, `segment_group_13` becomes `SG13`, `dtm` becomes `DTM`
+/// and the element `_010` becomes `010`.
+fn structure_name(field: &Ident) -> String {
+    let name = field.to_string();
+    match name.strip_prefix("segment_group_") {
+        Some(number) => format!("SG{number}"),
+        None => name.trim_start_matches('_').to_uppercase(),
+    }
+}
+
+#[proc_macro_derive(Validate, attributes(edifact))]
+pub fn validate(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let output = generate_validate(&input).unwrap_or_else(|err| err.to_compile_error());
+    #[cfg(feature = "debug")]
+    println!("{output}");
+    proc_macro::TokenStream::from(output)
+}
+
+// impl crate::validate::Validate for COPARN {
+//     fn validate(&self) -> Vec<crate::validate::Violation> {
+//         let mut violations = vec![];
+//         violations.extend(crate::validate::check_repetitions("DTM", self.dtm.len(), 0, Some(9)));
+//         for (idx, value) in self.segment_group_1.iter().enumerate() {
+//             violations.extend(crate::validate::Validate::validate(value).into_iter().map(|v| v.within(&format!("{}[{idx}]", "SG1"))));
+//         }
+//         violations
+//     }
+// }
+//
+// impl crate::validate::Validate for C058 {
+//     fn validate(&self) -> Vec<crate::validate::Violation> {
+//         let mut violations = vec![];
+//         let value = &self._010;
+//         violations.extend(crate::validate::check_representation("010", value, &crate::validate::Representation { .. }));
+//         violations
+//     }
+// }
+fn generate_validate(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let mut checks = vec![];
+    if let Data::Struct(s) = &ast.data {
+        for field in &s.fields {
+            let Some(id) = &field.ident else {
+                continue;
+            };
+            let Type::Path(tp) = &field.ty else {
+                continue;
+            };
+            let outer = &tp.path.segments[0];
+            // type inside of Vec or Option, the type itself otherwise
+            let inner = match &outer.arguments {
+                PathArguments::AngleBracketed(args) => match args.args.first() {
+                    Some(GenericArgument::Type(Type::Path(t))) => t.path.segments[0].ident.clone(),
+                    _ => outer.ident.clone(),
+                },
+                _ => outer.ident.clone(),
+            };
+            let inner = inner.to_string();
+            // segments, composites and segment groups, code lists start with `_`
+            let nested = inner != "String" && inner.starts_with(|c: char| c.is_ascii_uppercase());
+            let path = structure_name(id);
+            let attr = field_attr(field)?;
+            let check = |position: TokenStream| match &attr.repr {
+                Some(repr) if inner == "String" => Some(quote! {
+                    violations.extend(crate::validate::check_representation(#position, value, &#repr));
+                }),
+                _ if nested => Some(quote! {
+                    violations.extend(crate::validate::Validate::validate(value).into_iter().map(|v| v.within(#position)));
+                }),
+                // codes of the `lenient-codes` feature which are not in the code list
+                _ if inner.starts_with('_') => {
+                    let list = format_ident!("{}", inner);
+                    Some(quote! {
+                        #[cfg(feature = "lenient-codes")]
+                        if let #list::Unknown(code) = value {
+                            violations.push(crate::validate::unknown_code(#position, #inner, code));
+                        }
+                    })
+                }
+                _ => None,
+            };
+            match outer.ident.to_string().as_str() {
+                "Vec" => {
+                    let (min, max) = (attr.min, attr.max);
+                    let max = match max {
+                        Some(max) => quote! { Some(#max) },
+                        None => quote! { None },
+                    };
+                    checks.push(quote! {
+                        violations.extend(crate::validate::check_repetitions(#path, self.#id.len(), #min, #max));
+                    });
+                    if let Some(check) = check(quote! { &format!("{}[{idx}]", #path) }) {
+                        checks.push(quote! {
+                            for (idx, value) in self.#id.iter().enumerate() {
+                                #check
+                            }
+                        });
+                    }
+                }
+                "Option" => {
+                    if let Some(check) = check(quote! { #path }) {
+                        checks.push(quote! {
+                            if let Some(value) = &self.#id {
+                                #check
+                            }
+                        });
+                    }
+                }
+                _ => {
+                    if let Some(check) = check(quote! { #path }) {
+                        checks.push(quote! {
+                            let value = &self.#id;
+                            #check
+                        });
+                    }
+                }
+            }
+        }
+    }
+    // messages have to count their segments in UNT
+    let fields: Vec<String> = match &ast.data {
+        Data::Struct(data) => data
+            .fields
+            .iter()
+            .filter_map(|f| f.ident.as_ref().map(|i| i.to_string()))
+            .collect(),
+        _ => vec![],
+    };
+    if fields.iter().any(|f| f == "unh") && fields.iter().any(|f| f == "unt") {
+        checks.push(quote! {
+            violations.extend(crate::control::check_trailer(
+                &self.to_string(),
+                &self.unh._010,
+                &self.unt._010,
+                &self.unt._020,
+            ));
+        });
+    }
+    Ok(quote! {
+        impl crate::validate::Validate for #name {
+            fn validate(&self) -> Vec<crate::validate::Violation> {
+                #[allow(unused_mut)]
+                let mut violations = vec![];
+                #(#checks)*
+                violations
+            }
+        }
+    })
+}
+
+#[proc_macro_derive(CodeList)]
+pub fn code_list(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let output = generate_code_list(&input).unwrap_or_else(|err| err.to_compile_error());
+    #[cfg(feature = "debug")]
+    println!("{output}");
+    proc_macro::TokenStream::from(output)
+}
+
+// impl crate::codes::CodeList for _0001 {
+//     fn list_id() -> &'static str { "0001" }
+//     fn all() -> &'static [Self] { &[_0001::UNOA, ..] }
+//     fn code(&self) -> &str {
+//         match self { _0001::UNOA => "UNOA", .., _0001::Unknown(code) => code }
+//     }
+//     fn name(&self) -> &'static str {
+//         match self { _0001::UNOA => "UN/ECE level A", .., _ => "" }
+//     }
+//     fn description(&self) -> &'static str { .. }
+// }
+fn generate_code_list(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let Data::Enum(data) = &ast.data else {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "CodeList is only derived for enums",
+        ));
+    };
+    let list_id = name.to_string().trim_start_matches('_').to_string();
+    let (mut all, mut codes, mut names, mut descriptions) = (vec![], vec![], vec![], vec![]);
+    for variant in &data.variants {
+        let id = &variant.ident;
+        // `Unknown(String)` of the `lenient-codes` feature
+        if let Fields::Unnamed(_) = variant.fields {
+            let cfg: Vec<_> = variant
+                .attrs
+                .iter()
+                .filter(|a| a.path().is_ident("cfg"))
+                .collect();
+            codes.push(quote! { #(#cfg)* #name::#id(code) => code, });
+            continue;
+        }
+        let code = variant_code(variant)?
+            .unwrap_or_else(|| id.to_string().trim_start_matches('_').to_string());
+        // the first line of the documentation is the name, the following lines the description
+        let docs = variant_docs(variant);
+        let (code_name, description) = match docs.split_first() {
+            Some((first, rest)) => (first.clone(), rest.join(" ")),
+            None => (String::new(), String::new()),
+        };
+        all.push(quote! { #name::#id, });
+        codes.push(quote! { #name::#id => #code, });
+        names.push(quote! { #name::#id => #code_name, });
+        descriptions.push(quote! { #name::#id => #description, });
+    }
+    let rest = if all.len() < data.variants.len() {
+        quote! { #[allow(unreachable_patterns)] _ => "", }
+    } else {
+        quote! {}
+    };
+    Ok(quote! {
+        impl crate::codes::CodeList for #name {
+            fn list_id() -> &'static str {
+                #list_id
+            }
+            fn all() -> &'static [Self] {
+                &[#(#all)*]
+            }
+            fn code(&self) -> &str {
+                match self {
+                    #(#codes)*
+                }
+            }
+            fn name(&self) -> &'static str {
+                match self {
+                    #(#names)*
+                    #rest
+                }
+            }
+            fn description(&self) -> &'static str {
+                match self {
+                    #(#descriptions)*
+                    #rest
+                }
+            }
+        }
+    })
+}
+
+/// Code of a variant from `#[strum(serialize = "..")]` or `#[serde(rename = "..")]`.
+fn variant_code(variant: &syn::Variant) -> syn::Result<Option<String>> {
+    let mut code = None;
+    for attr in &variant.attrs {
+        let key = if attr.path().is_ident("strum") {
+            "serialize"
+        } else if attr.path().is_ident("serde") {
+            "rename"
+        } else {
+            continue;
+        };
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(key) {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                code.get_or_insert(lit.value());
+            } else if meta.input.peek(syn::Token![=]) {
+                meta.value()?.parse::<syn::Expr>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(code)
+}
+
+/// Non-empty lines of the documentation of a variant.
+fn variant_docs(variant: &syn::Variant) -> Vec<String> {
+    variant
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("doc"))
+        .filter_map(|a| match &a.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(lit),
+                        ..
+                    }),
+                ..
+            }) => Some(lit.value().trim().to_string()),
+            _ => None,
+        })
+        .filter(|line| !line.is_empty())
+        .collect()
+}
@@ -0,0 +1,595 @@
+//! Character repertoires of the syntax levels declared in UNB S001 (`_0001`).
+//!
+//! Parsing accepts any character, [`check`] reports the characters of an
+//! interchange which are not part of its syntax level and [`transliterate`]
+//! replaces them, e.g. before sending to a Level A partner.
+//! [`decode`] and [`encode`] convert between bytes and the text of an interchange
+//! with the encoding of its syntax level.
+
+use crate::util::{delimiters, parse_colon_section, parse_line, parse_una, with_delimiters, Error};
+use crate::ParseError;
+use encoding_rs::{Encoding, ISO_8859_2, ISO_8859_5, ISO_8859_7};
+use nom::{combinator::opt, error::ErrorKind, Parser as _};
+use std::{fmt, str::Utf8Error};
+use strum_macros::{Display, EnumString};
+
+/// Syntax level of an interchange, the supported values of `_0001`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, Display)]
+pub enum SyntaxLevel {
+    /// upper case letters, digits, space and `.,-()/'+:=?!"%&*;<>`
+    UNOA,
+    /// level A with lower case letters
+    UNOB,
+    /// ISO 8859-1, Latin alphabet No. 1
+    UNOC,
+    /// ISO 8859-2, Latin alphabet No. 2
+    UNOD,
+    /// ISO 8859-5, Latin/Cyrillic alphabet
+    UNOE,
+    /// ISO 8859-7, Latin/Greek alphabet
+    UNOF,
+    /// ISO 10646-1 with UTF-8 encoding
+    UNOW,
+    /// ISO 10646-1 without code extension technique, encoded as UTF-8
+    UNOY,
+}
+
+impl SyntaxLevel {
+    /// Whether the character is part of the repertoire.
+    pub fn contains(self, c: char) -> bool {
+        let level_a = matches!(
+            c,
+            'A'..='Z' | '0'..='9' | ' ' | '.' | ',' | '-' | '(' | ')' | '/' | '\'' | '+' | ':'
+                | '=' | '?' | '!' | '"' | '%' | '&' | '*' | ';' | '<' | '>'
+        );
+        let ascii = (' '..='~').contains(&c);
+        match self {
+            SyntaxLevel::UNOA => level_a,
+            SyntaxLevel::UNOB => level_a || c.is_ascii_lowercase(),
+            SyntaxLevel::UNOC => ascii || ('\u{a0}'..='\u{ff}').contains(&c),
+            SyntaxLevel::UNOD => ascii || encodable(ISO_8859_2, c),
+            SyntaxLevel::UNOE => ascii || encodable(ISO_8859_5, c),
+            SyntaxLevel::UNOF => ascii || encodable(ISO_8859_7, c),
+            SyntaxLevel::UNOW | SyntaxLevel::UNOY => true,
+        }
+    }
+
+    /// Appends the encoded character, returns `false` if the encoding can not represent it.
+    fn encode(self, c: char, bytes: &mut Vec<u8>) -> bool {
+        let encoding = match self {
+            SyntaxLevel::UNOA | SyntaxLevel::UNOB | SyntaxLevel::UNOC => {
+                return match u8::try_from(c) {
+                    Ok(b) => {
+                        bytes.push(b);
+                        true
+                    }
+                    Err(_) => false,
+                };
+            }
+            SyntaxLevel::UNOD => ISO_8859_2,
+            SyntaxLevel::UNOE => ISO_8859_5,
+            SyntaxLevel::UNOF => ISO_8859_7,
+            SyntaxLevel::UNOW | SyntaxLevel::UNOY => {
+                bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                return true;
+            }
+        };
+        let mut buf = [0; 4];
+        let (encoded, _, unmappable) = encoding.encode(c.encode_utf8(&mut buf));
+        bytes.extend_from_slice(&encoded);
+        !unmappable
+    }
+
+    /// Replaces a character which is not part of the repertoire.
+    ///
+    /// Accents are removed (`é` becomes `e`), Level A uses upper case letters.
+    /// Anything else becomes a space.
+    pub fn transliterate(self, c: char) -> String {
+        if self.contains(c) {
+            return c.to_string();
+        }
+        let mut folded = FOLDING
+            .iter()
+            .find(|(from, _)| *from == c)
+            .map_or_else(|| c.to_string(), |(_, to)| to.to_string());
+        if self == SyntaxLevel::UNOA {
+            folded = folded.to_uppercase();
+        }
+        if folded.chars().all(|c| self.contains(c)) {
+            folded
+        } else {
+            " ".to_string()
+        }
+    }
+}
+
+/// Printable characters of a single byte encoding, C1 control characters are excluded.
+fn encodable(encoding: &'static Encoding, c: char) -> bool {
+    let mut buf = [0; 4];
+    let (_, _, unmappable) = encoding.encode(c.encode_utf8(&mut buf));
+    c >= '\u{a0}' && !unmappable
+}
+
+/// A character which is not part of the syntax level of the interchange.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharsetViolation {
+    /// position of the segment in the interchange, starting with 1 at UNB (UNA is not counted)
+    pub segment_index: usize,
+    pub segment_tag: String,
+    pub character: char,
+    /// syntax level declared in UNB S001
+    pub syntax: SyntaxLevel,
+}
+
+impl fmt::Display for CharsetViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "segment {} ({}): character {:?} is not part of {}",
+            self.segment_index, self.segment_tag, self.character, self.syntax
+        )
+    }
+}
+
+/// Reports the characters which are not part of the syntax level declared in UNB S001.
+///
+/// Service characters are not checked. Interchanges without UNB or with an
+/// unsupported syntax level have no violations.
+pub fn check(input: &str) -> Vec<CharsetViolation> {
+    let mut violations = vec![];
+    walk(input, |position, c| {
+        if !position.service && !position.syntax.contains(c) {
+            violations.push(position.violation(c));
+        }
+    });
+    violations
+}
+
+/// Returns the interchange if all characters are part of its syntax level, see [`check`].
+pub fn checked(input: String) -> Result<String, Vec<CharsetViolation>> {
+    let violations = check(&input);
+    if violations.is_empty() {
+        Ok(input)
+    } else {
+        Err(violations)
+    }
+}
+
+/// Replaces the characters which are not part of the syntax level declared in UNB S001,
+/// see [`SyntaxLevel::transliterate`].
+pub fn transliterate(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let syntax = walk(input, |position, c| {
+        if position.service {
+            output.push(c);
+        } else {
+            output.push_str(&position.syntax.transliterate(c));
+        }
+    });
+    match syntax {
+        Some(_) => output,
+        None => input.to_string(),
+    }
+}
+
+/// Decodes an interchange with the encoding of the syntax level declared in UNB S001.
+///
+/// UNA and UNB are read as ISO 8859-1, which shares the service characters with
+/// all supported encodings. Interchanges without a supported syntax level are decoded as UTF-8.
+pub fn decode(bytes: &[u8]) -> Result<String, ParseError> {
+    let latin1: String = bytes.iter().map(|&b| char::from(b)).collect();
+    decode_as(syntax_level(&latin1), bytes).map_err(|e| {
+        let valid = std::str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or_default();
+        let err = Error {
+            expected: Some("UTF-8".into()),
+            ..Error::new(&valid[valid.len()..], ErrorKind::Char)
+        };
+        ParseError::new(valid, nom::Err::Error(err))
+    })
+}
+
+/// Decodes a part of an interchange with the encoding of `syntax`, UTF-8 if `None`.
+pub(crate) fn decode_as(syntax: Option<SyntaxLevel>, bytes: &[u8]) -> Result<String, Utf8Error> {
+    let encoding = match syntax {
+        Some(SyntaxLevel::UNOA | SyntaxLevel::UNOB | SyntaxLevel::UNOC) => {
+            return Ok(bytes.iter().map(|&b| char::from(b)).collect());
+        }
+        Some(SyntaxLevel::UNOD) => ISO_8859_2,
+        Some(SyntaxLevel::UNOE) => ISO_8859_5,
+        Some(SyntaxLevel::UNOF) => ISO_8859_7,
+        Some(SyntaxLevel::UNOW | SyntaxLevel::UNOY) | None => {
+            return std::str::from_utf8(bytes).map(str::to_string);
+        }
+    };
+    Ok(encoding.decode_without_bom_handling(bytes).0.into_owned())
+}
+
+/// Encodes an interchange with the encoding of the syntax level declared in UNB S001.
+///
+/// Fails with the characters which are not part of the syntax level, like [`checked`].
+/// Interchanges without a supported syntax level are encoded as UTF-8.
+pub fn encode(input: &str) -> Result<Vec<u8>, Vec<CharsetViolation>> {
+    let mut bytes = Vec::with_capacity(input.len());
+    let mut violations = vec![];
+    let syntax = walk(input, |position, c| {
+        let allowed = position.service || position.syntax.contains(c);
+        if !allowed || !position.syntax.encode(c, &mut bytes) {
+            violations.push(position.violation(c));
+        }
+    });
+    match syntax {
+        None => Ok(input.as_bytes().to_vec()),
+        Some(_) if violations.is_empty() => Ok(bytes),
+        Some(_) => Err(violations),
+    }
+}
+
+/// Syntax level declared in UNB S001, `None` without UNB or for an unsupported level.
+pub fn syntax_level(input: &str) -> Option<SyntaxLevel> {
+    let (rest, una) = opt(parse_una).parse(input).unwrap_or((input, None));
+    with_delimiters(una.unwrap_or_else(delimiters), || {
+        let (_, vars) = parse_line(rest.trim_start(), "UNB").ok()?;
+        let (_, s001) = parse_colon_section(vars.first()?).ok()?;
+        s001.first()?.parse::<SyntaxLevel>().ok()
+    })
+}
+
+/// Position of a character in the interchange.
+struct Position<'a> {
+    syntax: SyntaxLevel,
+    /// 0 for UNA, starting with 1 at UNB
+    segment_index: usize,
+    segment_tag: &'a str,
+    /// separators, release character and line breaks
+    service: bool,
+}
+
+impl Position<'_> {
+    fn violation(&self, character: char) -> CharsetViolation {
+        CharsetViolation {
+            segment_index: self.segment_index,
+            segment_tag: self.segment_tag.to_string(),
+            character,
+            syntax: self.syntax,
+        }
+    }
+}
+
+/// Calls `visit` for every character of the interchange.
+///
+/// Returns the syntax level, without a supported syntax level nothing is visited.
+fn walk<'a>(input: &'a str, mut visit: impl FnMut(&Position<'a>, char)) -> Option<SyntaxLevel> {
+    let syntax = syntax_level(input)?;
+    let (rest, una) = opt(parse_una).parse(input).unwrap_or((input, None));
+    let d = una.unwrap_or_else(delimiters);
+    let mut position = Position {
+        syntax,
+        segment_index: 0,
+        segment_tag: "UNA",
+        service: true,
+    };
+    for c in input[..input.len() - rest.len()].chars() {
+        visit(&position, c);
+    }
+    position.segment_index = 1;
+    position.segment_tag = "";
+    let mut segment_start = true;
+    let mut released = false;
+    for (pos, c) in rest.char_indices() {
+        // a released service character is part of the value, but no data character
+        if released || Some(c) == d.release {
+            released = !released;
+            position.service = true;
+        } else if c == d.segment {
+            position.service = true;
+            visit(&position, c);
+            position.segment_index += 1;
+            segment_start = true;
+            continue;
+        } else {
+            if segment_start && !c.is_whitespace() {
+                position.segment_tag = rest[pos..]
+                    .split([d.element, d.segment])
+                    .next()
+                    .unwrap_or_default();
+                segment_start = false;
+            }
+            position.service = [d.element, d.component, '\n', '\r'].contains(&c);
+        }
+        visit(&position, c);
+    }
+    Some(syntax)
+}
+
+/// Latin letters with their base letters, from U+00C0 to U+017F.
+const FOLDING: &[(char, &str)] = &[
+    ('À', "A"),
+    ('Á', "A"),
+    ('Â', "A"),
+    ('Ã', "A"),
+    ('Ä', "A"),
+    ('Å', "A"),
+    ('Æ', "AE"),
+    ('Ç', "C"),
+    ('È', "E"),
+    ('É', "E"),
+    ('Ê', "E"),
+    ('Ë', "E"),
+    ('Ì', "I"),
+    ('Í', "I"),
+    ('Î', "I"),
+    ('Ï', "I"),
+    ('Ð', "D"),
+    ('Ñ', "N"),
+    ('Ò', "O"),
+    ('Ó', "O"),
+    ('Ô', "O"),
+    ('Õ', "O"),
+    ('Ö', "O"),
+    ('Ø', "O"),
+    ('Ù', "U"),
+    ('Ú', "U"),
+    ('Û', "U"),
+    ('Ü', "U"),
+    ('Ý', "Y"),
+    ('Þ', "TH"),
+    ('ß', "ss"),
+    ('à', "a"),
+    ('á', "a"),
+    ('â', "a"),
+    ('ã', "a"),
+    ('ä', "a"),
+    ('å', "a"),
+    ('æ', "ae"),
+    ('ç', "c"),
+    ('è', "e"),
+    ('é', "e"),
+    ('ê', "e"),
+    ('ë', "e"),
+    ('ì', "i"),
+    ('í', "i"),
+    ('î', "i"),
+    ('ï', "i"),
+    ('ð', "d"),
+    ('ñ', "n"),
+    ('ò', "o"),
+    ('ó', "o"),
+    ('ô', "o"),
+    ('õ', "o"),
+    ('ö', "o"),
+    ('ø', "o"),
+    ('ù', "u"),
+    ('ú', "u"),
+    ('û', "u"),
+    ('ü', "u"),
+    ('ý', "y"),
+    ('þ', "th"),
+    ('ÿ', "y"),
+    ('Ā', "A"),
+    ('ā', "a"),
+    ('Ă', "A"),
+    ('ă', "a"),
+    ('Ą', "A"),
+    ('ą', "a"),
+    ('Ć', "C"),
+    ('ć', "c"),
+    ('Ĉ', "C"),
+    ('ĉ', "c"),
+    ('Ċ', "C"),
+    ('ċ', "c"),
+    ('Č', "C"),
+    ('č', "c"),
+    ('Ď', "D"),
+    ('ď', "d"),
+    ('Đ', "D"),
+    ('đ', "d"),
+    ('Ē', "E"),
+    ('ē', "e"),
+    ('Ĕ', "E"),
+    ('ĕ', "e"),
+    ('Ė', "E"),
+    ('ė', "e"),
+    ('Ę', "E"),
+    ('ę', "e"),
+    ('Ě', "E"),
+    ('ě', "e"),
+    ('Ĝ', "G"),
+    ('ĝ', "g"),
+    ('Ğ', "G"),
+    ('ğ', "g"),
+    ('Ġ', "G"),
+    ('ġ', "g"),
+    ('Ģ', "G"),
+    ('ģ', "g"),
+    ('Ĥ', "H"),
+    ('ĥ', "h"),
+    ('Ħ', "H"),
+    ('ħ', "h"),
+    ('Ĩ', "I"),
+    ('ĩ', "i"),
+    ('Ī', "I"),
+    ('ī', "i"),
+    ('Ĭ', "I"),
+    ('ĭ', "i"),
+    ('Į', "I"),
+    ('į', "i"),
+    ('İ', "I"),
+    ('ı', "i"),
+    ('Ĳ', "IJ"),
+    ('ĳ', "ij"),
+    ('Ĵ', "J"),
+    ('ĵ', "j"),
+    ('Ķ', "K"),
+    ('ķ', "k"),
+    ('ĸ', "k"),
+    ('Ĺ', "L"),
+    ('ĺ', "l"),
+    ('Ļ', "L"),
+    ('ļ', "l"),
+    ('Ľ', "L"),
+    ('ľ', "l"),
+    ('Ŀ', "L"),
+    ('ŀ', "l"),
+    ('Ł', "L"),
+    ('ł', "l"),
+    ('Ń', "N"),
+    ('ń', "n"),
+    ('Ņ', "N"),
+    ('ņ', "n"),
+    ('Ň', "N"),
+    ('ň', "n"),
+    ('ŉ', "n"),
+    ('Ŋ', "N"),
+    ('ŋ', "n"),
+    ('Ō', "O"),
+    ('ō', "o"),
+    ('Ŏ', "O"),
+    ('ŏ', "o"),
+    ('Ő', "O"),
+    ('ő', "o"),
+    ('Œ', "OE"),
+    ('œ', "oe"),
+    ('Ŕ', "R"),
+    ('ŕ', "r"),
+    ('Ŗ', "R"),
+    ('ŗ', "r"),
+    ('Ř', "R"),
+    ('ř', "r"),
+    ('Ś', "S"),
+    ('ś', "s"),
+    ('Ŝ', "S"),
+    ('ŝ', "s"),
+    ('Ş', "S"),
+    ('ş', "s"),
+    ('Š', "S"),
+    ('š', "s"),
+    ('Ţ', "T"),
+    ('ţ', "t"),
+    ('Ť', "T"),
+    ('ť', "t"),
+    ('Ŧ', "T"),
+    ('ŧ', "t"),
+    ('Ũ', "U"),
+    ('ũ', "u"),
+    ('Ū', "U"),
+    ('ū', "u"),
+    ('Ŭ', "U"),
+    ('ŭ', "u"),
+    ('Ů', "U"),
+    ('ů', "u"),
+    ('Ű', "U"),
+    ('ű', "u"),
+    ('Ų', "U"),
+    ('ų', "u"),
+    ('Ŵ', "W"),
+    ('ŵ', "w"),
+    ('Ŷ', "Y"),
+    ('ŷ', "y"),
+    ('Ÿ', "Y"),
+    ('Ź', "Z"),
+    ('ź', "z"),
+    ('Ż', "Z"),
+    ('ż', "z"),
+    ('Ž', "Z"),
+    ('ž', "z"),
+    ('ſ', "s"),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const LEVEL_A: &str = "UNA:+.? '
+UNB+UNOA:2+SENDER:ZZZ+RECEIVER:ZZZ+200101:1200+1'
+UNH+1+IFTSTA:D:00B:UN'
+NAD+CA+ABCD:160++Müller?+Söhne'
+UNT+2+1'
+UNZ+1+1'";
+
+    #[test]
+    fn check_level_a() {
+        let violations = check(LEVEL_A);
+        let found: String = violations.iter().map(|v| v.character).collect();
+        // the released `+` is a service character
+        assert_eq!(found, "ülleröhne");
+        assert_eq!(violations[0].segment_index, 3);
+        assert_eq!(violations[0].segment_tag, "NAD");
+        assert_eq!(
+            violations[0].to_string(),
+            "segment 3 (NAD): character 'ü' is not part of UNOA"
+        );
+    }
+
+    #[test]
+    fn transliterate_level_a() {
+        let output = transliterate(LEVEL_A);
+        assert_eq!(output, LEVEL_A.replace("Müller?+Söhne", "MULLER?+SOHNE"));
+        assert_eq!(check(&output), vec![]);
+        assert!(checked(LEVEL_A.to_string()).is_err());
+        assert_eq!(checked(output.clone()), Ok(output));
+    }
+
+    #[test]
+    fn repertoires() {
+        assert!(SyntaxLevel::UNOB.contains('a'));
+        assert!(!SyntaxLevel::UNOB.contains('#'));
+        assert_eq!(SyntaxLevel::UNOB.transliterate('ß'), "ss");
+        assert_eq!(SyntaxLevel::UNOA.transliterate('ß'), "SS");
+        assert_eq!(SyntaxLevel::UNOA.transliterate('€'), " ");
+        assert!(SyntaxLevel::UNOC.contains('ü'));
+        assert!(!SyntaxLevel::UNOC.contains('ł'));
+        assert_eq!(SyntaxLevel::UNOC.transliterate('ł'), "l");
+        assert!(SyntaxLevel::UNOD.contains('ł'));
+        assert!(!SyntaxLevel::UNOD.contains('å'));
+        assert!(SyntaxLevel::UNOE.contains('Ж'));
+        assert!(SyntaxLevel::UNOF.contains('Ω'));
+        assert!(!SyntaxLevel::UNOF.contains('Ж'));
+    }
+
+    fn interchange(syntax: &str, name: &str) -> String {
+        format!("UNB+{syntax}:3+SENDER+RECEIVER+200101:1200+1'NAD+CA+++{name}'UNZ+1+1'")
+    }
+
+    #[test]
+    fn decode_encode() {
+        for (syntax, name, byte) in [
+            ("UNOC", "Müller", 0xfc),
+            ("UNOD", "Łódź", 0xa3),
+            ("UNOE", "Жуков", 0xb6),
+            ("UNOF", "Ωμέγα", 0xd9),
+        ] {
+            let text = interchange(syntax, name);
+            let bytes = encode(&text).unwrap();
+            assert!(bytes.contains(&byte), "{syntax}");
+            assert_eq!(bytes.len(), text.chars().count(), "{syntax}");
+            assert_eq!(decode(&bytes).unwrap(), text, "{syntax}");
+        }
+        let text = interchange("UNOY", "Жуков Ωμέγα");
+        assert_eq!(encode(&text).unwrap(), text.as_bytes());
+        assert_eq!(decode(text.as_bytes()).unwrap(), text);
+    }
+
+    #[test]
+    fn encode_outside_of_level() {
+        let violations = encode(&interchange("UNOD", "Ж")).unwrap_err();
+        assert_eq!(violations[0].character, 'Ж');
+        assert_eq!(violations[0].segment_tag, "NAD");
+    }
+
+    #[test]
+    fn decode_invalid_utf8() {
+        let mut bytes = interchange("UNOY", "").into_bytes();
+        bytes.insert(bytes.len() - 9, 0xff);
+        let err = decode(&bytes).unwrap_err();
+        assert_eq!(err.segment_index, 2);
+        assert_eq!(err.segment_tag, "NAD");
+        assert_eq!(err.msg, "expected UTF-8");
+    }
+
+    #[test]
+    fn check_unsupported_level() {
+        let input = LEVEL_A.replace("UNOA", "UNOY");
+        assert_eq!(check(&input), vec![]);
+        assert_eq!(transliterate(&input), input);
+    }
+}
@@ -0,0 +1,34 @@
+//! Names and descriptions of the codes of the UNCL code lists.
+//!
+//! ```
+//! # #[cfg(feature = "d00b")]
+//! # {
+//! use edifact_types::codes::CodeList;
+//! use edifact_types::d00b::_0001;
+//!
+//! assert_eq!(_0001::list_id(), "0001");
+//! assert_eq!(_0001::UNOA.code(), "UNOA");
+//! assert_eq!(_0001::UNOA.name(), "UN/ECE level A");
+//! assert!(_0001::all().contains(&_0001::UNOC));
+//! # }
+//! ```
+
+/// A code list like `_2005`, implemented for each code list enum of the directories.
+///
+/// Name and description are taken from the documentation of the codes in the directory.
+/// Whether a code is deprecated is not part of that documentation, so it is not available.
+pub trait CodeList: Sized + 'static {
+    /// Number of the data element of the list, e.g. `2005`.
+    fn list_id() -> &'static str;
+    /// All codes of the list in the order of the directory.
+    fn all() -> &'static [Self];
+    /// The code as written in EDIFACT, e.g. `137`.
+    fn code(&self) -> &str;
+    /// Name of the code, e.g. `Document/message date/time`.
+    ///
+    /// Empty for unknown codes and for the codes the directory has no documentation for yet,
+    /// like the ones of `_4343`.
+    fn name(&self) -> &'static str;
+    /// Description of the code, empty like [`CodeList::name`] or when the code has none.
+    fn description(&self) -> &'static str;
+}
@@ -0,0 +1,98 @@
+//! Control counts and references of the service segments.
+//!
+//! UNT counts the segments of its message and repeats the reference of UNH,
+//! UNE and UNZ count the messages or groups they enclose and repeat the reference
+//! of their header. [`Finalize`] fills them in for messages built in code.
+//!
+//! The parsers accept mismatches, [`crate::validate::Validate`] of the messages
+//! reports them for UNT, of the interchanges for all levels of the envelope.
+
+use crate::util::parse_raw_line;
+use crate::validate::{Violation, ViolationKind};
+
+/// Sets the control counts and references from the content.
+pub trait Finalize {
+    /// Sets the control counts and references, e.g. UNT `_010` from the rendered segments
+    /// and UNT `_020` from UNH `_010`.
+    fn finalize(&mut self);
+
+    /// Returns the value with its control counts and references set.
+    ///
+    /// ```
+    /// # #[cfg(feature = "d00b")]
+    /// # {
+    /// use edifact_types::control::Finalize;
+    /// use edifact_types::d00b::IFTSTA;
+    ///
+    /// let mut message = IFTSTA::default();
+    /// message.unh._010 = "42".to_string();
+    /// let message = message.finalized();
+    /// // UNH and UNT, the empty segments are not rendered
+    /// assert_eq!(message.unt._010, "2");
+    /// assert_eq!(message.unt._020, "42");
+    /// # }
+    /// ```
+    fn finalized(mut self) -> Self
+    where
+        Self: Sized,
+    {
+        self.finalize();
+        self
+    }
+}
+
+/// Number of segments in the serialized `input`, with the current delimiters.
+pub fn segment_count(mut input: &str) -> usize {
+    let mut count = 0;
+    while !input.trim().is_empty() {
+        match parse_raw_line(input) {
+            Ok((rest, _)) => input = rest,
+            Err(_) => return count + 1,
+        }
+        count += 1;
+    }
+    count
+}
+
+/// Checks UNT of the serialized `message`, it has to count the segments from UNH to UNT
+/// and repeat `unh_reference`.
+pub fn check_trailer(
+    message: &str,
+    unh_reference: &str,
+    unt_count: &str,
+    unt_reference: &str,
+) -> Vec<Violation> {
+    let count = segment_count(message);
+    check_count("UNT/010", unt_count, count)
+        .into_iter()
+        .chain(check_reference("UNT/020", unt_reference, unh_reference))
+        .collect()
+}
+
+/// Checks a control count, e.g. UNZ `_010` against the number of messages.
+pub fn check_count(path: &str, found: &str, count: usize) -> Option<Violation> {
+    if found.parse::<usize>() == Ok(count) {
+        return None;
+    }
+    Some(Violation {
+        path: path.to_string(),
+        kind: ViolationKind::ControlMismatch {
+            expected: count.to_string(),
+            found: found.to_string(),
+        },
+    })
+}
+
+/// Checks a control reference, e.g. UNZ `_020` against UNB `_050`.
+pub fn check_reference(path: &str, found: &str, expected: &str) -> Option<Violation> {
+    if found == expected {
+        return None;
+    }
+    Some(Violation {
+        path: path.to_string(),
+        kind: ViolationKind::ControlMismatch {
+            expected: expected.to_string(),
+            found: found.to_string(),
+        },
+    })
+}
@@ -0,0 +1,42 @@
+use super::{_2005, _2379, C507, DTM};
+use crate::datetime::{self, DateTimeError, DateTimeValue};
+
+impl C507 {
+    /// The value (2380) as date, time or period of its format code (2379).
+    pub fn value(&self) -> Result<DateTimeValue, DateTimeError> {
+        let value = self._020.as_deref().ok_or(DateTimeError::MissingValue)?;
+        let format = self._030.as_ref().ok_or(DateTimeError::MissingFormat)?;
+        datetime::parse(value, &format.to_string())
+    }
+
+    /// Creates the composite from a date, time or period, written with `format`.
+    pub fn new(
+        qualifier: _2005,
+        value: impl Into<DateTimeValue>,
+        format: _2379,
+    ) -> Result<C507, DateTimeError> {
+        Ok(C507 {
+            _010: qualifier,
+            _020: Some(datetime::format(&value.into(), &format.to_string())?),
+            _030: Some(format),
+        })
+    }
+}
+
+impl DTM {
+    /// The date, time or period of C507, see [`C507::value`].
+    pub fn value(&self) -> Result<DateTimeValue, DateTimeError> {
+        self._010.value()
+    }
+
+    /// Creates the segment from a date, time or period, written with `format`.
+    pub fn new(
+        qualifier: _2005,
+        value: impl Into<DateTimeValue>,
+        format: _2379,
+    ) -> Result<DTM, DateTimeError> {
+        Ok(DTM {
+            _010: C507::new(qualifier, value, format)?,
+        })
+    }
+}
@@ -1,7 +1,6 @@
 use super::*;
-use crate::util::{clean_num, Parser};
-use edifact_types_macros::{DisplayInnerSegment, ParseElement};
-use nom::IResult;
+use crate::util::{clean_num, IResult, Parser};
+use edifact_types_macros::{DisplayInnerSegment, ParseElement, Validate};
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::{self, Debug},
@@ -9,26 +8,67 @@ use std::{
 };
 
 /// C002 - DOCUMENT/MESSAGE NAME
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C002 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_name_code", alias = "_010")
+    )]
     pub _010: Option<_1001>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<_1131>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<_3055>,
+    #[edifact(element = "1000", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_name", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
 /// C040 - CARRIER
 ///
 /// Identification of a carrier by code and/or by name. Code preferred.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C040 {
     /// Carrier identifier                 
+    #[edifact(element = "3127", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "carrier_identifier", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list identification code      
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency code
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Carrier name                       
+    #[edifact(element = "3128", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "carrier_name", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
@@ -36,28 +76,69 @@ pub struct C040 {
 ///
 /// Code and/or name of a department or employee. Code
 /// preferred.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C056 {
     /// Department or employee name code          C      an..17
+    #[edifact(element = "3413", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "department_or_employee_name_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Department or employee name               C      an..35
+    #[edifact(element = "3412", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "department_or_employee_name", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
 /// C058 NAME AND ADDRESS
 ///
 /// Unstructured name and address: one to five lines.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C058 {
     /// Name and address description              M      an..35
+    #[edifact(element = "3124", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address_description_010", alias = "_010")
+    )]
     pub _010: String,
     /// Name and address description              C      an..35
+    #[edifact(element = "3124", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address_description_020", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Name and address description              C      an..35
+    #[edifact(element = "3124", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address_description_030", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Name and address description              C      an..35
+    #[edifact(element = "3124", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address_description_040", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// Name and address description              C      an..35
+    #[edifact(element = "3124", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address_description_050", alias = "_050")
+    )]
     pub _050: Option<String>,
 }
 
@@ -65,15 +146,50 @@ pub struct C058 {
 ///
 /// Street address and/or PO Box number in a structured
 /// address: one to four lines.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C059 {
     /// Street and number or post office box identifier                                M      an..35
+    #[edifact(element = "3042", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "street_and_number_or_post_office_box_identifier_010",
+            alias = "_010"
+        )
+    )]
     pub _010: Option<String>,
     /// Street and number or post office box identifier                                C      an..35
+    #[edifact(element = "3042", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "street_and_number_or_post_office_box_identifier_020",
+            alias = "_020"
+        )
+    )]
     pub _020: Option<String>,
     /// Street and number or post office box identifier                                C      an..35
+    #[edifact(element = "3042", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "street_and_number_or_post_office_box_identifier_030",
+            alias = "_030"
+        )
+    )]
     pub _030: Option<String>,
     /// Street and number or post office box identifier                                C      an..35
+    #[edifact(element = "3042", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "street_and_number_or_post_office_box_identifier_040",
+            alias = "_040"
+        )
+    )]
     pub _040: Option<String>,
 }
 
@@ -81,11 +197,21 @@ pub struct C059 {
 ///
 /// Communication number of a department or employee in
 /// a specified channel.
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C076 {
     /// Communication address identifier          M      an..512
+    #[edifact(element = "3148", repr = "an..512")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "communication_address_identifier", alias = "_010")
+    )]
     pub _010: String,
     /// Communication address code qualifier      M      an..3
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "communication_address_code_qualifier", alias = "_020")
+    )]
     pub _020: _3155,
 }
 
@@ -93,90 +219,229 @@ pub struct C076 {
 ///
 /// Identification of a transaction party by name, one
 /// to five lines. Party name may be formatted.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C080 {
     /// Party name                                M      an..35
+    #[edifact(element = "3036", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_name_010", alias = "_010")
+    )]
     pub _010: String,
     /// Party name                                C      an..35
+    #[edifact(element = "3036", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_name_020", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Party name                                C      an..35
+    #[edifact(element = "3036", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_name_030", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Party name                                C      an..35
+    #[edifact(element = "3036", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_name_040", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// Party name                                C      an..35
+    #[edifact(element = "3036", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_name_050", alias = "_050")
+    )]
     pub _050: Option<String>,
     /// Party name format code                    C      an..3
+    #[edifact(element = "3045", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_name_format_code", alias = "_060")
+    )]
     pub _060: Option<String>,
 }
 
 /// C082 PARTY IDENTIFICATION DETAILS
 ///
 /// Identification of a transaction party by code.
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C082 {
     /// Party identifier                          M      an..35
+    #[edifact(element = "3039", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_identifier", alias = "_010")
+    )]
     pub _010: String,
     /// Code list identification code             C      an..17
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<_1131>,
     /// Code list responsible agency code         C      an..3
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<_3055>,
 }
 
 /// Terms of delivery or transport
 ///
 /// Terms of delivery or transport code from a specified source.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C100 {
     /// Delivery or transport terms description code
     ///
     /// Code specifying the delivery or transport terms.
     /// 1 Use UN/ECE Recommendation No. 5 Incoterms 1990. If not applicable, use appropriate code set in combination with 1131/3055.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "delivery_or_transport_terms_description_code",
+            alias = "_010"
+        )
+    )]
     pub _010: Option<_4053>,
     /// Code list identification code
     ///
     /// Code identifying a code list.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<_1131>,
     /// Code list responsible agency code
     ///
     /// Code specifying the agency responsible for a code list.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<_3055>,
     /// Delivery or transport terms description
     ///
     /// Free form description of delivery or transport terms.
+    #[edifact(element = "4052", repr = "an..70")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "delivery_or_transport_terms_description_040", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// Delivery or transport terms description
     ///
     /// Free form description of delivery or transport terms.
+    #[edifact(element = "4052", repr = "an..70")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "delivery_or_transport_terms_description_050", alias = "_050")
+    )]
     pub _050: Option<String>,
 }
 
 /// C106 DOCUMENT/MESSAGE IDENTIFICATION
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C106 {
+    #[edifact(element = "1004", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_identifier", alias = "_010")
+    )]
     pub _010: Option<String>,
+    #[edifact(element = "1056", repr = "an..9")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "version_identifier", alias = "_020")
+    )]
     pub _020: Option<String>,
+    #[edifact(element = "1060", repr = "an..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "revision_identifier", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
 /// C107 - TEXT REFERENCE
 ///
 /// Coded reference to a standard text and its source.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C107 {
+    #[edifact(element = "4441", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "free_text_description_code", alias = "_010")
+    )]
     pub _010: String,
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
 /// C108 - TEXT LITERAL
 ///
 /// Free text; one to five lines.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C108 {
+    #[edifact(element = "4440", repr = "an..512")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "free_text_010", alias = "_010")
+    )]
     pub _010: String,
+    #[edifact(element = "4440", repr = "an..512")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "free_text_020", alias = "_020")
+    )]
     pub _020: Option<String>,
+    #[edifact(element = "4440", repr = "an..512")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "free_text_030", alias = "_030")
+    )]
     pub _030: Option<String>,
+    #[edifact(element = "4440", repr = "an..512")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "free_text_040", alias = "_040")
+    )]
     pub _040: Option<String>,
+    #[edifact(element = "4440", repr = "an..512")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "free_text_050", alias = "_050")
+    )]
     pub _050: Option<String>,
 }
 
@@ -184,239 +449,519 @@ pub struct C108 {
 ///
 /// Measurement value and relevant minimum and maximum
 /// values of the measurement range.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C174 {
     /// Measurement unit code                     M      an..3
+    #[edifact(element = "6411", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurement_unit_code", alias = "_010")
+    )]
     pub _010: String,
     /// Measurement value                         C      an..18
+    #[edifact(element = "6314", repr = "an..18")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurement_value", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Range minimum value                       C      n..18
+    #[edifact(element = "6162", repr = "n..18")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "range_minimum_value", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Range maximum value                       C      n..18
+    #[edifact(element = "6152", repr = "n..18")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "range_maximum_value", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// Significant digits quantity               C      n..2
+    #[edifact(element = "6432", repr = "n..2")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "significant_digits_quantity", alias = "_050")
+    )]
     pub _050: Option<String>,
 }
 
 /// C186 Quantity details
 ///
 /// Quantity information in a transaction, qualified when relevant.
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C186 {
     /// Quantity type code qualifier
     ///
     /// Code qualifying the type of quantity.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "quantity_type_code_qualifier", alias = "_010")
+    )]
     pub _010: _6063,
     /// Quantity
     ///
     /// Alphanumeric representation of a quantity.
+    #[edifact(element = "6060", repr = "an..35")]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "quantity", alias = "_020"))]
     pub _020: String,
     /// Measurement unit code
     ///
     /// Code specifying the unit of measurement.
     /// 1 See UN/ECE Recommendation 20, common code.
+    #[edifact(element = "6411", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurement_unit_code", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
 /// C200 Charge
 ///
 /// Identification of a charge by code and/or by name.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C200 {
     /// Freight and other charges description identifier
     ///
     /// Code identifying freight and other charges.
     /// 1 Use UN/ECE Recommendation No. 2
     /// Freight costs and charges. If not applicable, use appropriate code in combination with 1131/3055.
+    #[edifact(element = "8023", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "freight_and_other_charges_description_identifier",
+            alias = "_010"
+        )
+    )]
     pub _010: Option<String>,
     /// Code list identification code
     ///
     /// Code identifying a code list.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<_1131>,
     /// Code list responsible agency code
     ///
     /// Code specifying the agency responsible for a code list.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<_3055>,
     /// Freight and other charges description
     ///
     /// Free form description of freight and other charges.
+    #[edifact(element = "8022", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "freight_and_other_charges_description", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// Payment arrangement code
     ///
     /// Code specifying the arrangements for a payment.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "payment_arrangement_code", alias = "_050")
+    )]
     pub _050: Option<_4237>,
     /// Item identifier
     ///
     /// To identify an item.
+    #[edifact(element = "7140", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "item_identifier", alias = "_060")
+    )]
     pub _060: Option<String>,
 }
 
 /// C203 Rate/tariff class
 ///
 /// Identification of the applicable rate/tariff class.
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C203 {
     /// Rate or tariff class description code
     ///
     /// Code specifying an applicable rate or tariff class.
     /// 1 User or association defined code. May be used in combination with 1131/3055.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "rate_or_tariff_class_description_code", alias = "_010")
+    )]
     pub _010: _5243,
     /// Code list identification code
     ///
     /// Code identifying a code list.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code_020", alias = "_020")
+    )]
     pub _020: Option<_1131>,
     /// Code list responsible agency code
     ///
     /// Code specifying the agency responsible for a code list.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code_030", alias = "_030")
+    )]
     pub _030: Option<_3055>,
     /// Rate or tariff class description
     ///
     /// Free form description of an applicable rate or tariff class.
+    #[edifact(element = "5242", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "rate_or_tariff_class_description", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// Supplementary rate or tariff code
     ///
     /// Code specifying a supplementary rate or tariff.
     /// 1 User or association defined code. May be used in combination with 1131/3055.
+    #[edifact(element = "5275", repr = "an..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "supplementary_rate_or_tariff_code_050", alias = "_050")
+    )]
     pub _050: Option<String>,
     /// Code list identification code
     ///
     /// Code identifying a code list.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code_060", alias = "_060")
+    )]
     pub _060: Option<_1131>,
     /// Code list responsible agency code
     ///
     /// Code specifying the agency responsible for a code list.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code_070", alias = "_070")
+    )]
     pub _070: Option<_3055>,
     /// Supplementary rate or tariff code
     ///
     /// Code specifying a supplementary rate or tariff.
     /// 1 User or association defined code. May be used in combination with 1131/3055.
+    #[edifact(element = "5275", repr = "an..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "supplementary_rate_or_tariff_code_080", alias = "_080")
+    )]
     pub _080: Option<String>,
     /// Code list identification code
     ///
     /// Code identifying a code list.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code_090", alias = "_090")
+    )]
     pub _090: Option<_1131>,
     /// Code list responsible agency code
     ///
     /// Code specifying the agency responsible for a code list.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code_100", alias = "_100")
+    )]
     pub _100: Option<_3055>,
 }
 
 /// C205 HAZARD CODE
 ///
 /// The identification of the dangerous goods in code.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C205 {
     /// Hazard identification code                M      an..7
+    #[edifact(element = "8351", repr = "an..7")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "hazard_identification_code", alias = "_010")
+    )]
     pub _010: String,
     /// Additional hazard classification
     /// identifier                                C      an..7
+    #[edifact(element = "8078", repr = "an..7")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "additional_hazard_classification", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Hazard code version identifier            C      an..10
+    #[edifact(element = "8092", repr = "an..10")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "hazard_code_version_identifier", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
 /// C208 - IDENTITY NUMBER RANGE
 ///
 /// Goods item identification numbers, start and end of consecutively numbered range.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C208 {
     /// Object identifier                         M      an..35
+    #[edifact(element = "7402", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "object_identifier_010", alias = "_010")
+    )]
     pub _010: String,
     /// Object identifier                         C      an..35
+    #[edifact(element = "7402", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "object_identifier_020", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
 /// C210 - MARKS & LABELS
 ///
 /// Shipping marks on packages in free text; one to ten lines.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C210 {
     /// Shipping marks description                M      an..35
+    #[edifact(element = "7102", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "shipping_marks_description_010", alias = "_010")
+    )]
     pub _010: String,
     /// Shipping marks description                C      an..35
+    #[edifact(element = "7102", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "shipping_marks_description_020", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Shipping marks description                C      an..35
+    #[edifact(element = "7102", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "shipping_marks_description_030", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Shipping marks description                C      an..35
+    #[edifact(element = "7102", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "shipping_marks_description_040", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// Shipping marks description                C      an..35
+    #[edifact(element = "7102", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "shipping_marks_description_050", alias = "_050")
+    )]
     pub _050: Option<String>,
     /// Shipping marks description                C      an..35
+    #[edifact(element = "7102", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "shipping_marks_description_060", alias = "_060")
+    )]
     pub _060: Option<String>,
     /// Shipping marks description                C      an..35
+    #[edifact(element = "7102", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "shipping_marks_description_070", alias = "_070")
+    )]
     pub _070: Option<String>,
     /// Shipping marks description                C      an..35
+    #[edifact(element = "7102", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "shipping_marks_description_080", alias = "_080")
+    )]
     pub _080: Option<String>,
     /// Shipping marks description                C      an..35
+    #[edifact(element = "7102", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "shipping_marks_description_090", alias = "_090")
+    )]
     pub _090: Option<String>,
     /// Shipping marks description                C      an..35
+    #[edifact(element = "7102", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "shipping_marks_description_100", alias = "_100")
+    )]
     pub _100: Option<String>,
 }
 
 /// C211 - DIMENSIONS
 ///
 /// Specification of the dimensions of a transportable unit.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C211 {
     /// Measurement unit code
     ///
     /// Code specifying the unit of measurement.
+    #[edifact(element = "6411", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurement_unit_code", alias = "_010")
+    )]
     pub _010: String,
     /// Length dimension value
     ///
     /// To specify the value of a length dimension.
+    #[edifact(element = "6168", repr = "n..15")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "length_dimension_value", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Width dimension value
     ///
     /// To specify the value of a width dimension.
+    #[edifact(element = "6140", repr = "n..15")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "width_dimension_value", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Height dimension value
     ///
     /// To specify the value of a height dimension.
+    #[edifact(element = "6008", repr = "n..15")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "height_dimension_value", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
 /// C212 Item number identification
 ///
 /// Goods identification for a specified source.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C212 {
     /// Item identifier                             an..35
     ///
     /// To identify an item.
+    #[edifact(element = "7140", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "item_identifier", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Item type identification code               an..3
     ///
     /// Coded identification of an item type.
     /// 1 User or association defined code.
     /// May be used in combination with 1131/3055.
+    #[edifact(element = "7143", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "item_type_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list identification code               an..17
     ///
     /// Code identifying a code list.
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Code list responsible agency code           an..3
     ///
     /// Code specifying the agency responsible for a code list.
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
 /// C213 NUMBER AND TYPE OF PACKAGES
 ///
 /// Number and type of individual parts of a shipment.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C213 {
     /// Package quantity                          C      n..8
+    #[edifact(element = "7224", repr = "n..8")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "package_quantity", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Package type description code             C      an..17
+    #[edifact(element = "7065", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "package_type_description_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list identification code             C      an..17
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Code list responsible agency code         C      an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// Type of packages                          C      an..35
+    #[edifact(element = "7064", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "type_of_packages", alias = "_050")
+    )]
     pub _050: Option<String>,
     /// Packaging related description code        C      an..3
+    #[edifact(element = "7233", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "packaging_related_description_code", alias = "_060")
+    )]
     pub _060: Option<String>,
 }
 
@@ -424,69 +969,172 @@ pub struct C213 {
 ///
 /// Identification of the issuer of a seal on equipment
 /// either by code or by name.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C215 {
     /// Sealing party name code                   C      an..3
+    #[edifact(element = "9303", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "sealing_party_name_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list identification code             C      an..17
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency code         C      an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Sealing party name                        C      an..35
+    #[edifact(element = "9302", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "sealing_party_name", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
 /// C218 HAZARDOUS MATERIAL
 ///
 /// To specify a hazardous material.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C218 {
     /// Hazardous material category name code     C      an..4
+    #[edifact(element = "7419", repr = "an..4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "hazardous_material_category_name_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list identification code             C      an..17
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency code         C      an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Hazardous material category name          C      an..35
+    #[edifact(element = "7418", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "hazardous_material_category_name", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
 /// C219 - MOVEMENT TYPE
 ///
 /// Description of type of service for movement of cargo.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C219 {
     /// Movement type description code        
+    #[edifact(element = "8335", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "movement_type_description_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Movement type description              
+    #[edifact(element = "8334", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "movement_type_description", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
 /// C220 - MODE OF TRANSPORT
 ///
 /// Method of transport code or name. Code preferred.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C220 {
     /// Transport mode name code                  C      an..3
+    #[edifact(element = "8067", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_mode_name_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Transport mode name                       C      an..17
+    #[edifact(element = "8066", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_mode_name", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
 /// C222 - TRANSPORT IDENTIFICATION
 ///
 /// Code and/or name identifying the means of transport.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C222 {
     /// Transport means identification name identifier   
+    #[edifact(element = "8213", repr = "an..9")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "transport_means_identification_name_identifier",
+            alias = "_010"
+        )
+    )]
     pub _010: Option<String>,
     /// Code list identification code          
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency code     
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Transport means identification name   
+    #[edifact(element = "8212", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_means_identification_name", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// Transport means nationality code      
+    #[edifact(element = "8453", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_means_nationality_code", alias = "_050")
+    )]
     pub _050: Option<String>,
 }
 
@@ -494,113 +1142,235 @@ pub struct C222 {
 ///
 /// Temperature at which a vapor can be ignited as per
 /// ISO 1523/73.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C223 {
     /// Shipment flashpoint value                 C      n3
+    #[edifact(element = "7106", repr = "n3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "shipment_flashpoint_value", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Measurement unit code                     C      an..3
+    #[edifact(element = "6411", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurement_unit_code", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
 /// C224 EQUIPMENT SIZE AND TYPE
 ///
 /// Code and or name identifying size and type of equipment. Code preferred.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C224 {
     /// Equipment size and type description code  C      an..10
+    #[edifact(element = "8155", repr = "an..10")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_size_and_type_description_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list identification code             C      an..17
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency code         C      an..3
-    pub _030: Option<String>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
+    pub _030: Option<_3055>,
     /// Equipment size and type description       C      an..35
+    #[edifact(element = "8154", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_size_and_type_description", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
 /// C228 - TRANSPORT MEANS
 ///
 /// Code and/or name identifying the type of means of transport.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C228 {
     /// Transport means description code    
+    #[edifact(element = "8179", repr = "an..8")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_means_description_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Transport means description          
+    #[edifact(element = "8178", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_means_description", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
 /// C229 Charge category
 ///
 /// Identification of a category or a zone of charges.
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C229 {
     /// Charge category code
     ///
     /// Code specifying the category of charges.
     /// 1 User or association defined code. May be used in combination with 1131/3055.   
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "charge_category_code", alias = "_010")
+    )]
     pub _010: _5237,
     /// Code list identification code
     ///
     /// Code identifying a code list.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<_1131>,
     /// Code list responsible agency code
     ///
     /// Code specifying the agency responsible for a code list.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<_3055>,
 }
 
 /// C231 Method of payment
 ///
 /// Code identifying the method of payment.
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C231 {
     /// Transport charges payment method code
     ///
     /// Code specifying the payment method for transport charges.  
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_charges_payment_method_code", alias = "_010")
+    )]
     pub _010: _4215,
     /// Code list identification code
     ///
     /// Code identifying a code list.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<_1131>,
     /// Code list responsible agency code
     ///
     /// Code specifying the agency responsible for a code list.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<_3055>,
 }
 
 /// C232 Government action
 ///
 /// Code indicating a type of government action.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C232 {
     /// Government agency identification code
     ///
     /// Code identifying a government agency.
+    #[edifact(element = "9415", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "government_agency_identification_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Government involvement code
     ///
     /// Code indicating the requirement and status of governmental involvement.
+    #[edifact(element = "9411", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "government_involvement_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Government action code
     ///
     /// Code specifying a type of government action
     /// such as inspection, detention, fumigation, security.
+    #[edifact(element = "9417", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "government_action_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Government procedure code
     ///
     /// Code specifying a government procedure.
+    #[edifact(element = "9353", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "government_procedure_code", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
 /// C233 - SERVICE
 ///
 /// To identify a service (which may constitute an additional component to a basic contract).
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C233 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "service_requirement_code_010", alias = "_010")
+    )]
     pub _010: _7273,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code_020", alias = "_020")
+    )]
     pub _020: Option<_1131>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code_030", alias = "_030")
+    )]
     pub _030: Option<_3055>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "service_requirement_code_040", alias = "_040")
+    )]
     pub _040: Option<_7273>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code_050", alias = "_050")
+    )]
     pub _050: Option<_1131>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code_060", alias = "_060")
+    )]
     pub _060: Option<_3055>,
 }
 
@@ -608,12 +1378,25 @@ pub struct C233 {
 ///
 /// Information on dangerous goods, taken from the
 /// United Nations Dangerous Goods classification.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C234 {
     /// United Nations Dangerous Goods (UNDG)
     /// identifier                                C      n4
+    #[edifact(element = "7124", repr = "n4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "united_nations_dangerous_goods_undg", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Dangerous goods flashpoint value          C      an..8
+    #[edifact(element = "7088", repr = "an..8")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods_flashpoint_value", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
@@ -621,13 +1404,26 @@ pub struct C234 {
 ///
 /// These numbers appear on the hazard identification
 /// placard required on the means of transport.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C235 {
     /// Orange hazard placard upper part
     /// identifier                                C      an..4
+    #[edifact(element = "8158", repr = "an..4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "orange_hazard_placard_upper_part", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Orange hazard placard lower part
     /// identifier                                C      an4
+    #[edifact(element = "8186", repr = "an4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "orange_hazard_placard_lower_part", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
@@ -635,28 +1431,69 @@ pub struct C235 {
 ///
 /// Markings identifying the type of hazardous goods and
 /// similar information.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C236 {
     /// Dangerous goods marking identifier        C      an..4
+    #[edifact(element = "8246", repr = "an..4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods_marking_identifier_010", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Dangerous goods marking identifier        C      an..4
+    #[edifact(element = "8246", repr = "an..4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods_marking_identifier_020", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Dangerous goods marking identifier        C      an..4
+    #[edifact(element = "8246", repr = "an..4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods_marking_identifier_030", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
 /// C237 - EQUIPMENT IDENTIFICATION
 ///
 /// Marks (letters/numbers) identifying equipment.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C237 {
     /// Equipment identifier                      C      an..17
+    #[edifact(element = "8260", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_identifier", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list identification code             C      an..17
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency code         C      an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Country name code                         C      an..3
+    #[edifact(element = "3207", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "country_name_code", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
@@ -664,11 +1501,24 @@ pub struct C237 {
 ///
 /// The temperature under which the goods are (to be) stored
 /// or shipped.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C239 {
     /// Temperature value           C  n..15
+    #[edifact(element = "6246", repr = "n..15")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "temperature_value", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Measurement unit code       C  an..3
+    #[edifact(element = "6411", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurement_unit_code", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
@@ -676,38 +1526,72 @@ pub struct C239 {
 ///
 /// Control total for checking integrity of a message or part
 /// of a message.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C270 {
     /// Control qualifier
     ///
     /// M  an..3
+    #[edifact(element = "6069", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "control_qualifier", alias = "_010")
+    )]
     pub _010: String,
     /// Control value
     ///
     /// M  n..18
+    #[edifact(element = "6066", repr = "n..18")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "control_value", alias = "_020")
+    )]
     pub _020: String,
     /// Measure unit qualifier
     ///
     /// C  an..3
+    #[edifact(element = "6411", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measure_unit_qualifier", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
 /// C280 Range
 ///
 /// Range minimum and maximum limits.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C280 {
     /// Measurement unit code    C  an..3
     ///
     /// 1 See UN/ECE Recommendation 20, common code.
+    #[edifact(element = "6411", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurement_unit_code", alias = "_010")
+    )]
     pub _010: String,
     /// Range minimum value      C  an..18
     ///
     /// To specify the minimum value of a range.
+    #[edifact(element = "6162", repr = "an..18")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "range_minimum_value", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Range maximum value      C  an..18
     ///
     /// To specify the maximum value of a range.
+    #[edifact(element = "6152", repr = "an..18")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "range_maximum_value", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
@@ -716,58 +1600,126 @@ pub struct C280 {
 /// To provide details of reason for, and responsibility
 /// for, use of transportation other than normally
 /// utilized.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C401 {
     /// Excess transportation reason code         M      an..3
+    #[edifact(element = "8457", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "excess_transportation_reason_code", alias = "_010")
+    )]
     pub _010: String,
     /// Excess transportation responsibility code M      an..3
+    #[edifact(element = "8459", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "excess_transportation_responsibility_code_m", alias = "_020")
+    )]
     pub _020: String,
     /// Customer shipment authorisation
     /// identifier                   
+    #[edifact(element = "7130", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "customer_shipment_authorisation", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
 /// C501 Percentage details
 ///
 /// Identification of measurement type.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C501 {
     /// Percentage type code qualifier              C      an..3
     ///
     /// Code qualifying the type of percentage.
+    #[edifact(element = "5245", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "percentage_type_code_qualifier", alias = "_010")
+    )]
     pub _010: String,
     /// Percentage                                  C      an..10
     ///
     /// To specify a percentage.
+    #[edifact(element = "5482", repr = "an..10")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "percentage", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Percentage basis identification code        C      an..3
     ///
     /// Code specifying the basis on which a percentage is calculated.
     ///
     /// 1 User or association defined code. May be used in combination with 1131/3055.
+    #[edifact(element = "5249", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "percentage_basis_identification_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Code list identification code               C      an..17
     ///
     /// Code identifying a code list.
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// Code list responsible agency code           C      an..3
     ///
     /// Code specifying the agency responsible for a code list.
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_050")
+    )]
     pub _050: Option<String>,
 }
 
 /// C502 MEASUREMENT DETAILS
 ///
 /// Identification of measurement type.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C502 {
     /// Measured attribute code                   C      an..3
-    pub _010: Option<String>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measured_attribute_code", alias = "_010")
+    )]
+    pub _010: Option<_6313>,
     /// Measurement significance code             C      an..3
+    #[edifact(element = "6321", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurement_significance_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Non-discrete measurement name code        C      an..17
+    #[edifact(element = "6155", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "non_discrete_measurement_name_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Non-discrete measurement name             C      an..70
+    #[edifact(element = "6154", repr = "an..70")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "non_discrete_measurement_name", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
@@ -775,100 +1727,224 @@ pub struct C502 {
 ///
 /// Identification of document/message by number,
 /// status, source and/or language.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C503 {
     /// Document identifier                       C      an..35
+    #[edifact(element = "1004", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_identifier", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Document status code                      C      an..3
+    #[edifact(element = "1373", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_status_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Document source description               C      an..70
+    #[edifact(element = "1366", repr = "an..70")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_source_description", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Language name code                        C      an..3
+    #[edifact(element = "3453", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "language_name_code", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// Version identifier                        C      an..9
+    #[edifact(element = "1056", repr = "an..9")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "version_identifier", alias = "_050")
+    )]
     pub _050: Option<String>,
     /// Revision identifier                       C      an..6
+    #[edifact(element = "1060", repr = "an..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "revision_identifier", alias = "_060")
+    )]
     pub _060: Option<String>,
 }
 
 /// C504 Currency details
 ///
 /// The usage to which a currency relates.
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C504 {
     /// Currency usage code qualifier
     ///
     /// Code qualifying the usage of a currency.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "currency_usage_code_qualifier", alias = "_010")
+    )]
     pub _010: _6347,
     /// Currency identification code
     ///
     /// Code specifying a monetary unit.
+    #[edifact(element = "6345", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "currency_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Currency type code qualifier
     ///
     /// Code qualifying the type of currency.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "currency_type_code_qualifier", alias = "_030")
+    )]
     pub _030: Option<_6343>,
     /// Currency rate value
     ///
     /// To specify the value of the multiplication factor used in expressing currency units.
+    #[edifact(element = "6348", repr = "n..4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "currency_rate_value", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
 /// C506 - REFERENCE
 ///
 /// Identification of a reference.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C506 {
     /// Reference code qualifier                  M      an..3
-    pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "reference_code_qualifier", alias = "_010")
+    )]
+    pub _010: _1153,
     /// Reference identifier                      C      an..70
+    #[edifact(element = "1154", repr = "an..70")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "reference_identifier", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Document line identifier                  C      an..6
+    #[edifact(element = "1156", repr = "an..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_line_identifier", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Reference version identifier              C      an..35
+    #[edifact(element = "4000", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "reference_version_identifier", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// Revision identifier                       C      an..6
+    #[edifact(element = "1060", repr = "an..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "revision_identifier", alias = "_050")
+    )]
     pub _050: Option<String>,
 }
 
 /// C507 DTM  DATE/TIME/PERIOD
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C507 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "date_or_time_or_period_function_code_qualifier",
+            alias = "_010"
+        )
+    )]
     pub _010: _2005,
+    #[edifact(element = "2380", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_or_time_or_period_value", alias = "_020")
+    )]
     pub _020: Option<String>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_or_time_or_period_format_code", alias = "_030")
+    )]
     pub _030: Option<_2379>,
 }
 
 /// C509 Price information
 ///
 /// Identification of price type, price and related details.
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C509 {
     /// Price code qualifier
     ///
     /// Code qualifying a price.
     /// 1 Code set of 5387 may be used also.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "price_code_qualifier", alias = "_010")
+    )]
     pub _010: _5125,
     /// Price amount
     ///
     /// To specify a price.
+    #[edifact(element = "5118", repr = "n..15")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "price_amount", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Price type code
     ///
     /// Code specifying the type of price.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "price_type_code", alias = "_030")
+    )]
     pub _030: Option<_5375>,
     /// Price specification code
     ///
     /// Code identifying pricing specification.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "price_specification_code", alias = "_040")
+    )]
     pub _040: Option<_5387>,
     /// Unit price basis value
     ///
     /// To specify the basis for a unit price.
+    #[edifact(element = "5284", repr = "n..9")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "unit_price_basis_value", alias = "_050")
+    )]
     pub _050: Option<String>,
     /// Measurement unit code
     ///
     /// Code specifying the unit of measurement.
     /// 1 See UN/ECE Recommendation 20, common code.
+    #[edifact(element = "6411", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurement_unit_code", alias = "_060")
+    )]
     pub _060: Option<String>,
 }
 
@@ -876,67 +1952,154 @@ pub struct C509 {
 ///
 /// Amount of goods or services stated as a
 /// monetary amount in a specified currency.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C516 {
     /// Monetary amount type code qualifier
     ///
     /// Code qualifying the type of monetary amount.
+    #[edifact(element = "5025", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "monetary_amount_type_code_qualifier", alias = "_010")
+    )]
     pub _010: String,
     /// Monetary amount
     ///
     /// To specify a monetary amount.
+    #[edifact(element = "5004", repr = "n..18")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "monetary_amount", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Currency identification code
     ///
     /// Code specifying a monetary unit.
     ///
     /// 1 Use ISO 4217 three alpha code.
+    #[edifact(element = "6345", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "currency_identification_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Currency type code qualifier
     ///
     /// Code qualifying the type of currency.
+    #[edifact(element = "6343", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "currency_type_code_qualifier", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// Status description code
     ///
     /// Code specifying a status.
     ///
     /// 1 For transport status, use UN/ECE Recommendation 24.
+    #[edifact(element = "4405", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "status_description_code", alias = "_050")
+    )]
     pub _050: Option<String>,
 }
 
 /// C517 - LOCATION IDENTIFICATION
 ///
 /// Identification of a location by code or name.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C517 {
     /// Location name code
     ///
     /// Code specifying the name of the location.
+    #[edifact(element = "3225", repr = "an..25")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "location_name_code", alias = "_010")
+    )]
     pub _010: Option<String>,
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
+    #[edifact(element = "3224", repr = "an..256")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "location_name", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
 /// C519 - RELATED LOCATION ONE IDENTIFICATION
 ///
 /// Identification the first related location by code or name.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C519 {
+    #[edifact(element = "3223", repr = "an..25")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "first_related_location_name_code", alias = "_010")
+    )]
     pub _010: Option<String>,
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
+    #[edifact(element = "3222", repr = "an..70")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "first_related_location_name", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
 /// C523 NUMBER OF UNIT DETAILS
 ///
 /// Identification of number of units and its purpose.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C523 {
     /// Units quantity                            C      n..15
+    #[edifact(element = "6350", repr = "n..15")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "units_quantity", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Unit type code qualifier                  C      an..3
+    #[edifact(element = "6353", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "unit_type_code_qualifier", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
@@ -944,139 +2107,317 @@ pub struct C523 {
 ///
 /// Instruction for the handling of goods, products or
 /// articles in shipment, storage etc.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C524 {
     /// Handling instruction description code     C      an..3
+    #[edifact(element = "4079", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "handling_instruction_description_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list identification code             C      an..17
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency code         C      an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Handling instruction description          C      an..70
+    #[edifact(element = "4078", repr = "an..70")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "handling_instruction_description", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
 /// C528 Commodity/rate detail
 ///
 /// Identification of commodity/rates.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C528 {
     /// Commodity identification code
     ///
     /// Code identifying a commodity for Customs, transport or statistical purposes (generic term).
     /// 1 User or association defined code. May be used in combination with 1131/3055.
+    #[edifact(element = "7357", repr = "an..18")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "commodity_identification_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list identification code
     ///
     /// Code identifying a code list.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<_1131>,
     /// Code list responsible agency code
     ///
     /// Code specifying the agency responsible for a code list.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<_3055>,
 }
 
 /// C536 - CONTRACT AND CARRIAGE CONDITION
 ///
 /// To identify a contract and carriage condition.
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C536 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contract_and_carriage_condition_code", alias = "_010")
+    )]
     pub _010: _4065,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<_1131>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<_3055>,
 }
 
 /// C537 - TRANSPORT PRIORITY
 ///
 /// To indicate the priority of requested transport service.
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C537 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_service_priority_code", alias = "_010")
+    )]
     pub _010: _4219,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<_1131>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<_3055>,
 }
 
 /// C553 - RELATED LOCATION TWO IDENTIFICATION
 ///
 /// Identification of second related location by code or name.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C553 {
+    #[edifact(element = "3233", repr = "an..25")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "second_related_location_name_code", alias = "_010")
+    )]
     pub _010: Option<String>,
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
+    #[edifact(element = "3232", repr = "an..70")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "second_related_location_name", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
 /// C554 Rate/tariff class detail
 ///
 /// Identification of the applicable rate/tariff class.
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C554 {
     /// Rate or tariff class description code
     ///
     /// Code specifying an applicable rate or tariff class.
     /// 1 User or association defined code. May be used in combination with 1131/3055.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "rate_or_tariff_class_description_code", alias = "_010")
+    )]
     pub _010: Option<_5243>,
     /// Code list identification code
     ///
     /// Code identifying a code list.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<_1131>,
     /// Code list responsible agency code
     ///
     /// Code specifying the agency responsible for a code list.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<_3055>,
 }
 
 /// C555 - STATUS
 ///
 /// To specify a status.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C555 {
     /// Status description code                
+    #[edifact(element = "4405", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "status_description_code", alias = "_010")
+    )]
     pub _010: String,
     /// Code list identification code           
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency code      
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Status description                      
+    #[edifact(element = "4404", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "status_description", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
 /// C556 - STATUS REASON
 ///
 /// To specify the reason for a status.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C556 {
     /// Status reason description code       
+    #[edifact(element = "9013", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "status_reason_description_code", alias = "_010")
+    )]
     pub _010: String,
     /// Code list identification code         
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency code    
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Status reason description              
+    #[edifact(element = "9012", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "status_reason_description", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
 /// C601 - STATUS CATEGORY
 ///
 /// To specify the category of the status.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C601 {
     /// Status category code                   
+    #[edifact(element = "9015", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "status_category_code", alias = "_010")
+    )]
     pub _010: String,
     /// Code list identification code           
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency code      
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
 /// C703 - NATURE OF CARGO
 ///
 /// Rough classification of a type of cargo.
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C703 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "cargo_type_classification_code", alias = "_010")
+    )]
     pub _010: _7085,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<_1131>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<_3055>,
 }
 
@@ -1084,45 +2425,110 @@ pub struct C703 {
 ///
 /// To specify a part of a country (eg county or part of
 /// a city).
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C819 {
     /// Country sub-entity name code              C      an..9
+    #[edifact(element = "3229", repr = "an..9")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "country_sub_entity_name_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list identification code             C      an..17
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<_1131>,
     /// Code list responsible agency code         C      an..3
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<_3055>,
     /// Country sub-entity name                   C      an..35
+    #[edifact(element = "3228", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "country_sub_entity_name", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
 /// C821 Type of damage
 ///
 /// To specify the type of damage to an object.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C821 {
     /// Damage type description code              C      an..3
+    #[edifact(element = "7501", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "damage_type_description_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list identification code             C      an..17
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency code         C      an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Damage type description                   C      an..35
+    #[edifact(element = "7500", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "damage_type_description", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
 /// C822 Damage area
 ///
 /// To specify where the damage is on an object.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C822 {
     /// Damage area description code              C      an..4
+    #[edifact(element = "7503", repr = "an..4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "damage_area_description_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list identification code             C      an..17
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency code         C      an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Damage area description                   C      an..35
+    #[edifact(element = "7502", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "damage_area_description", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
@@ -1130,15 +2536,38 @@ pub struct C822 {
 ///
 /// To identify the type of unit/component
 /// of an object (e.g. lock, door, tyre).
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C823 {
     /// Unit or component type description code   C      an..3
+    #[edifact(element = "7505", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "unit_or_component_type_description_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list identification code             C      an..17
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency code         C      an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Unit or component type description        C      an..35
+    #[edifact(element = "7504", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "unit_or_component_type_description", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
@@ -1146,30 +2575,76 @@ pub struct C823 {
 ///
 /// To identify the material of which
 /// a component is composed (e.g. steel, plastics).
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C824 {
     /// Component material description code       C      an..3
+    #[edifact(element = "7507", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "component_material_description_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list identification code             C      an..17
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency code         C      an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Component material description            C      an..35
+    #[edifact(element = "7506", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "component_material_description", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
 /// C825 Damage severity
 ///
 /// To specify the severity of damage to an object.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C825 {
     /// Damage severity description code          C      an..3
+    #[edifact(element = "7509", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "damage_severity_description_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list identification code             C      an..17
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency code         C      an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Damage severity description               C      an..35
+    #[edifact(element = "7508", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "damage_severity_description", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
@@ -1177,15 +2652,41 @@ pub struct C825 {
 ///
 /// To indicate an action which has been taken or
 ///  is to be taken (e.g. in relation to a certain object).
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C826 {
     /// Action request/notification description code         C      an..3
+    #[edifact(element = "1229", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "action_request_notification_description_code",
+            alias = "_010"
+        )
+    )]
     pub _010: Option<String>,
     /// Code list identification code                        C      an..17
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency code                    C      an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Action request/notification description              C      an..35
+    #[edifact(element = "1228", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "action_request_notification_description", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
@@ -1194,13 +2695,31 @@ pub struct C826 {
 /// Specification of the type of marking that reflects
 /// the method that was used and the conventions adhered
 /// to for marking (e.g. of packages).
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C827 {
     /// Marking type code                         M      an..3
+    #[edifact(element = "7511", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "marking_type_code", alias = "_010")
+    )]
     pub _010: String,
     /// Code list identification code             C      an..17
+    #[edifact(element = "1131", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency code         C      an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
@@ -1208,10 +2727,28 @@ pub struct C827 {
 ///
 /// Identification of the agency controlling the syntax and indication of syntax level, plus the syntax version number.
 #[derive(
-    Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement, PartialEq, Eq, Default,
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    PartialEq,
+    Eq,
+    Default,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct S001 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "syntax_identifier", alias = "_010")
+    )]
     pub _010: _0001,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "syntax_version_number", alias = "_020")
+    )]
     pub _020: _0002,
 }
 
@@ -1219,18 +2756,42 @@ pub struct S001 {
 ///
 /// Identification of the sender of the interchange.
 #[derive(
-    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, PartialEq, Eq,
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    DisplayInnerSegment,
+    ParseElement,
+    PartialEq,
+    Eq,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct S002 {
     /// Sender identification
     ///
     /// Name or coded representation of the sender of a data interchange.
     /// Code or name as specified in IA.
+    #[edifact(element = "0004", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "sender_identification", alias = "_010")
+    )]
     pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "partner_identification_code_qualifier", alias = "_020")
+    )]
     pub _020: Option<_0007>,
     /// Address for reverse routing
     ///
     /// Address specified by the sender of an interchange to be included by the recipient in the response interchanges to facilitate internal routing.
+    #[edifact(element = "0008", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "address_for_reverse_routing", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
@@ -1238,19 +2799,43 @@ pub struct S002 {
 ///
 /// Identification of the recipient of the interchange.
 #[derive(
-    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, PartialEq, Eq,
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    DisplayInnerSegment,
+    ParseElement,
+    PartialEq,
+    Eq,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct S003 {
     /// Recipient identification
     ///
     /// Name or coded representation of the recipient of a data interchange.
     /// Code or name as specified in IA.
+    #[edifact(element = "0010", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "recipient_identification", alias = "_010")
+    )]
     pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "partner_identification_code_qualifier", alias = "_020")
+    )]
     pub _020: Option<_0007>,
     /// Routing address
     ///
     /// Address specified by the recipient of an interchange to be included by the sender and used by the recipient for routing of received interchanges inside his organization.
     /// If used, normally coded sub-address for onward routing.
+    #[edifact(element = "0014", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "routing_address", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
@@ -1258,18 +2843,38 @@ pub struct S003 {
 ///
 /// Date/time of preparation of the interchange.
 #[derive(
-    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, PartialEq, Eq,
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    DisplayInnerSegment,
+    ParseElement,
+    PartialEq,
+    Eq,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct S004 {
     /// Date of preparation
     ///
     /// Local date when an interchange or a functional group was prepared.
     /// YYMMDD
+    #[edifact(element = "0017", repr = "n..8")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_of_preparation", alias = "_010")
+    )]
     pub _010: String,
     /// Time of preparation
     ///
     /// Local time of day when an interchange or a functional group was prepared.
     /// HHMM
+    #[edifact(element = "0019", repr = "n4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "time_of_preparation", alias = "_020")
+    )]
     pub _020: String,
 }
 
@@ -1277,144 +2882,412 @@ pub struct S004 {
 ///
 /// Reference or password as agreed between the communicating partners.
 #[derive(
-    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, PartialEq, Eq,
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    DisplayInnerSegment,
+    ParseElement,
+    PartialEq,
+    Eq,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct S005 {
     /// Recipient's reference/password
     ///
     /// Unique reference assigned by the recipient to the data interchange or a password
     /// to the recipient's system or to a third party network as specified in the partners interchange agreement.
     /// As specified in IA. May be password to recipient's system or to third party network.
+    #[edifact(element = "0022", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "recipients_reference_password", alias = "_010")
+    )]
     pub _010: String,
     /// Recipient's reference/password qualifier
     ///
     /// Qualifier for the recipient's reference or password.
     /// Used if specified in IA.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "recipients_reference_password_qualifier", alias = "_020")
+    )]
     pub _020: Option<_0025>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment)]
+/// Application sender identification
+///
+/// Identification of the sender division, department etc. from which a group of messages is sent.
+#[derive(
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    DisplayInnerSegment,
+    ParseElement,
+    PartialEq,
+    Eq,
+    Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct S006 {
+    /// Application sender identification
+    ///
+    /// Name or code identifying the division, department etc. within the originating sender's organization,
+    /// which may be the application sender.
+    /// M   an..35
+    #[edifact(element = "0040", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "application_sender_identification", alias = "_010")
+    )]
+    pub _010: String,
+    /// Partner identification code qualifier
+    ///
+    /// C   an..4
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "partner_identification_code_qualifier", alias = "_020")
+    )]
+    pub _020: Option<_0007>,
+}
+
+/// Application recipient identification
+///
+/// Identification of the recipient division, department etc. for which a group of messages is intended.
+#[derive(
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    DisplayInnerSegment,
+    ParseElement,
+    PartialEq,
+    Eq,
+    Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct S007 {
+    /// Application recipient identification
+    ///
+    /// Name or code identifying the division, department etc. within the recipient's organization
+    /// for which the group of messages is intended.
+    /// M   an..35
+    #[edifact(element = "0044", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "application_recipient_identification", alias = "_010")
+    )]
+    pub _010: String,
+    /// Partner identification code qualifier
+    ///
+    /// C   an..4
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "partner_identification_code_qualifier", alias = "_020")
+    )]
+    pub _020: Option<_0007>,
+}
+
+/// Message version
+///
+/// Specification of the message version and release number of the messages in the functional group.
+#[derive(
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    DisplayInnerSegment,
+    ParseElement,
+    PartialEq,
+    Eq,
+    Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct S008 {
+    /// Message version number
+    ///
+    /// M   an..3
+    #[edifact(element = "0052", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_version_number", alias = "_010")
+    )]
+    pub _010: String,
+    /// Message release number
+    ///
+    /// M   an..3
+    #[edifact(element = "0054", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_release_number", alias = "_020")
+    )]
+    pub _020: String,
+    /// Association assigned code
+    ///
+    /// C   an..6
+    #[edifact(element = "0057", repr = "an..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "association_assigned_code", alias = "_030")
+    )]
+    pub _030: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct S009 {
     /// Message type
     ///
     /// M   an1..6
+    #[edifact(element = "0065", repr = "an1..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_type", alias = "_010")
+    )]
     pub _010: String,
     /// Message version number
     ///
     /// M   an1..3
+    #[edifact(element = "0052", repr = "an1..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_version_number", alias = "_020")
+    )]
     pub _020: String,
     /// Message release number
     ///
     /// M   an1..3
+    #[edifact(element = "0054", repr = "an1..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_release_number", alias = "_030")
+    )]
     pub _030: String,
     /// Controlling agency, coded
     ///
     /// M   an1..3
+    #[edifact(element = "0051", repr = "an1..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "controlling_agency_coded", alias = "_040")
+    )]
     pub _040: String,
     /// Association assigned code
     ///
     /// C   an1..6
+    #[edifact(element = "0057", repr = "an1..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "association_assigned_code", alias = "_050")
+    )]
     pub _050: Option<String>,
     /// Code list directory version number
     ///
     /// C   an1..6
+    #[edifact(element = "0110", repr = "an1..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_directory_version_number", alias = "_060")
+    )]
     pub _060: Option<String>,
     /// Message type sub-function identification
     ///
     /// C   an1..6
+    #[edifact(element = "0113", repr = "an1..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_type_sub_function_identification", alias = "_070")
+    )]
     pub _070: Option<String>,
 }
 
-impl<'a> Parser<&'a str, S009, nom::error::Error<&'a str>> for S009 {
+impl<'a> Parser<&'a str, S009, crate::util::Error<&'a str>> for S009 {
     fn parse(input: &'a str) -> IResult<&'a str, S009> {
         let (_, vars) = crate::util::parse_colon_section(input)?;
-        let v = vars.get(1).unwrap().to_string();
-        let r = vars.get(2).unwrap().to_string();
+        let mandatory = |idx: usize, position: &'static str| match vars.get(idx) {
+            Some(x) if !x.is_empty() => Ok(crate::util::unescape(x)),
+            _ => Err(nom::Err::Failure(
+                crate::util::Error::missing(input, "String").within(position),
+            )),
+        };
+        let v = mandatory(1, "020")?;
+        let r = mandatory(2, "030")?;
         if format!("{v}{r}") != VERSION {
-            return Err(nom::Err::Error(nom::error::Error::new(
-                "File supplied EDIFACT Version/Release in UNH segment. Please use the correct parsing feature",
-                nom::error::ErrorKind::Verify,
+            // the supplied Version/Release needs the parser of another directory
+            return Err(nom::Err::Error(crate::util::Error::unexpected_value(
+                input,
+                &format!("{v}{r}"),
+                VERSION,
             )));
         }
         let output = S009 {
-            _010: vars.first().unwrap().to_string(),
+            _010: mandatory(0, "010")?,
             _020: v,
             _030: r,
-            _040: vars.get(3).unwrap().to_string(),
-            _050: vars.get(4).map(|x| x.to_string()),
-            _060: vars.get(5).map(|x| x.to_string()),
-            _070: vars.get(6).map(|x| x.to_string()),
+            _040: mandatory(3, "040")?,
+            _050: vars.get(4).map(|x| crate::util::unescape(x)),
+            _060: vars.get(5).map(|x| crate::util::unescape(x)),
+            _070: vars.get(6).map(|x| crate::util::unescape(x)),
         };
         Ok(("", output))
     }
 }
 
 /// STATUS OF THE TRANSFER
-#[derive(Debug, Serialize, Deserialize, Default, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct S010 {
     /// Sequence of transfers
     /// M  n..2
+    #[edifact(element = "0070", repr = "n..2")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "sequence_of_transfers", alias = "_010")
+    )]
     pub _010: String,
     /// First and last transfer
     ///
     /// C  a1
+    #[edifact(element = "0073", repr = "a1")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "first_and_last_transfer", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct S016 {
     /// Message subset identification
     ///
     /// Coded identification of a message subset, assigned by its controlling agency.
+    #[edifact(element = "0115", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_subset_identification", alias = "_010")
+    )]
     pub _010: String,
     /// Message subset version number
     ///
     /// Version number of the message subset.
+    #[edifact(element = "0116", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_subset_version_number", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Message subset release number
     ///
     /// Release number within the message subset version number.
+    #[edifact(element = "0118", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_subset_release_number", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Controlling agency, coded
+    #[edifact(element = "0051", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "controlling_agency_coded", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct S017 {
     /// Message implementation guideline identification
     ///
     /// Coded identification of the message implementation guideline, assigned by its controlling agency.
+    #[edifact(element = "0121", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "message_implementation_guideline_identification",
+            alias = "_010"
+        )
+    )]
     pub _010: String,
     /// Message implementation guideline version number
     ///
     /// Version number of the message implementation guideline.
+    #[edifact(element = "0122", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "message_implementation_guideline_version_number",
+            alias = "_020"
+        )
+    )]
     pub _020: Option<String>,
     /// Message implementation guideline release number
     ///
     /// Release number within the message implementation guideline version number.
+    #[edifact(element = "0124", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "message_implementation_guideline_release_number",
+            alias = "_030"
+        )
+    )]
     pub _030: Option<String>,
     /// Controlling agency, coded
     ///
     /// Code identifying a controlling agency.
+    #[edifact(element = "0051", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "controlling_agency_coded", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default, DisplayInnerSegment, ParseElement)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayInnerSegment, ParseElement, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct S018 {
     /// Scenario identification
     ///
     /// Code identifying scenario.
+    #[edifact(element = "0127", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "scenario_identification", alias = "_010")
+    )]
     pub _010: String,
     /// Scenario version number
     ///
     /// Version number of a scenario.
+    #[edifact(element = "0128", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "scenario_version_number", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Scenario release number
     ///
     /// Release number within the scenario version number.
+    #[edifact(element = "0130", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "scenario_release_number", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Controlling agency, coded
     ///
     /// Code identifying a controlling agency.
+    #[edifact(element = "0051", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "controlling_agency_coded", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
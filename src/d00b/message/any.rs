@@ -0,0 +1,145 @@
+use crate::control::{self, Finalize};
+use crate::d00b::*;
+use crate::util::{
+    delimiters, many_until_segment, parse_colon_section, parse_line, parse_raw_line, Error, IResult,
+};
+use crate::validate::{Validate, Violation};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Any message of the D00B directory
+///
+/// The message type is taken from the message identifier (S009) in UNH.
+/// Messages without bindings are kept as raw segments.
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[allow(clippy::large_enum_variant)]
+pub enum AnyMessage {
+    COPARN(COPARN),
+    IFTMIN(IFTMIN),
+    IFTSTA(IFTSTA),
+    Unknown {
+        unh: UNH,
+        /// segments between UNH and UNT, without segment terminator
+        raw_segments: Vec<String>,
+        unt: UNT,
+    },
+}
+
+impl AnyMessage {
+    /// Message header of the message
+    pub fn unh(&self) -> &UNH {
+        match self {
+            AnyMessage::COPARN(msg) => &msg.unh,
+            AnyMessage::IFTMIN(msg) => &msg.unh,
+            AnyMessage::IFTSTA(msg) => &msg.unh,
+            AnyMessage::Unknown { unh, .. } => unh,
+        }
+    }
+}
+
+impl Default for AnyMessage {
+    fn default() -> Self {
+        AnyMessage::Unknown {
+            unh: UNH::default(),
+            raw_segments: vec![],
+            unt: UNT::default(),
+        }
+    }
+}
+
+impl<'a> Parser<&'a str, AnyMessage, Error<&'a str>> for AnyMessage {
+    fn parse(input: &'a str) -> IResult<&'a str, AnyMessage> {
+        // peek at the message identifier
+        let (_, vars) = parse_line(input, "UNH")?;
+        let (_, s009) = parse_colon_section(vars.get(1).unwrap_or(&""))?;
+        let version = s009.get(1).zip(s009.get(2)).map(|(v, r)| format!("{v}{r}"));
+        if version.as_deref() != Some(VERSION) {
+            let version = version.unwrap_or_default();
+            return Err(nom::Err::Error(
+                Error::unexpected_value(vars.get(1).copied().unwrap_or(input), &version, VERSION)
+                    .within("020")
+                    .within("UNH"),
+            ));
+        }
+        match s009.first().copied() {
+            Some("COPARN") => {
+                COPARN::parse(input).map(|(rest, msg)| (rest, AnyMessage::COPARN(msg)))
+            }
+            Some("IFTMIN") => {
+                IFTMIN::parse(input).map(|(rest, msg)| (rest, AnyMessage::IFTMIN(msg)))
+            }
+            Some("IFTSTA") => {
+                IFTSTA::parse(input).map(|(rest, msg)| (rest, AnyMessage::IFTSTA(msg)))
+            }
+            _ => {
+                let (rest, unh) = UNH::parse(input)?;
+                let (rest, raw_segments) = many_until_segment(rest, &["UNT"], parse_raw_line)?;
+                let (rest, unt) = UNT::parse(rest)?;
+                let raw_segments = raw_segments.into_iter().map(String::from).collect();
+                Ok((
+                    rest,
+                    AnyMessage::Unknown {
+                        unh,
+                        raw_segments,
+                        unt,
+                    },
+                ))
+            }
+        }
+    }
+}
+
+impl fmt::Display for AnyMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnyMessage::COPARN(msg) => write!(f, "{msg}"),
+            AnyMessage::IFTMIN(msg) => write!(f, "{msg}"),
+            AnyMessage::IFTSTA(msg) => write!(f, "{msg}"),
+            AnyMessage::Unknown {
+                unh,
+                raw_segments,
+                unt,
+            } => {
+                let delimiters = delimiters();
+                writeln!(f, "{unh}{}", delimiters.segment)?;
+                for segment in raw_segments {
+                    writeln!(f, "{segment}{}", delimiters.segment)?;
+                }
+                write!(f, "{unt}{}", delimiters.segment)
+            }
+        }
+    }
+}
+
+impl Validate for AnyMessage {
+    /// Messages without bindings have no structure to check, only their UNT.
+    fn validate(&self) -> Vec<Violation> {
+        match self {
+            AnyMessage::COPARN(msg) => msg.validate(),
+            AnyMessage::IFTMIN(msg) => msg.validate(),
+            AnyMessage::IFTSTA(msg) => msg.validate(),
+            AnyMessage::Unknown { unh, unt, .. } => {
+                control::check_trailer(&self.to_string(), &unh._010, &unt._010, &unt._020)
+            }
+        }
+    }
+}
+
+impl Finalize for AnyMessage {
+    fn finalize(&mut self) {
+        match self {
+            AnyMessage::COPARN(msg) => msg.finalize(),
+            AnyMessage::IFTMIN(msg) => msg.finalize(),
+            AnyMessage::IFTSTA(msg) => msg.finalize(),
+            AnyMessage::Unknown {
+                unh,
+                raw_segments,
+                unt,
+            } => {
+                unt._010 = (raw_segments.len() + 2).to_string();
+                unt._020 = unh._010.clone();
+            }
+        }
+    }
+}
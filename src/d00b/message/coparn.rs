@@ -1,186 +1,630 @@
 use crate::d00b::*;
-use edifact_types_macros::{DisplayEdifact, DisplayEdifactSg, ParseMsg, ParseSg};
+use edifact_types_macros::{DisplayEdifact, DisplayEdifactSg, ParseMsg, ParseSg, Validate};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Default, Debug, Serialize, Deserialize, DisplayEdifact, ParseMsg)]
+#[derive(Default, Debug, Serialize, Deserialize, DisplayEdifact, ParseMsg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARN {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_header", alias = "unh")
+    )]
     pub unh: UNH,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "beginning_of_message", alias = "bgm")
+    )]
     pub bgm: BGM,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_movement_details", alias = "tmd")
+    )]
     pub tmd: Option<TMD>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_service_requirements", alias = "tsr")
+    )]
     pub tsr: Vec<TSR>,
+    #[edifact(max = 99)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Vec<FTX>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Vec<LOC>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "reference_group", alias = "segment_group_1")
+    )]
     pub segment_group_1: Vec<COPARNSegmentgroup1>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "details_of_transport_group", alias = "segment_group_2")
+    )]
     pub segment_group_2: Vec<COPARNSegmentgroup2>,
+    #[edifact(min = 1, max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address_group", alias = "segment_group_4")
+    )]
     pub segment_group_4: Vec<COPARNSegmentgroup4>,
+    #[edifact(max = 999)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "goods_item_details_group", alias = "segment_group_6")
+    )]
     pub segment_group_6: Vec<COPARNSegmentgroup6>,
+    #[edifact(min = 1, max = 9999)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_details_group", alias = "segment_group_13")
+    )]
     pub segment_group_13: Vec<COPARNSegmentgroup13>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "control_total", alias = "cnt")
+    )]
     pub cnt: Option<CNT>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_trailer", alias = "unt")
+    )]
     pub unt: UNT,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup1 {
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: RFF,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup10 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods", alias = "dgs")
+    )]
     pub dgs: DGS,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Vec<FTX>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements", alias = "mea")
+    )]
     pub mea: Vec<MEA>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_information_group", alias = "segment_group_11")
+    )]
     pub segment_group_11: Vec<COPARNSegmentgroup11>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup11 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_information", alias = "cta")
+    )]
     pub cta: CTA,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "communication_contact", alias = "com")
+    )]
     pub com: Vec<COM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup12 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "temperature", alias = "tmp")
+    )]
     pub tmp: TMP,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "range_details", alias = "rng")
+    )]
     pub rng: Option<RNG>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup13 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_details", alias = "eqd")
+    )]
     pub eqd: EQD,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: Vec<RFF>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_units", alias = "eqn")
+    )]
     pub eqn: Option<EQN>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_movement_details", alias = "tmd")
+    )]
     pub tmd: Vec<TMD>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_service_requirements", alias = "tsr")
+    )]
     pub tsr: Vec<TSR>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Vec<LOC>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements", alias = "mea")
+    )]
     pub mea: Vec<MEA>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dimensions", alias = "dim")
+    )]
     pub dim: Vec<DIM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "temperature_group", alias = "segment_group_14")
+    )]
     pub segment_group_14: Vec<COPARNSegmentgroup14>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "seal_number", alias = "sel")
+    )]
     pub sel: Vec<SEL>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Vec<FTX>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "percentage_details", alias = "pcd")
+    )]
     pub pcd: Vec<PCD>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods_group", alias = "segment_group_15")
+    )]
     pub segment_group_15: Vec<COPARNSegmentgroup15>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "monetary_amount", alias = "moa")
+    )]
     pub moa: Vec<MOA>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "governmental_requirements", alias = "gor")
+    )]
     pub gor: Vec<GOR>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "attached_equipment", alias = "eqa")
+    )]
     pub eqa: Option<EQA>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "component_details", alias = "cod")
+    )]
     pub cod: Option<COD>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "handling_instructions", alias = "han")
+    )]
     pub han: Vec<HAN>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "damage_group", alias = "segment_group_17")
+    )]
     pub segment_group_17: Vec<COPARNSegmentgroup17>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "details_of_transport_group", alias = "segment_group_18")
+    )]
     pub segment_group_18: Vec<COPARNSegmentgroup18>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address_group", alias = "segment_group_20")
+    )]
     pub segment_group_20: Vec<COPARNSegmentgroup20>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup14 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "temperature", alias = "tmp")
+    )]
     pub tmp: TMP,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "range_details", alias = "rng")
+    )]
     pub rng: Option<RNG>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup15 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods", alias = "dgs")
+    )]
     pub dgs: DGS,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Vec<FTX>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements", alias = "mea")
+    )]
     pub mea: Vec<MEA>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_information_group", alias = "segment_group_16")
+    )]
     pub segment_group_16: Vec<COPARNSegmentgroup16>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup16 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_information", alias = "cta")
+    )]
     pub cta: CTA,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "communication_contact", alias = "com")
+    )]
     pub com: Vec<COM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup17 {
+    #[cfg_attr(feature = "semantic-names", serde(rename = "damage", alias = "dam"))]
     pub dam: DAM,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "component_details", alias = "cod")
+    )]
     pub cod: Option<COD>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup18 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "details_of_transport", alias = "tdt")
+    )]
     pub tdt: TDT,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "place_location_identification_group",
+            alias = "segment_group_19"
+        )
+    )]
     pub segment_group_19: Vec<COPARNSegmentgroup19>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup19 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: LOC,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup2 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "details_of_transport", alias = "tdt")
+    )]
     pub tdt: TDT,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: Vec<RFF>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "place_location_identification_group",
+            alias = "segment_group_3"
+        )
+    )]
     pub segment_group_3: Vec<COPARNSegmentgroup3>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup20 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address", alias = "nad")
+    )]
     pub nad: NAD,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Option<DTM>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_information", alias = "cta")
+    )]
     pub cta: Option<CTA>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "communication_contact", alias = "com")
+    )]
     pub com: Option<COM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: Vec<RFF>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup3 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: LOC,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup4 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address", alias = "nad")
+    )]
     pub nad: NAD,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_information_group", alias = "segment_group_5")
+    )]
     pub segment_group_5: Vec<COPARNSegmentgroup5>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: Vec<RFF>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup5 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_information", alias = "cta")
+    )]
     pub cta: CTA,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "communication_contact", alias = "com")
+    )]
     pub com: Vec<COM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup6 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "goods_item_details", alias = "gid")
+    )]
     pub gid: GID,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "handling_instructions", alias = "han")
+    )]
     pub han: Vec<HAN>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Vec<FTX>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: Vec<RFF>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "additional_product_id", alias = "pia")
+    )]
     pub pia: Vec<PIA>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address_group", alias = "segment_group_7")
+    )]
     pub segment_group_7: Vec<COPARNSegmentgroup7>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements", alias = "mea")
+    )]
     pub mea: Vec<MEA>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dimensions", alias = "dim")
+    )]
     pub dim: Vec<DIM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_details_group", alias = "segment_group_8")
+    )]
     pub segment_group_8: Vec<COPARNSegmentgroup8>,
+    #[edifact(max = 999)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "split_goods_placement_group", alias = "segment_group_9")
+    )]
     pub segment_group_9: Vec<COPARNSegmentgroup9>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods_group", alias = "segment_group_10")
+    )]
     pub segment_group_10: Vec<COPARNSegmentgroup10>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "temperature_group", alias = "segment_group_12")
+    )]
     pub segment_group_12: Vec<COPARNSegmentgroup12>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup7 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address", alias = "nad")
+    )]
     pub nad: NAD,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: Vec<RFF>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup8 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_details", alias = "doc")
+    )]
     pub doc: DOC,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Vec<LOC>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPARNSegmentgroup9 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "split_goods_placement", alias = "sgp")
+    )]
     pub sgp: SGP,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements", alias = "mea")
+    )]
     pub mea: Vec<MEA>,
 }
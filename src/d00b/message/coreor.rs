@@ -1,17 +1,29 @@
 use crate::d00b::*;
-use crate::util::Parser;
-use edifact_types_macros::DisplayEdifact;
-use nom::IResult;
+use crate::util::{IResult, Parser};
+use edifact_types_macros::{DisplayEdifact, Validate};
 use serde::{Deserialize, Serialize};
 use std::fmt;
-#[derive(Debug, Serialize, Deserialize, Default, DisplayEdifact)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayEdifact, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Coreor {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_header", alias = "unh")
+    )]
     pub unh: UNH,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "beginning_of_message", alias = "bgm")
+    )]
     pub bgm: BGM,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_trailer", alias = "unt")
+    )]
     pub unt: UNT,
 }
 
-impl<'a> Parser<&'a str, Coreor, nom::error::Error<&'a str>> for Coreor {
+impl<'a> Parser<&'a str, Coreor, crate::util::Error<&'a str>> for Coreor {
     fn parse(input: &'a str) -> IResult<&'a str, Coreor> {
         let mut output = Coreor::default();
         let (rest, obj) = UNH::parse(input)?;
@@ -1,380 +1,1296 @@
 use crate::d00b::*;
-use edifact_types_macros::{DisplayEdifact, DisplayEdifactSg, ParseMsg, ParseSg};
+use edifact_types_macros::{DisplayEdifact, DisplayEdifactSg, ParseMsg, ParseSg, Validate};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Default, Debug, Serialize, Deserialize, DisplayEdifact, ParseMsg)]
+#[derive(Default, Debug, Serialize, Deserialize, DisplayEdifact, ParseMsg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMIN {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_header", alias = "unh")
+    )]
     pub unh: UNH,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "beginning_of_message", alias = "bgm")
+    )]
     pub bgm: BGM,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_information", alias = "cta")
+    )]
     pub cta: Option<CTA>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "communication_contact", alias = "com")
+    )]
     pub com: Vec<COM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_service_requirements", alias = "tsr")
+    )]
     pub tsr: Vec<TSR>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "currencies", alias = "cux")
+    )]
     pub cux: Vec<CUX>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "monetary_amount", alias = "moa")
+    )]
     pub moa: Vec<MOA>,
+    #[edifact(max = 99)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Vec<FTX>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "control_total", alias = "cnt")
+    )]
     pub cnt: Vec<CNT>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_details", alias = "doc")
+    )]
     pub doc: Vec<DOC>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "nature_of_cargo", alias = "gds")
+    )]
     pub gds: Vec<GDS>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "place_location_identification_group",
+            alias = "segment_group_1"
+        )
+    )]
     pub segment_group_1: Vec<IFTMINSegmentgroup1>,
+    #[edifact(max = 2)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "terms_of_delivery_or_transport_group",
+            alias = "segment_group_2"
+        )
+    )]
     pub segment_group_2: Vec<IFTMINSegmentgroup2>,
+    #[edifact(max = 999)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "reference_group", alias = "segment_group_3")
+    )]
     pub segment_group_3: Vec<IFTMINSegmentgroup3>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "governmental_requirements_group", alias = "segment_group_4")
+    )]
     pub segment_group_4: Vec<IFTMINSegmentgroup4>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "charge_payment_instructions_group",
+            alias = "segment_group_6"
+        )
+    )]
     pub segment_group_6: Vec<IFTMINSegmentgroup6>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "transport_charge_rate_calculations_group",
+            alias = "segment_group_7"
+        )
+    )]
     pub segment_group_7: Vec<IFTMINSegmentgroup7>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "details_of_transport_group", alias = "segment_group_8")
+    )]
     pub segment_group_8: Vec<IFTMINSegmentgroup8>,
+    #[edifact(min = 1, max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address_group", alias = "segment_group_11")
+    )]
     pub segment_group_11: Vec<IFTMINSegmentgroup11>,
+    #[edifact(max = 999)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "goods_item_details_group", alias = "segment_group_18")
+    )]
     pub segment_group_18: Vec<IFTMINSegmentgroup18>,
+    #[edifact(max = 999)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_details_group", alias = "segment_group_37")
+    )]
     pub segment_group_37: Vec<IFTMINSegmentgroup37>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_trailer", alias = "unt")
+    )]
     pub unt: UNT,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup1 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: LOC,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup10 {
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: RFF,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Option<DTM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup11 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address", alias = "nad")
+    )]
     pub nad: NAD,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Vec<LOC>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "monetary_amount", alias = "moa")
+    )]
     pub moa: Vec<MOA>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_information_group", alias = "segment_group_12")
+    )]
     pub segment_group_12: Vec<IFTMINSegmentgroup12>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_details_group", alias = "segment_group_13")
+    )]
     pub segment_group_13: Vec<IFTMINSegmentgroup13>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "transport_charge_rate_calculations_group",
+            alias = "segment_group_14"
+        )
+    )]
     pub segment_group_14: Vec<IFTMINSegmentgroup14>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "reference_group", alias = "segment_group_15")
+    )]
     pub segment_group_15: Vec<IFTMINSegmentgroup15>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "charge_payment_instructions_group",
+            alias = "segment_group_16"
+        )
+    )]
     pub segment_group_16: Vec<IFTMINSegmentgroup16>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "transport_service_requirements_group",
+            alias = "segment_group_17"
+        )
+    )]
     pub segment_group_17: Vec<IFTMINSegmentgroup17>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup12 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_information", alias = "cta")
+    )]
     pub cta: CTA,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "communication_contact", alias = "com")
+    )]
     pub com: Vec<COM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup13 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_details", alias = "doc")
+    )]
     pub doc: DOC,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Option<DTM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup14 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_charge_rate_calculations", alias = "tcc")
+    )]
     pub tcc: TCC,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "currencies", alias = "cux")
+    )]
     pub cux: Option<CUX>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "price_details", alias = "pri")
+    )]
     pub pri: Option<PRI>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_units", alias = "eqn")
+    )]
     pub eqn: Option<EQN>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "percentage_details", alias = "pcd")
+    )]
     pub pcd: Option<PCD>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "monetary_amount", alias = "moa")
+    )]
     pub moa: Vec<MOA>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "quantity", alias = "qty"))]
     pub qty: Vec<QTY>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup15 {
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: RFF,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup16 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "charge_payment_instructions", alias = "cpi")
+    )]
     pub cpi: CPI,
+    #[edifact(max = 99)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: Vec<RFF>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "currencies", alias = "cux")
+    )]
     pub cux: Option<CUX>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Vec<LOC>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "monetary_amount", alias = "moa")
+    )]
     pub moa: Vec<MOA>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup17 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_service_requirements", alias = "tsr")
+    )]
     pub tsr: TSR,
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: Option<RFF>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Option<LOC>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_placement", alias = "tpl")
+    )]
     pub tpl: Option<TPL>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Vec<FTX>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup18 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "goods_item_details", alias = "gid")
+    )]
     pub gid: GID,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "handling_instructions", alias = "han")
+    )]
     pub han: Vec<HAN>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "temperature", alias = "tmp")
+    )]
     pub tmp: Option<TMP>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "range_details", alias = "rng")
+    )]
     pub rng: Option<RNG>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_movement_details", alias = "tmd")
+    )]
     pub tmd: Option<TMD>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Vec<LOC>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "monetary_amount", alias = "moa")
+    )]
     pub moa: Vec<MOA>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "additional_product_id", alias = "pia")
+    )]
     pub pia: Vec<PIA>,
+    #[edifact(max = 99)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Vec<FTX>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "percentage_details", alias = "pcd")
+    )]
     pub pcd: Vec<PCD>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address_group", alias = "segment_group_19")
+    )]
     pub segment_group_19: Vec<IFTMINSegmentgroup19>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "nature_of_cargo", alias = "gds")
+    )]
     pub gds: Vec<GDS>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements_group", alias = "segment_group_20")
+    )]
     pub segment_group_20: Vec<IFTMINSegmentgroup20>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dimensions_group", alias = "segment_group_21")
+    )]
     pub segment_group_21: Vec<IFTMINSegmentgroup21>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "reference_group", alias = "segment_group_22")
+    )]
     pub segment_group_22: Vec<IFTMINSegmentgroup22>,
+    #[edifact(max = 999)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "package_identification_group", alias = "segment_group_23")
+    )]
     pub segment_group_23: Vec<IFTMINSegmentgroup23>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_details_group", alias = "segment_group_24")
+    )]
     pub segment_group_24: Vec<IFTMINSegmentgroup24>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "governmental_requirements_group", alias = "segment_group_25")
+    )]
     pub segment_group_25: Vec<IFTMINSegmentgroup25>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_placement_group", alias = "segment_group_27")
+    )]
     pub segment_group_27: Vec<IFTMINSegmentgroup27>,
+    #[edifact(max = 999)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "split_goods_placement_group", alias = "segment_group_29")
+    )]
     pub segment_group_29: Vec<IFTMINSegmentgroup29>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "transport_charge_rate_calculations_group",
+            alias = "segment_group_31"
+        )
+    )]
     pub segment_group_31: Vec<IFTMINSegmentgroup31>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods_group", alias = "segment_group_32")
+    )]
     pub segment_group_32: Vec<IFTMINSegmentgroup32>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup19 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address", alias = "nad")
+    )]
     pub nad: NAD,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Option<DTM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Vec<LOC>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup2 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "terms_of_delivery_or_transport", alias = "tod")
+    )]
     pub tod: TOD,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Vec<LOC>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup20 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements", alias = "mea")
+    )]
     pub mea: MEA,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_units", alias = "eqn")
+    )]
     pub eqn: Option<EQN>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup21 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dimensions", alias = "dim")
+    )]
     pub dim: DIM,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_units", alias = "eqn")
+    )]
     pub eqn: Option<EQN>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup22 {
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: RFF,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup23 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "package_identification", alias = "pci")
+    )]
     pub pci: PCI,
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: Option<RFF>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Option<DTM>,
+    #[edifact(max = 10)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "goods_identity_number", alias = "gin")
+    )]
     pub gin: Vec<GIN>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup24 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_details", alias = "doc")
+    )]
     pub doc: DOC,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup25 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "governmental_requirements", alias = "gor")
+    )]
     pub gor: GOR,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Vec<LOC>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "seal_number", alias = "sel")
+    )]
     pub sel: Vec<SEL>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Vec<FTX>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_details_group", alias = "segment_group_26")
+    )]
     pub segment_group_26: Vec<IFTMINSegmentgroup26>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup26 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_details", alias = "doc")
+    )]
     pub doc: DOC,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Option<DTM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup27 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_placement", alias = "tpl")
+    )]
     pub tpl: TPL,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements_group", alias = "segment_group_28")
+    )]
     pub segment_group_28: Vec<IFTMINSegmentgroup28>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup28 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements", alias = "mea")
+    )]
     pub mea: MEA,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_units", alias = "eqn")
+    )]
     pub eqn: Option<EQN>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup29 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "split_goods_placement", alias = "sgp")
+    )]
     pub sgp: SGP,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements_group", alias = "segment_group_30")
+    )]
     pub segment_group_30: Vec<IFTMINSegmentgroup30>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup3 {
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: RFF,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup30 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements", alias = "mea")
+    )]
     pub mea: MEA,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_units", alias = "eqn")
+    )]
     pub eqn: Option<EQN>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup31 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_charge_rate_calculations", alias = "tcc")
+    )]
     pub tcc: TCC,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "currencies", alias = "cux")
+    )]
     pub cux: Option<CUX>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "price_details", alias = "pri")
+    )]
     pub pri: Option<PRI>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_units", alias = "eqn")
+    )]
     pub eqn: Option<EQN>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "percentage_details", alias = "pcd")
+    )]
     pub pcd: Option<PCD>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "monetary_amount", alias = "moa")
+    )]
     pub moa: Vec<MOA>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "quantity", alias = "qty"))]
     pub qty: Vec<QTY>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Vec<LOC>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup32 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods", alias = "dgs")
+    )]
     pub dgs: DGS,
+    #[edifact(max = 99)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Vec<FTX>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_information_group", alias = "segment_group_33")
+    )]
     pub segment_group_33: Vec<IFTMINSegmentgroup33>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements_group", alias = "segment_group_34")
+    )]
     pub segment_group_34: Vec<IFTMINSegmentgroup34>,
+    #[edifact(max = 999)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "split_goods_placement_group", alias = "segment_group_35")
+    )]
     pub segment_group_35: Vec<IFTMINSegmentgroup35>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup33 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_information", alias = "cta")
+    )]
     pub cta: CTA,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "communication_contact", alias = "com")
+    )]
     pub com: Vec<COM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup34 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements", alias = "mea")
+    )]
     pub mea: MEA,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_units", alias = "eqn")
+    )]
     pub eqn: Option<EQN>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup35 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "split_goods_placement", alias = "sgp")
+    )]
     pub sgp: SGP,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements_group", alias = "segment_group_36")
+    )]
     pub segment_group_36: Vec<IFTMINSegmentgroup36>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup36 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements", alias = "mea")
+    )]
     pub mea: MEA,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_units", alias = "eqn")
+    )]
     pub eqn: Option<EQN>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup37 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_details", alias = "eqd")
+    )]
     pub eqd: EQD,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_units", alias = "eqn")
+    )]
     pub eqn: Option<EQN>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_movement_details", alias = "tmd")
+    )]
     pub tmd: Option<TMD>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements", alias = "mea")
+    )]
     pub mea: Vec<MEA>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dimensions", alias = "dim")
+    )]
     pub dim: Vec<DIM>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "seal_number", alias = "sel")
+    )]
     pub sel: Vec<SEL>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_placement", alias = "tpl")
+    )]
     pub tpl: Vec<TPL>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "handling_instructions", alias = "han")
+    )]
     pub han: Option<HAN>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "temperature", alias = "tmp")
+    )]
     pub tmp: Option<TMP>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Vec<FTX>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: Vec<RFF>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "transport_charge_rate_calculations_group",
+            alias = "segment_group_38"
+        )
+    )]
     pub segment_group_38: Vec<IFTMINSegmentgroup38>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address_group", alias = "segment_group_39")
+    )]
     pub segment_group_39: Vec<IFTMINSegmentgroup39>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "attached_equipment_group", alias = "segment_group_41")
+    )]
     pub segment_group_41: Vec<IFTMINSegmentgroup41>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods_group", alias = "segment_group_42")
+    )]
     pub segment_group_42: Vec<IFTMINSegmentgroup42>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup38 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_charge_rate_calculations", alias = "tcc")
+    )]
     pub tcc: TCC,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "currencies", alias = "cux")
+    )]
     pub cux: Option<CUX>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "price_details", alias = "pri")
+    )]
     pub pri: Option<PRI>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_units", alias = "eqn")
+    )]
     pub eqn: Option<EQN>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "percentage_details", alias = "pcd")
+    )]
     pub pcd: Option<PCD>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "monetary_amount", alias = "moa")
+    )]
     pub moa: Vec<MOA>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "quantity", alias = "qty"))]
     pub qty: Vec<QTY>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup39 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address", alias = "nad")
+    )]
     pub nad: NAD,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Option<DTM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_information_group", alias = "segment_group_40")
+    )]
     pub segment_group_40: Vec<IFTMINSegmentgroup40>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup4 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "governmental_requirements", alias = "gor")
+    )]
     pub gor: GOR,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Vec<LOC>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "seal_number", alias = "sel")
+    )]
     pub sel: Vec<SEL>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Vec<FTX>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_details_group", alias = "segment_group_5")
+    )]
     pub segment_group_5: Vec<IFTMINSegmentgroup5>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup40 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_information", alias = "cta")
+    )]
     pub cta: CTA,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "communication_contact", alias = "com")
+    )]
     pub com: Vec<COM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup41 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "attached_equipment", alias = "eqa")
+    )]
     pub eqa: EQA,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_units", alias = "eqn")
+    )]
     pub eqn: Option<EQN>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup42 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods", alias = "dgs")
+    )]
     pub dgs: DGS,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Vec<FTX>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_information_group", alias = "segment_group_43")
+    )]
     pub segment_group_43: Vec<IFTMINSegmentgroup43>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup43 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_information", alias = "cta")
+    )]
     pub cta: CTA,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "communication_contact", alias = "com")
+    )]
     pub com: Vec<COM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup5 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_details", alias = "doc")
+    )]
     pub doc: DOC,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Option<DTM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup6 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "charge_payment_instructions", alias = "cpi")
+    )]
     pub cpi: CPI,
+    #[edifact(max = 99)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: Vec<RFF>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "currencies", alias = "cux")
+    )]
     pub cux: Option<CUX>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Vec<LOC>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "monetary_amount", alias = "moa")
+    )]
     pub moa: Vec<MOA>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup7 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_charge_rate_calculations", alias = "tcc")
+    )]
     pub tcc: TCC,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Option<LOC>,
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Option<FTX>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "currencies", alias = "cux")
+    )]
     pub cux: Option<CUX>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "price_details", alias = "pri")
+    )]
     pub pri: Option<PRI>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_units", alias = "eqn")
+    )]
     pub eqn: Option<EQN>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "percentage_details", alias = "pcd")
+    )]
     pub pcd: Option<PCD>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "monetary_amount", alias = "moa")
+    )]
     pub moa: Vec<MOA>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "quantity", alias = "qty"))]
     pub qty: Vec<QTY>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup8 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "details_of_transport", alias = "tdt")
+    )]
     pub tdt: TDT,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_service_requirements", alias = "tsr")
+    )]
     pub tsr: Vec<TSR>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "place_location_identification_group",
+            alias = "segment_group_9"
+        )
+    )]
     pub segment_group_9: Vec<IFTMINSegmentgroup9>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "reference_group", alias = "segment_group_10")
+    )]
     pub segment_group_10: Vec<IFTMINSegmentgroup10>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTMINSegmentgroup9 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: LOC,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
 }
@@ -1,122 +1,402 @@
 use crate::d00b::*;
-use edifact_types_macros::{DisplayEdifact, DisplayEdifactSg, ParseMsg, ParseSg};
+use edifact_types_macros::{DisplayEdifact, DisplayEdifactSg, ParseMsg, ParseSg, Validate};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Default, Debug, Serialize, Deserialize, DisplayEdifact, ParseMsg)]
+#[derive(Default, Debug, Serialize, Deserialize, DisplayEdifact, ParseMsg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTSTA {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_header", alias = "unh")
+    )]
     pub unh: UNH,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "beginning_of_message", alias = "bgm")
+    )]
     pub bgm: BGM,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_service_requirements", alias = "tsr")
+    )]
     pub tsr: Option<TSR>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address_group", alias = "segment_group_1")
+    )]
     pub segment_group_1: Vec<IFTSTASegmentgroup1>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "reference_group", alias = "segment_group_3")
+    )]
     pub segment_group_3: Vec<IFTSTASegmentgroup3>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Vec<LOC>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Vec<FTX>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "control_total", alias = "cnt")
+    )]
     pub cnt: Vec<CNT>,
+    #[edifact(max = 9999)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "consignment_information_group", alias = "segment_group_4")
+    )]
     pub segment_group_4: Vec<IFTSTASegmentgroup4>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_trailer", alias = "unt")
+    )]
     pub unt: UNT,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTSTASegmentgroup1 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address", alias = "nad")
+    )]
     pub nad: NAD,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_information_group", alias = "segment_group_2")
+    )]
     pub segment_group_2: Vec<IFTSTASegmentgroup2>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTSTASegmentgroup10 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "goods_item_details", alias = "gid")
+    )]
     pub gid: GID,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "handling_instructions", alias = "han")
+    )]
     pub han: Vec<HAN>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "split_goods_placement", alias = "sgp")
+    )]
     pub sgp: Vec<SGP>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods", alias = "dgs")
+    )]
     pub dgs: Vec<DGS>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Vec<FTX>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements_group", alias = "segment_group_11")
+    )]
     pub segment_group_11: Vec<IFTSTASegmentgroup11>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dimensions_group", alias = "segment_group_12")
+    )]
     pub segment_group_12: Vec<IFTSTASegmentgroup12>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "package_identification_group", alias = "segment_group_13")
+    )]
     pub segment_group_13: Vec<IFTSTASegmentgroup13>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTSTASegmentgroup11 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements", alias = "mea")
+    )]
     pub mea: MEA,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_units", alias = "eqn")
+    )]
     pub eqn: Option<EQN>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTSTASegmentgroup12 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dimensions", alias = "dim")
+    )]
     pub dim: DIM,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_units", alias = "eqn")
+    )]
     pub eqn: Option<EQN>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTSTASegmentgroup13 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "package_identification", alias = "pci")
+    )]
     pub pci: PCI,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "goods_identity_number", alias = "gin")
+    )]
     pub gin: Vec<GIN>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTSTASegmentgroup2 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_information", alias = "cta")
+    )]
     pub cta: CTA,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "communication_contact", alias = "com")
+    )]
     pub com: Vec<COM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTSTASegmentgroup3 {
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: RFF,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Option<DTM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTSTASegmentgroup4 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "consignment_information", alias = "cni")
+    )]
     pub cni: CNI,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Vec<LOC>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "control_total", alias = "cnt")
+    )]
     pub cnt: Vec<CNT>,
+    #[edifact(min = 1, max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "status_group", alias = "segment_group_5")
+    )]
     pub segment_group_5: Vec<IFTSTASegmentgroup5>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTSTASegmentgroup5 {
+    #[cfg_attr(feature = "semantic-names", serde(rename = "status", alias = "sts"))]
     pub sts: STS,
+    #[edifact(max = 999)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: Vec<RFF>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_details", alias = "doc")
+    )]
     pub doc: Option<DOC>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Vec<FTX>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address", alias = "nad")
+    )]
     pub nad: Vec<NAD>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Option<LOC>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "package_identification", alias = "pci")
+    )]
     pub pci: Vec<PCI>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "details_of_transport_group", alias = "segment_group_6")
+    )]
     pub segment_group_6: Vec<IFTSTASegmentgroup6>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_details_group", alias = "segment_group_8")
+    )]
     pub segment_group_8: Vec<IFTSTASegmentgroup8>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "goods_item_details_group", alias = "segment_group_10")
+    )]
     pub segment_group_10: Vec<IFTSTASegmentgroup10>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTSTASegmentgroup6 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "details_of_transport", alias = "tdt")
+    )]
     pub tdt: TDT,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: Vec<RFF>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "place_location_identification_group",
+            alias = "segment_group_7"
+        )
+    )]
     pub segment_group_7: Vec<IFTSTASegmentgroup7>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTSTASegmentgroup7 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: LOC,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTSTASegmentgroup8 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_details", alias = "eqd")
+    )]
     pub eqd: EQD,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements", alias = "mea")
+    )]
     pub mea: Vec<MEA>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dimensions", alias = "dim")
+    )]
     pub dim: Vec<DIM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "seal_number", alias = "sel")
+    )]
     pub sel: Vec<SEL>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: Vec<RFF>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_placement", alias = "tpl")
+    )]
     pub tpl: Vec<TPL>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_movement_details", alias = "tmd")
+    )]
     pub tmd: Option<TMD>,
+    #[edifact(max = 99)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "attached_equipment_group", alias = "segment_group_9")
+    )]
     pub segment_group_9: Vec<IFTSTASegmentgroup9>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IFTSTASegmentgroup9 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "attached_equipment", alias = "eqa")
+    )]
     pub eqa: EQA,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "seal_number", alias = "sel")
+    )]
     pub sel: Vec<SEL>,
 }
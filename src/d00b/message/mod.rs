@@ -1,3 +1,4 @@
+pub mod any;
 pub mod coparn;
 pub mod coprar;
 pub mod coreor;
@@ -6,6 +7,9 @@ pub mod iftmcs;
 pub mod iftmin;
 pub mod iftsta;
 
+#[cfg(test)]
+mod test_any;
+
 #[cfg(test)]
 mod test_coparn;
 
@@ -0,0 +1,39 @@
+use crate::d00b::*;
+
+#[test]
+fn parse_mixed_interchange() {
+    let input_str = "UNB+UNOC:3+SENDER:ZZZ+RECEIVER:ZZZ+230522:0044+3724322'
+UNH+1+IFTSTA:D:00B:UN'
+BGM+23+2BOG129382+9'
+UNT+3+1'
+UNH+2+COPARN:D:00B:UN:SMDG20'
+BGM+11+CNOR12345+9'
+RFF+BN:24O4023815'
+UNT+4+2'
+UNH+3+IFTMIN:D:00B:UN'
+BGM+610+70123451234567898+1'
+DTM+137:20100810:102'
+UNT+4+3'
+UNH+4+BAPLIE:D:00B:UN:SMDG20'
+BGM++2343+9'
+LOC+147+0050302::5'
+UNT+4+4'
+UNZ+4+3724322'";
+    let (rest, obj): (&str, Interchange<AnyMessage>) = Interchange::parse(input_str).unwrap();
+    assert!(rest.is_empty());
+    assert!(matches!(obj.messages[0], AnyMessage::IFTSTA(_)));
+    assert!(matches!(obj.messages[1], AnyMessage::COPARN(_)));
+    assert!(matches!(obj.messages[2], AnyMessage::IFTMIN(_)));
+    let AnyMessage::Unknown { raw_segments, .. } = &obj.messages[3] else {
+        panic!("BAPLIE has no bindings");
+    };
+    assert_eq!(raw_segments, &["BGM++2343+9", "LOC+147+0050302::5"]);
+    assert_eq!(obj.messages[3].unh()._020._010, "BAPLIE");
+    assert_eq!(format!("{obj}"), input_str);
+}
+
+#[test]
+fn parse_any_wrong_version() {
+    let input_str = "UNH+1+IFTSTA:D:95B:UN'BGM+23+2BOG129382+9'UNT+3+1'";
+    assert!(AnyMessage::parse(input_str).is_err());
+}
@@ -1 +1,67 @@
+use crate::d00b::*;
+use crate::validate::{Validate, Violation, ViolationKind};
 
+fn coparn(header_dtm: usize, nad: bool, equipment_dtm: usize) -> String {
+    let mut segments = vec![
+        "UNH+1+COPARN:D:00B:UN".to_string(),
+        "BGM+12+1+9".to_string(),
+    ];
+    segments.extend((0..header_dtm).map(|_| "DTM+137:20200101:102".to_string()));
+    if nad {
+        segments.push("NAD+CA+ABCD:160:ZZZ".to_string());
+    }
+    segments.push("EQD+CN+ABCU1234567+45G1:6346:5".to_string());
+    segments.extend((0..equipment_dtm).map(|_| "DTM+7:20200101:102".to_string()));
+    segments.push(format!("UNT+{}+1", segments.len() + 1));
+    format!("{}'", segments.join("'\n"))
+}
+
+#[test]
+fn validate_coparn() {
+    let input_str = coparn(1, true, 1);
+    let (rest, obj) = COPARN::parse(&input_str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.validate(), vec![]);
+}
+
+#[test]
+fn validate_coparn_repetitions() {
+    // parsing is lenient, validation reports the limits of the message structure
+    let input_str = coparn(10, true, 12);
+    let (rest, obj) = COPARN::parse(&input_str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(
+        obj.validate(),
+        vec![
+            Violation {
+                path: "DTM".to_string(),
+                kind: ViolationKind::TooManyRepetitions { max: 9, found: 10 },
+            },
+            Violation {
+                path: "SG13[0]/DTM".to_string(),
+                kind: ViolationKind::TooManyRepetitions { max: 9, found: 12 },
+            },
+        ]
+    );
+    assert_eq!(
+        obj.validate()[1].to_string(),
+        "SG13[0]/DTM: repeats 12 times, at most 9 allowed"
+    );
+}
+
+#[test]
+fn validate_coparn_mandatory_group() {
+    let input_str = coparn(1, false, 0);
+    let (rest, obj) = COPARN::parse(&input_str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(
+        obj.validate(),
+        vec![Violation {
+            path: "SG4".to_string(),
+            kind: ViolationKind::MissingMandatory,
+        }]
+    );
+    // the same check applies through AnyMessage
+    let (_, any) = AnyMessage::parse(&input_str).unwrap();
+    assert_eq!(any.validate().len(), 1);
+}
@@ -1,4 +1,5 @@
 use crate::d00b::*;
+use crate::validate::{Representation, RepresentationKind, Validate, Violation, ViolationKind};
 use std::vec;
 
 #[test]
@@ -105,7 +106,7 @@ fn build_iftsta() {
             IFTSTASegmentgroup3 {
                 rff: RFF {
                     _010: C506 {
-                        _010: "4F".to_string(),
+                        _010: _1153::_4F,
                         _020: Some("100113938".to_string()),
                         ..Default::default()
                     },
@@ -115,7 +116,7 @@ fn build_iftsta() {
             IFTSTASegmentgroup3 {
                 rff: RFF {
                     _010: C506 {
-                        _010: "AAZ".to_string(),
+                        _010: _1153::AAZ,
                         _020: Some("ABCD".to_string()),
                         ..Default::default()
                     },
@@ -125,7 +126,7 @@ fn build_iftsta() {
             IFTSTASegmentgroup3 {
                 rff: RFF {
                     _010: C506 {
-                        _010: "BN".to_string(),
+                        _010: _1153::BN,
                         _020: Some("24O4023815".to_string()),
                         ..Default::default()
                     },
@@ -135,7 +136,7 @@ fn build_iftsta() {
             IFTSTASegmentgroup3 {
                 rff: RFF {
                     _010: C506 {
-                        _010: "BM".to_string(),
+                        _010: _1153::BM,
                         _020: Some("ABCD224O4023815X".to_string()),
                         ..Default::default()
                     },
@@ -145,7 +146,7 @@ fn build_iftsta() {
             IFTSTASegmentgroup3 {
                 rff: RFF {
                     _010: C506 {
-                        _010: "EQ".to_string(),
+                        _010: _1153::EQ,
                         _020: Some("TRHU4307252".to_string()),
                         ..Default::default()
                     },
@@ -155,7 +156,7 @@ fn build_iftsta() {
             IFTSTASegmentgroup3 {
                 rff: RFF {
                     _010: C506 {
-                        _010: "CO".to_string(),
+                        _010: _1153::CO,
                         _020: Some("2023521591".to_string()),
                         ..Default::default()
                     },
@@ -165,7 +166,7 @@ fn build_iftsta() {
             IFTSTASegmentgroup3 {
                 rff: RFF {
                     _010: C506 {
-                        _010: "FF".to_string(),
+                        _010: _1153::FF,
                         _020: Some("31075687".to_string()),
                         ..Default::default()
                     },
@@ -202,7 +203,7 @@ fn build_iftsta() {
                     // RFF+BM:ABCD224O4023815X'
                     RFF {
                         _010: C506 {
-                            _010: "BM".to_string(),
+                            _010: _1153::BM,
                             _020: Some("ABCD224O4023815X".to_string()),
                             ..Default::default()
                         },
@@ -210,7 +211,7 @@ fn build_iftsta() {
                     // RFF+BN:24O4023815'
                     RFF {
                         _010: C506 {
-                            _010: "BN".to_string(),
+                            _010: _1153::BN,
                             _020: Some("24O4023815".to_string()),
                             ..Default::default()
                         },
@@ -218,7 +219,7 @@ fn build_iftsta() {
                     // RFF+EQ:TRHU4561222'
                     RFF {
                         _010: C506 {
-                            _010: "EQ".to_string(),
+                            _010: _1153::EQ,
                             _020: Some("TRHU4561222".to_string()),
                             ..Default::default()
                         },
@@ -237,7 +238,7 @@ fn build_iftsta() {
                 nad: vec![],
                 // LOC+175+USSYR:227::SYRACUSE+US:162'
                 loc: Some(LOC {
-                    _010: "175".to_string(),
+                    _010: _3227::_175,
                     _020: Some(C517 {
                         _010: Some("USSYR".to_string()),
                         _020: Some("227".to_string()),
@@ -257,7 +258,7 @@ fn build_iftsta() {
                 segment_group_6: vec![IFTSTASegmentgroup6 {
                     // TDT+1++2+25+ABCD:172::SENDER-COMP+++:::CSX TRANSPORTATION'
                     tdt: TDT {
-                        _010: "1".to_string(),
+                        _010: _8051::_1,
                         _020: None,
                         _030: Some(C220 {
                             _010: Some("2".to_string()),
@@ -290,7 +291,7 @@ fn build_iftsta() {
                         IFTSTASegmentgroup7 {
                             // LOC+9+COCTG:139::SOCIEDAD PORTUARIA DE CARTAGEN, , CO'
                             loc: LOC {
-                                _010: "9".to_string(),
+                                _010: _3227::_9,
                                 _020: Some(C517 {
                                     _010: Some("COCTG".to_string()),
                                     _020: Some("139".to_string()),
@@ -313,7 +314,7 @@ fn build_iftsta() {
                         IFTSTASegmentgroup7 {
                             // LOC+11+USNYC:139::APM TERMINALS ELIZABETH, , US'
                             loc: LOC {
-                                _010: "11".to_string(),
+                                _010: _3227::_11,
                                 _020: Some(C517 {
                                     _010: Some("USNYC".to_string()),
                                     _020: Some("139".to_string()),
@@ -346,7 +347,7 @@ fn build_iftsta() {
                         IFTSTASegmentgroup7 {
                             // LOC+29+USCHI:139::CSX 59TH STREET RAMP, , US'
                             loc: LOC {
-                                _010: "29".to_string(),
+                                _010: _3227::_29,
                                 _020: Some(C517 {
                                     _010: Some("USCHI".to_string()),
                                     _020: Some("139".to_string()),
@@ -379,7 +380,7 @@ fn build_iftsta() {
                         IFTSTASegmentgroup7 {
                             // LOC+7+USWOQ:139::WOODRIDGE, IL, US'
                             loc: LOC {
-                                _010: "7".to_string(),
+                                _010: _3227::_7,
                                 _020: Some(C517 {
                                     _010: Some("USWOQ".to_string()),
                                     _020: Some("139".to_string()),
@@ -404,7 +405,7 @@ fn build_iftsta() {
                 segment_group_8: vec![IFTSTASegmentgroup8 {
                     // EQD+CN+TRHU4561222+45G1:102:5+++5'
                     eqd: EQD {
-                        _010: "CN".to_string(),
+                        _010: _8053::CN,
                         _020: Some(C237 {
                             _010: Some("TRHU4561222".to_string()),
                             _020: None,
@@ -414,7 +415,7 @@ fn build_iftsta() {
                         _030: Some(C224 {
                             _010: Some("45G1".to_string()),
                             _020: Some("102".to_string()),
-                            _030: Some("5".to_string()),
+                            _030: Some(_3055::_5),
                             _040: None,
                         }),
                         _040: None,
@@ -520,3 +521,445 @@ fn parse_iftsta_with_unb() {
     println!("{obj:?}");
     assert!(rest.is_empty());
 }
+
+#[test]
+fn parse_iftsta_with_una() {
+    let input_str = "UNA:*.! ~
+UNB*UNOC:2*SENDER:ZZZ*RECEIVER:ZZZ*220101:1021*2803570~
+UNH*2805567*IFTSTA:D:00B:UN~
+BGM*23*2BOG129382*9~
+DTM*137:202201010021:203~
+CNI*1~
+LOC*11*USNYC:139::APM TERMINALS ELIZA!~ !*BETH, , US~
+STS*1*P::HS:Rail Departed from In-Transit Locat~
+UNT*7*2805567~
+UNZ*1*2803570~";
+    let (rest, obj): (&str, Interchange<IFTSTA>) = Interchange::parse(input_str).unwrap();
+    assert!(rest.is_empty());
+    let loc = &obj.messages[0].segment_group_4[0].loc[0];
+    assert_eq!(
+        loc._020.as_ref().unwrap()._040.as_deref(),
+        Some("APM TERMINALS ELIZA~ *BETH, , US")
+    );
+    // serialization keeps the delimiters of the UNA segment
+    assert_eq!(format!("{obj}"), input_str);
+    // without UNA the default delimiters are used again
+    let (rest, _obj): (&str, Interchange<IFTSTA>) = Interchange::parse(
+        "UNB+UNOC:2+SENDER:ZZZ+RECEIVER:ZZZ+220101:1021+2803570'UNH+2805567+IFTSTA:D:00B:UN'BGM+23+2BOG129382+9'UNT+3+2805567'UNZ+1+2803570'",
+    )
+    .unwrap();
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn parse_iftsta_grouped() {
+    let input_str = "UNB+UNOC:2+SENDER:ZZZ+RECEIVER:ZZZ+220101:1021+2803570'
+UNG+IFTSTA+SENDER-APP+RECEIVER-APP+220101:1021+17+UN+D:00B'
+UNH+1+IFTSTA:D:00B:UN'
+BGM+23+2BOG129382+9'
+UNT+3+1'
+UNH+2+IFTSTA:D:00B:UN'
+BGM+23+2BOG129383+9'
+UNT+3+2'
+UNE+2+17'
+UNG+IFTSTA+SENDER-APP+RECEIVER-APP+220101:1021+18+UN+D:00B'
+UNH+3+IFTSTA:D:00B:UN'
+BGM+23+2BOG129384+9'
+UNT+3+3'
+UNE+1+18'
+UNZ+2+2803570'";
+    let (rest, obj): (&str, GroupedInterchange<IFTSTA>) =
+        GroupedInterchange::parse(input_str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.groups.len(), 2);
+    assert_eq!(obj.groups[0].messages.len(), 2);
+    assert_eq!(obj.groups[0].ung._070.as_ref().unwrap()._020, "00B");
+    assert_eq!(format!("{obj}"), input_str);
+    // wrong control counts and references are parsed and reported by `Validate`
+    let wrong = input_str
+        .replace("UNE+2+17'", "UNE+3+17'")
+        .replace("UNE+1+18'", "UNE+1+17'");
+    let (_, obj) = GroupedInterchange::<IFTSTA>::parse(&wrong).unwrap();
+    assert_eq!(format!("{obj}"), wrong);
+    let violations: Vec<String> = obj.validate().iter().map(|v| v.to_string()).collect();
+    assert_eq!(
+        violations,
+        [
+            r#"groups[0]/UNE/010: value "3", expected "2""#,
+            r#"groups[1]/UNE/020: value "17", expected "18""#,
+        ]
+    );
+}
+
+#[test]
+fn parse_iftsta_multiple_messages() {
+    let input_str = "UNB+UNOC:2+SENDER:ZZZ+RECEIVER:ZZZ+220101:1021+2803570'
+UNH+1+IFTSTA:D:00B:UN'
+BGM+23+2BOG129382+9'
+UNT+3+1'
+UNH+2+IFTSTA:D:00B:UN'
+BGM+23+2BOG129383+9'
+UNT+3+2'
+UNH+3+IFTSTA:D:00B:UN'
+BGM+23+2BOG129384+9'
+UNT+3+3'
+UNZ+3+2803570'";
+    let (rest, obj): (&str, Interchange<IFTSTA>) = Interchange::parse(input_str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.messages.len(), 3);
+    assert_eq!(obj.messages[2].unh._010, "3");
+    assert_eq!(format!("{obj}"), input_str);
+    // UNZ has to count all messages, a wrong count is reported by `Validate`
+    let wrong_count = input_str.replace("UNZ+3+", "UNZ+1+");
+    let (_, obj) = Interchange::<IFTSTA>::parse(&wrong_count).unwrap();
+    let violations = obj.validate();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(
+        violations[0].to_string(),
+        r#"UNZ/010: value "1", expected "3""#
+    );
+    // a broken message is reported, instead of stopping at it
+    let broken = input_str.replace("BGM+23+2BOG129383+9'\n", "");
+    let Err(nom::Err::Error(e)) = Interchange::<IFTSTA>::parse(&broken) else {
+        panic!("broken message has to fail");
+    };
+    assert!(e.input.starts_with("UNT+3+2'"));
+}
+
+#[test]
+fn validate_iftsta_representation() {
+    let input_str = r#"UNH+2805567+IFTSTA:D:00B:UN'
+BGM+23+2BOG129382+9'
+DTM+137:202201010021:203'
+NAD+CA+ABCD:160++SENDER-COMP+STREET 1+CITY1++99999+DE'
+CNI+1'
+STS+1+P::HS:Rail Departed from In-Transit Locat'
+LOC+175+USSYR:227::SYRACUSE+US:162'
+EQD+CN+TRHU4561222+45G1:102:5+++5'
+UNT+9+2805567'"#;
+    let (rest, mut obj) = IFTSTA::parse(input_str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.validate(), vec![]);
+
+    // the party name is an..35, the consolidation item number n..4
+    let name = "SENDER-COMPANY WITH A VERY LONG NAME";
+    obj.segment_group_1[0].nad._040.as_mut().unwrap()._010 = name.to_string();
+    obj.segment_group_4[0].cni._010 = Some("1A".to_string());
+    let violations = obj.validate();
+    assert_eq!(
+        violations,
+        vec![
+            Violation {
+                path: "SG1[0]/NAD/040/010".to_string(),
+                kind: ViolationKind::InvalidRepresentation {
+                    representation: Representation {
+                        kind: RepresentationKind::Alphanumeric,
+                        min: 0,
+                        max: 35,
+                    },
+                    value: name.to_string(),
+                },
+            },
+            Violation {
+                path: "SG4[0]/CNI/010".to_string(),
+                kind: ViolationKind::InvalidRepresentation {
+                    representation: Representation {
+                        kind: RepresentationKind::Numeric,
+                        min: 0,
+                        max: 4,
+                    },
+                    value: "1A".to_string(),
+                },
+            },
+        ]
+    );
+    assert_eq!(
+        violations[1].to_string(),
+        r#"SG4[0]/CNI/010: value "1A" does not match n..4"#
+    );
+}
+
+#[test]
+fn validate_segment_representation() {
+    // numeric values may carry a sign and a decimal mark
+    let cni = CNI {
+        _010: Some("-1.5".to_string()),
+        ..Default::default()
+    };
+    assert!(cni.validated().is_ok());
+    let cni = CNI {
+        _010: Some("12345".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(cni.validated().unwrap_err()[0].path, "010");
+}
+
+#[test]
+fn serialize_iftsta_syntax_level() {
+    let input_str = "UNB+UNOA:2+SENDER:ZZZ+RECEIVER:ZZZ+220101:1021+2803570'UNH+2805567+IFTSTA:D:00B:UN'BGM+23+2bog129382+9'UNT+3+2805567'UNZ+1+2803570'";
+    let (rest, obj): (&str, Interchange<IFTSTA>) = Interchange::parse(input_str).unwrap();
+    assert!(rest.is_empty());
+    // parsing is lenient, serializing checks against UNOA
+    let violations = obj.to_string_checked().unwrap_err();
+    assert_eq!(violations.len(), 3);
+    assert_eq!(violations[0].segment_tag, "BGM");
+    assert!(obj
+        .to_string_transliterated()
+        .contains("BGM+23+2BOG129382+9'"));
+}
+
+#[test]
+fn finalize_iftsta_interchange() {
+    use crate::control::Finalize;
+    let input_str = "UNB+UNOC:2+SENDER:ZZZ+RECEIVER:ZZZ+220101:1021+2803570'
+UNH+1+IFTSTA:D:00B:UN'
+BGM+23+2BOG129382+9'
+DTM+137:202201010021:203'
+UNT+0+X'
+UNH+2+IFTSTA:D:00B:UN'
+BGM+23+2BOG129383+9'
+UNT+0+X'
+UNZ+2+0'";
+    let (_, mut obj): (&str, Interchange<IFTSTA>) = Interchange::parse(input_str).unwrap();
+    let violations = obj.messages[0].validate();
+    assert_eq!(
+        violations.iter().map(|v| v.to_string()).collect::<Vec<_>>(),
+        vec![
+            r#"UNT/010: value "0", expected "4""#,
+            r#"UNT/020: value "X", expected "1""#,
+        ]
+    );
+    // a third message built in code
+    let rendered = obj.messages[1].to_string();
+    let (_, mut message) = IFTSTA::parse(&rendered).unwrap();
+    message.unh._010 = "3".to_string();
+    obj.messages.push(message);
+    obj.finalize();
+    assert_eq!(obj.messages[0].unt._010, "4");
+    assert_eq!(obj.messages[0].unt._020, "1");
+    assert_eq!(obj.messages[2].unt._010, "3");
+    assert_eq!(obj.messages[2].unt._020, "3");
+    assert_eq!(obj.unz._010, "3");
+    assert_eq!(obj.unz._020, "2803570");
+    assert!(obj.messages.iter().all(|m| m.validate().is_empty()));
+    let (_, parsed): (&str, Interchange<IFTSTA>) = Interchange::parse(&obj.to_string()).unwrap();
+    assert_eq!(parsed.to_string(), obj.to_string());
+}
+
+#[test]
+fn finalize_iftsta_default_unt() {
+    use crate::control::{segment_count, Finalize};
+    let (_, mut message) = IFTSTA::parse("UNH+1+IFTSTA:D:00B:UN'BGM+23+1+9'UNT+3+1'").unwrap();
+    // UNT without any value is not rendered before it is finalized
+    message.unh._010 = String::new();
+    message.unt = UNT::default();
+    let message = message.finalized();
+    assert_eq!(message.unt._010, "3");
+    assert_eq!(segment_count(&message.to_string()), 3);
+}
+
+#[test]
+fn validate_iftsta_envelope() {
+    let input_str = std::fs::read_to_string("./test-data/d00b_iftsta.edi").unwrap();
+    let (_, obj): (&str, Interchange<IFTSTA>) = Interchange::parse(&input_str).unwrap();
+    // the sample repeats another reference in UNZ
+    assert_eq!(obj.unb._050, "2803570");
+    let violations: Vec<String> = obj.validate().iter().map(|v| v.to_string()).collect();
+    assert_eq!(
+        violations,
+        vec![r#"UNZ/020: value "2805570", expected "2803570""#]
+    );
+
+    let input_str = "UNB+UNOC:2+SENDER:ZZZ+RECEIVER:ZZZ+220101:1021+2803570'
+UNG+IFTSTA+SENDER-APP+RECEIVER-APP+220101:1021+17+UN+D:00B'
+UNH+1+IFTSTA:D:00B:UN'
+BGM+23+2BOG129382+9'
+UNT+3+1'
+UNE+1+17'
+UNZ+1+2803570'";
+    let (_, mut obj): (&str, GroupedInterchange<IFTSTA>) =
+        GroupedInterchange::parse(input_str).unwrap();
+    assert_eq!(obj.validate(), vec![]);
+    obj.groups[0].messages[0].unt._020 = "2".to_string();
+    obj.groups[0].une._020 = "18".to_string();
+    obj.groups[0].une._010 = "2".to_string();
+    obj.unz._010 = "3".to_string();
+    let violations: Vec<String> = obj.validate().iter().map(|v| v.to_string()).collect();
+    assert_eq!(
+        violations,
+        vec![
+            r#"groups[0]/messages[0]/UNT/020: value "2", expected "1""#,
+            r#"groups[0]/UNE/010: value "2", expected "1""#,
+            r#"groups[0]/UNE/020: value "18", expected "17""#,
+            r#"UNZ/010: value "3", expected "1""#,
+        ]
+    );
+}
+
+#[test]
+fn serialize_iftsta_json() {
+    let input_str = "UNH+1+IFTSTA:D:00B:UN'BGM+23+2BOG129382+9'DTM+137:202201010021:203'UNT+4+1'";
+    let (_, obj) = IFTSTA::parse(input_str).unwrap();
+    let json = serde_json::to_value(&obj).unwrap();
+    #[cfg(feature = "semantic-names")]
+    let (bgm, positional) = (
+        &json["beginning_of_message"]["document_message_identification"]["document_identifier"],
+        serde_json::json!({
+            "unh": json["message_header"],
+            "bgm": {
+                "_010": {"_010": "_23", "_020": null, "_030": null, "_040": null},
+                "_020": {"_010": "2BOG129382", "_020": null, "_030": null},
+                "_030": "_9",
+                "_040": null,
+            },
+            "dtm": json["date_time_period"],
+            "tsr": null,
+            "segment_group_1": [],
+            "segment_group_3": [],
+            "loc": [],
+            "ftx": [],
+            "cnt": [],
+            "segment_group_4": [],
+            "unt": {"_010": "4", "_020": "1"},
+        }),
+    );
+    #[cfg(not(feature = "semantic-names"))]
+    let (bgm, positional) = (&json["bgm"]["_020"]["_010"], json.clone());
+    assert_eq!(bgm, "2BOG129382");
+    // the positional names are accepted in any case
+    let obj: IFTSTA = serde_json::from_value(positional).unwrap();
+    assert_eq!(obj.to_string(), input_str.replace('\'', "'\n").trim_end());
+}
+
+#[cfg(feature = "json-schema")]
+#[test]
+fn iftsta_json_schema() {
+    let schema = serde_json::to_value(schemars::schema_for!(IFTSTA)).unwrap();
+    let defs = &schema["$defs"];
+    assert!(defs["BGM"]["properties"].is_object());
+    // code lists are string enums with the description of their codes
+    let codes = defs["_1225"]["oneOf"].as_array().unwrap();
+    let cancellation = codes.iter().find(|c| c["const"] == "_1").unwrap();
+    assert_eq!(cancellation["type"], "string");
+    assert!(cancellation["description"]
+        .as_str()
+        .unwrap()
+        .starts_with("Cancellation"));
+    // the schema describes the serialized fields
+    let (_, obj) = IFTSTA::parse("UNH+1+IFTSTA:D:00B:UN'BGM+23+2BOG129382+1'UNT+3+1'").unwrap();
+    let json = serde_json::to_value(&obj).unwrap();
+    for key in json.as_object().unwrap().keys() {
+        assert!(schema["properties"][key].is_object(), "{key}");
+    }
+}
+
+#[cfg(feature = "xml")]
+#[test]
+fn iftsta_xml_roundtrip() {
+    use crate::xml::XmlMessage;
+    let input_str = std::fs::read_to_string("./test-data/d00b_iftsta.edi").unwrap();
+    let (_, obj): (&str, Interchange<IFTSTA>) = Interchange::parse(&input_str).unwrap();
+    let message = &obj.messages[0];
+    let xml = message.to_xml();
+    assert!(xml.contains("<IFTSTA><UNH><E0062>"));
+    assert!(xml.contains("<SG4><CNI>"));
+    assert!(xml.contains("<DTM><C507><E2005>"));
+    let parsed = IFTSTA::from_xml(&xml).unwrap();
+    assert_eq!(parsed.to_string(), message.to_string());
+
+    let xml = xml.replace("<E2005>", "<E2005>X");
+    #[cfg(not(feature = "lenient-codes"))]
+    {
+        use crate::xml::XmlErrorKind;
+        let err = IFTSTA::from_xml(&xml).unwrap_err();
+        assert!(matches!(err.kind, XmlErrorKind::UnknownCode(_)));
+        assert!(err.path.starts_with("IFTSTA/"));
+        assert!(err.path.ends_with("/DTM/C507/E2005"));
+    }
+    #[cfg(feature = "lenient-codes")]
+    assert_eq!(IFTSTA::from_xml(&xml).unwrap().to_xml(), xml);
+
+    let err = IFTSTA::from_xml("<IFTSTA><BGM/></IFTSTA>").unwrap_err();
+    assert_eq!(err.to_string(), "IFTSTA: missing element UNH");
+}
+
+#[test]
+fn iftsta_serde_format() {
+    use crate::format;
+    let input_str = std::fs::read_to_string("./test-data/d00b_iftsta.edi").unwrap();
+    let (_, obj): (&str, Interchange<IFTSTA>) = Interchange::parse(&input_str).unwrap();
+    let edi = format::to_string(&obj.messages[0]).unwrap();
+    assert_eq!(edi, obj.messages[0].to_string());
+    let message: IFTSTA = format::from_str(&edi).unwrap();
+    assert_eq!(message.to_string(), edi);
+
+    // the interchange with its service string advice
+    let input_str = "UNA:+.? '\nUNB+UNOC:2+SENDER:ZZZ+RECEIVER:ZZZ+220101:1021+1'\nUNH+1+IFTSTA:D:00B:UN'\nBGM+23+2BOG?+129382+9'\nUNT+3+1'\nUNZ+1+1'";
+    let interchange: Interchange<IFTSTA> = format::from_str(input_str).unwrap();
+    assert_eq!(
+        interchange.messages[0]
+            .bgm
+            ._020
+            .as_ref()
+            .unwrap()
+            ._010
+            .as_deref(),
+        Some("2BOG+129382")
+    );
+    assert_eq!(format::to_string(&interchange).unwrap(), input_str);
+
+    let unknown = "UNH+1+IFTSTA:D:00B:UN'BGM+23+2BOG129382+9'DTM+999:202201010021:203'UNT+4+1'";
+    #[cfg(not(feature = "lenient-codes"))]
+    assert_eq!(
+        format::from_str::<IFTSTA>(unknown).unwrap_err().to_string(),
+        "segment 3 (DTM), element DTM/010/010: unknown `_2005` value 999"
+    );
+    #[cfg(feature = "lenient-codes")]
+    assert_eq!(
+        format::to_string(&format::from_str::<IFTSTA>(unknown).unwrap()).unwrap(),
+        unknown.replace('\'', "'\n").trim_end()
+    );
+    let err =
+        format::from_str::<IFTSTA>("UNH+1+IFTSTA:D:00B:UN'BGM+23+2BOG129382+9'UNT+3+1'FTX+AAA'")
+            .unwrap_err();
+    assert_eq!(
+        (err.segment_index, err.msg.as_str()),
+        (4, "unexpected segment")
+    );
+}
+
+#[cfg(feature = "lenient-codes")]
+#[test]
+fn iftsta_lenient_codes() {
+    use crate::validate::ViolationKind;
+    let input_str = "UNH+1+IFTSTA:D:00B:UN'BGM+ZZZ+2BOG129382+9'DTM+137:202201010021:203'UNT+4+1'";
+    let (_, obj) = IFTSTA::parse(input_str).unwrap();
+    let code = obj.bgm._010.as_ref().unwrap()._010.as_ref().unwrap();
+    assert_eq!(code, &_1001::Unknown("ZZZ".to_string()));
+    assert_eq!(crate::codes::CodeList::code(code), "ZZZ");
+    assert_eq!(crate::codes::CodeList::name(code), "");
+    assert_eq!(obj.to_string(), input_str.replace('\'', "'\n").trim_end());
+    let violations = obj.validate();
+    assert_eq!(
+        violations[0].kind,
+        ViolationKind::UnknownCode {
+            list: "1001".to_string(),
+            value: "ZZZ".to_string()
+        }
+    );
+    assert_eq!(
+        violations[0].to_string(),
+        r#"BGM/010/010: value "ZZZ" is not in code list 1001"#
+    );
+    assert_eq!(violations.len(), 1);
+    // JSON keeps the code as well
+    let json = serde_json::to_string(&obj).unwrap();
+    let obj: IFTSTA = serde_json::from_str(&json).unwrap();
+    assert_eq!(obj.to_string(), input_str.replace('\'', "'\n").trim_end());
+    // unknown codes keep their leading zeros
+    let padded = "UNH+1+IFTSTA:D:00B:UN'\nBGM+23+1+0099'\nUNT+3+1'";
+    let (_, obj) = IFTSTA::parse(padded).unwrap();
+    let function = obj.bgm._030.as_ref().unwrap();
+    assert_eq!(function, &_1225::Unknown("0099".to_string()));
+    assert_eq!(obj.to_string(), padded);
+}
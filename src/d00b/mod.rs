@@ -1,19 +1,28 @@
-use crate::util::Parser;
-use edifact_types_macros::DisplayEdifact;
+use crate::charset::{self, CharsetViolation};
+use crate::control::{check_count, check_reference, Finalize};
+use crate::util::{
+    delimiters, many_until_segment, with_delimiters, Delimiters, Error, IResult, Parser,
+};
+use crate::validate::{Validate, Violation};
+use nom::combinator::opt;
 use nom::Parser as _;
-use nom::{combinator::opt, IResult};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 const VERSION: &str = "D00B";
 
+#[cfg(feature = "chrono")]
+mod datetime;
 mod element;
 mod message;
+#[cfg(feature = "rust_decimal")]
+mod numeric;
 mod segment;
 mod types;
 
 // Re-Export on root level to keep compatibility
 pub use element::*;
+pub use message::any::*;
 pub use message::coparn::*;
 pub use message::coprar::*;
 pub use message::coreor::*;
@@ -55,30 +64,265 @@ mod test_segment;
 /// In addition to the above service segments, the service
 /// segment UNS can, when required, be used to divide a message
 /// into sections. See annex B (NOT IMPLEMENTED).
-#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayEdifact)]
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Interchange<T>
 where
     T: std::fmt::Display,
 {
     pub una: Option<UNA>,
     pub unb: UNB,
-    pub segment: T,
+    pub messages: Vec<T>,
     pub unz: UNZ,
 }
 
-impl<'a, T: Default + Parser<&'a str, T, nom::error::Error<&'a str>> + std::fmt::Display>
-    Parser<&'a str, Interchange<T>, nom::error::Error<&'a str>> for Interchange<T>
+impl<'a, T: Default + Parser<&'a str, T, Error<&'a str>> + std::fmt::Display>
+    Parser<&'a str, Interchange<T>, Error<&'a str>> for Interchange<T>
 {
     fn parse(input: &'a str) -> IResult<&'a str, Interchange<T>> {
         let mut output = Interchange::default();
         let (input, obj) = opt(UNA::parse).parse(input)?;
         output.una = obj;
-        let (input, obj) = UNB::parse(input)?;
-        output.unb = obj;
-        let (input, t_obj) = T::parse(input)?;
-        output.segment = t_obj;
-        let (input, obj) = UNZ::parse(input)?;
-        output.unz = obj;
-        Ok((input, output))
+        // UNA takes precedence for the remainder of the interchange
+        let delimiters = output
+            .una
+            .as_ref()
+            .map_or_else(delimiters, Delimiters::from);
+        with_delimiters(delimiters, || {
+            let (input, obj) = UNB::parse(input)?;
+            output.unb = obj;
+            let (input, obj) = many_until_segment(input, &["UNZ", "UNG"], T::parse)?;
+            output.messages = obj;
+            let (rest, obj) = UNZ::parse(input)?;
+            output.unz = obj;
+            Ok((rest, output))
+        })
+    }
+}
+
+impl<T: std::fmt::Display> fmt::Display for Interchange<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let delimiters = self.una.as_ref().map_or_else(delimiters, Delimiters::from);
+        with_delimiters(delimiters, || {
+            if let Some(una) = &self.una {
+                writeln!(f, "{una}")?;
+            }
+            writeln!(f, "{}{}", self.unb, delimiters.segment)?;
+            for message in &self.messages {
+                writeln!(f, "{message}")?;
+            }
+            write!(f, "{}{}", self.unz, delimiters.segment)
+        })
+    }
+}
+
+impl<T: std::fmt::Display> Interchange<T> {
+    /// Serializes the interchange, fails with the characters which are not part of
+    /// the syntax level declared in UNB S001.
+    pub fn to_string_checked(&self) -> Result<String, Vec<CharsetViolation>> {
+        charset::checked(self.to_string())
+    }
+
+    /// Serializes the interchange, replacing the characters which are not part of
+    /// the syntax level declared in UNB S001.
+    pub fn to_string_transliterated(&self) -> String {
+        charset::transliterate(&self.to_string())
+    }
+
+    /// Serializes the interchange with the encoding of the syntax level declared in UNB S001.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Vec<CharsetViolation>> {
+        charset::encode(&self.to_string())
+    }
+}
+
+impl<T: std::fmt::Display + Finalize> Finalize for Interchange<T> {
+    /// Finalizes the messages, UNZ counts them and repeats the reference of UNB.
+    fn finalize(&mut self) {
+        let delimiters = self.una.as_ref().map_or_else(delimiters, Delimiters::from);
+        with_delimiters(delimiters, || {
+            self.messages.iter_mut().for_each(T::finalize)
+        });
+        self.unz._010 = self.messages.len().to_string();
+        self.unz._020 = self.unb._050.clone();
+    }
+}
+
+impl<T: std::fmt::Display + Validate> Validate for Interchange<T> {
+    /// Checks the messages and the envelope, UNZ has to count the messages
+    /// and repeat the reference of UNB.
+    fn validate(&self) -> Vec<Violation> {
+        let delimiters = self.una.as_ref().map_or_else(delimiters, Delimiters::from);
+        let mut violations = self.unb.validate_within("UNB");
+        with_delimiters(delimiters, || {
+            for (idx, message) in self.messages.iter().enumerate() {
+                violations.extend(message.validate_within(&format!("messages[{idx}]")));
+            }
+        });
+        violations.extend(self.unz.validate_within("UNZ"));
+        violations.extend(check_count("UNZ/010", &self.unz._010, self.messages.len()));
+        violations.extend(check_reference("UNZ/020", &self.unz._020, &self.unb._050));
+        violations
+    }
+}
+
+/// A functional group, enclosed by UNG and UNE
+///
+/// Groups one type of messages within an interchange.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct FunctionalGroup<T>
+where
+    T: std::fmt::Display,
+{
+    pub ung: UNG,
+    pub messages: Vec<T>,
+    pub une: UNE,
+}
+
+impl<'a, T: Default + Parser<&'a str, T, Error<&'a str>> + std::fmt::Display>
+    Parser<&'a str, FunctionalGroup<T>, Error<&'a str>> for FunctionalGroup<T>
+{
+    fn parse(input: &'a str) -> IResult<&'a str, FunctionalGroup<T>> {
+        let (input, ung) = UNG::parse(input)?;
+        let (input, messages) = many_until_segment(input, &["UNE"], T::parse)?;
+        let (rest, une) = UNE::parse(input)?;
+        Ok((rest, FunctionalGroup { ung, messages, une }))
+    }
+}
+
+impl<T: std::fmt::Display> fmt::Display for FunctionalGroup<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let delimiters = delimiters();
+        writeln!(f, "{}{}", self.ung, delimiters.segment)?;
+        for message in &self.messages {
+            writeln!(f, "{message}")?;
+        }
+        write!(f, "{}{}", self.une, delimiters.segment)
+    }
+}
+
+impl<T: std::fmt::Display + Finalize> Finalize for FunctionalGroup<T> {
+    /// Finalizes the messages, UNE counts them and repeats the reference of UNG.
+    fn finalize(&mut self) {
+        self.messages.iter_mut().for_each(T::finalize);
+        self.une._010 = self.messages.len().to_string();
+        self.une._020 = self.ung._050.clone();
+    }
+}
+
+impl<T: std::fmt::Display + Validate> Validate for FunctionalGroup<T> {
+    /// Checks the messages and the envelope, UNE has to count the messages
+    /// and repeat the reference of UNG.
+    fn validate(&self) -> Vec<Violation> {
+        let mut violations = self.ung.validate_within("UNG");
+        for (idx, message) in self.messages.iter().enumerate() {
+            violations.extend(message.validate_within(&format!("messages[{idx}]")));
+        }
+        violations.extend(self.une.validate_within("UNE"));
+        violations.extend(check_count("UNE/010", &self.une._010, self.messages.len()));
+        violations.extend(check_reference("UNE/020", &self.une._020, &self.ung._050));
+        violations
+    }
+}
+
+/// An interchange which holds its messages in functional groups (UNG/UNE)
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct GroupedInterchange<T>
+where
+    T: std::fmt::Display,
+{
+    pub una: Option<UNA>,
+    pub unb: UNB,
+    pub groups: Vec<FunctionalGroup<T>>,
+    pub unz: UNZ,
+}
+
+impl<'a, T: Default + Parser<&'a str, T, Error<&'a str>> + std::fmt::Display>
+    Parser<&'a str, GroupedInterchange<T>, Error<&'a str>> for GroupedInterchange<T>
+{
+    fn parse(input: &'a str) -> IResult<&'a str, GroupedInterchange<T>> {
+        let mut output = GroupedInterchange::default();
+        let (input, obj) = opt(UNA::parse).parse(input)?;
+        output.una = obj;
+        // UNA takes precedence for the remainder of the interchange
+        let delimiters = output
+            .una
+            .as_ref()
+            .map_or_else(delimiters, Delimiters::from);
+        with_delimiters(delimiters, || {
+            let (input, obj) = UNB::parse(input)?;
+            output.unb = obj;
+            let (input, obj) = many_until_segment(input, &["UNZ"], FunctionalGroup::parse)?;
+            output.groups = obj;
+            let (rest, obj) = UNZ::parse(input)?;
+            output.unz = obj;
+            Ok((rest, output))
+        })
+    }
+}
+
+impl<T: std::fmt::Display> fmt::Display for GroupedInterchange<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let delimiters = self.una.as_ref().map_or_else(delimiters, Delimiters::from);
+        with_delimiters(delimiters, || {
+            if let Some(una) = &self.una {
+                writeln!(f, "{una}")?;
+            }
+            writeln!(f, "{}{}", self.unb, delimiters.segment)?;
+            for group in &self.groups {
+                writeln!(f, "{group}")?;
+            }
+            write!(f, "{}{}", self.unz, delimiters.segment)
+        })
+    }
+}
+
+impl<T: std::fmt::Display> GroupedInterchange<T> {
+    /// Serializes the interchange, fails with the characters which are not part of
+    /// the syntax level declared in UNB S001.
+    pub fn to_string_checked(&self) -> Result<String, Vec<CharsetViolation>> {
+        charset::checked(self.to_string())
+    }
+
+    /// Serializes the interchange, replacing the characters which are not part of
+    /// the syntax level declared in UNB S001.
+    pub fn to_string_transliterated(&self) -> String {
+        charset::transliterate(&self.to_string())
+    }
+
+    /// Serializes the interchange with the encoding of the syntax level declared in UNB S001.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Vec<CharsetViolation>> {
+        charset::encode(&self.to_string())
+    }
+}
+
+impl<T: std::fmt::Display + Finalize> Finalize for GroupedInterchange<T> {
+    /// Finalizes the functional groups, UNZ counts them and repeats the reference of UNB.
+    fn finalize(&mut self) {
+        let delimiters = self.una.as_ref().map_or_else(delimiters, Delimiters::from);
+        with_delimiters(delimiters, || {
+            self.groups.iter_mut().for_each(FunctionalGroup::finalize)
+        });
+        self.unz._010 = self.groups.len().to_string();
+        self.unz._020 = self.unb._050.clone();
+    }
+}
+
+impl<T: std::fmt::Display + Validate> Validate for GroupedInterchange<T> {
+    /// Checks the functional groups and the envelope, UNZ has to count the groups
+    /// and repeat the reference of UNB.
+    fn validate(&self) -> Vec<Violation> {
+        let delimiters = self.una.as_ref().map_or_else(delimiters, Delimiters::from);
+        let mut violations = self.unb.validate_within("UNB");
+        with_delimiters(delimiters, || {
+            for (idx, group) in self.groups.iter().enumerate() {
+                violations.extend(group.validate_within(&format!("groups[{idx}]")));
+            }
+        });
+        violations.extend(self.unz.validate_within("UNZ"));
+        violations.extend(check_count("UNZ/010", &self.unz._010, self.groups.len()));
+        violations.extend(check_reference("UNZ/020", &self.unz._020, &self.unb._050));
+        violations
     }
 }
@@ -0,0 +1,185 @@
+use super::{C174, C186, C239, C501, C509, C516, MEA, MOA, PCD, PRI, QTY, TMP};
+use crate::numeric::{self, NumericError};
+use crate::validate::{Representation, RepresentationKind};
+use rust_decimal::Decimal;
+
+/// Numeric representation with up to `max` digits, e.g. `n..18`.
+const fn digits(max: usize) -> Representation {
+    Representation {
+        kind: RepresentationKind::Numeric,
+        min: 0,
+        max,
+    }
+}
+
+fn read(value: Option<&str>) -> Result<Option<Decimal>, NumericError> {
+    value.map(numeric::parse).transpose()
+}
+
+impl C174 {
+    /// Measure (6314).
+    pub fn measure(&self) -> Result<Option<Decimal>, NumericError> {
+        read(self._020.as_deref())
+    }
+
+    /// Writes `value` as measure (6314) with the current decimal mark.
+    ///
+    /// Fails with [`NumericError::TooManyDigits`] for more than the 18 digits of `n..18`.
+    pub fn set_measure(&mut self, value: Decimal) -> Result<(), NumericError> {
+        self._020 = Some(numeric::format(value, &digits(18))?);
+        Ok(())
+    }
+}
+
+impl C516 {
+    /// Monetary amount (5004).
+    pub fn amount(&self) -> Result<Option<Decimal>, NumericError> {
+        read(self._020.as_deref())
+    }
+
+    /// Writes `value` as monetary amount (5004) with the current decimal mark.
+    ///
+    /// Fails with [`NumericError::TooManyDigits`] for more than the 18 digits of `n..18`.
+    pub fn set_amount(&mut self, value: Decimal) -> Result<(), NumericError> {
+        self._020 = Some(numeric::format(value, &digits(18))?);
+        Ok(())
+    }
+}
+
+impl C186 {
+    /// Quantity (6060).
+    pub fn quantity(&self) -> Result<Decimal, NumericError> {
+        numeric::parse(&self._020)
+    }
+
+    /// Writes `value` as quantity (6060) with the current decimal mark.
+    ///
+    /// Fails with [`NumericError::TooManyDigits`] for more than the 35 digits of `n..35`.
+    pub fn set_quantity(&mut self, value: Decimal) -> Result<(), NumericError> {
+        self._020 = numeric::format(value, &digits(35))?;
+        Ok(())
+    }
+}
+
+impl C509 {
+    /// Price amount (5118).
+    pub fn price(&self) -> Result<Option<Decimal>, NumericError> {
+        read(self._020.as_deref())
+    }
+
+    /// Writes `value` as price amount (5118) with the current decimal mark.
+    ///
+    /// Fails with [`NumericError::TooManyDigits`] for more than the 15 digits of `n..15`.
+    pub fn set_price(&mut self, value: Decimal) -> Result<(), NumericError> {
+        self._020 = Some(numeric::format(value, &digits(15))?);
+        Ok(())
+    }
+}
+
+impl C501 {
+    /// Percentage (5482).
+    pub fn percentage(&self) -> Result<Option<Decimal>, NumericError> {
+        read(self._020.as_deref())
+    }
+
+    /// Writes `value` as percentage (5482) with the current decimal mark.
+    ///
+    /// Fails with [`NumericError::TooManyDigits`] for more than the 10 digits of `n..10`.
+    pub fn set_percentage(&mut self, value: Decimal) -> Result<(), NumericError> {
+        self._020 = Some(numeric::format(value, &digits(10))?);
+        Ok(())
+    }
+}
+
+impl C239 {
+    /// Temperature degree (6246).
+    pub fn temperature(&self) -> Result<Option<Decimal>, NumericError> {
+        read(self._010.as_deref())
+    }
+
+    /// Writes `value` as temperature degree (6246) with the current decimal mark.
+    ///
+    /// Fails with [`NumericError::TooManyDigits`] for more than the 15 digits of `n..15`.
+    pub fn set_temperature(&mut self, value: Decimal) -> Result<(), NumericError> {
+        self._010 = Some(numeric::format(value, &digits(15))?);
+        Ok(())
+    }
+}
+
+impl MEA {
+    /// The measure of C174, `None` without C174 or measure.
+    pub fn measure(&self) -> Result<Option<Decimal>, NumericError> {
+        self._030.as_ref().map_or(Ok(None), C174::measure)
+    }
+
+    /// Sets unit (6411) and measure of C174, adding C174 when missing.
+    ///
+    /// Fails like [`C174::set_measure`], leaving C174 unchanged.
+    pub fn set_measure(&mut self, unit: &str, value: Decimal) -> Result<(), NumericError> {
+        let mut measurement = self._030.clone().unwrap_or_default();
+        measurement._010 = unit.to_string();
+        measurement.set_measure(value)?;
+        self._030 = Some(measurement);
+        Ok(())
+    }
+}
+
+impl MOA {
+    /// The monetary amount of C516.
+    pub fn amount(&self) -> Result<Option<Decimal>, NumericError> {
+        self._010.amount()
+    }
+
+    /// Writes `value` as monetary amount of C516, see [`C516::set_amount`].
+    pub fn set_amount(&mut self, value: Decimal) -> Result<(), NumericError> {
+        self._010.set_amount(value)
+    }
+}
+
+impl QTY {
+    /// The quantity of C186.
+    pub fn quantity(&self) -> Result<Decimal, NumericError> {
+        self._010.quantity()
+    }
+
+    /// Writes `value` as quantity of C186, see [`C186::set_quantity`].
+    pub fn set_quantity(&mut self, value: Decimal) -> Result<(), NumericError> {
+        self._010.set_quantity(value)
+    }
+}
+
+impl PRI {
+    /// The price amount of C509, set with [`C509::set_price`].
+    pub fn price(&self) -> Result<Option<Decimal>, NumericError> {
+        self._010.as_ref().map_or(Ok(None), C509::price)
+    }
+}
+
+impl PCD {
+    /// The percentage of C501.
+    pub fn percentage(&self) -> Result<Option<Decimal>, NumericError> {
+        self._010.percentage()
+    }
+
+    /// Writes `value` as percentage of C501, see [`C501::set_percentage`].
+    pub fn set_percentage(&mut self, value: Decimal) -> Result<(), NumericError> {
+        self._010.set_percentage(value)
+    }
+}
+
+impl TMP {
+    /// The temperature degree of C239.
+    pub fn temperature(&self) -> Result<Option<Decimal>, NumericError> {
+        self._020.as_ref().map_or(Ok(None), C239::temperature)
+    }
+
+    /// Writes `value` as temperature degree of C239, adding C239 when missing.
+    ///
+    /// Fails like [`C239::set_temperature`].
+    pub fn set_temperature(&mut self, value: Decimal) -> Result<(), NumericError> {
+        let mut temperature = self._020.clone().unwrap_or_default();
+        temperature.set_temperature(value)?;
+        self._020 = Some(temperature);
+        Ok(())
+    }
+}
@@ -1,7 +1,6 @@
 use super::*;
-use crate::util::{clean_num, Parser};
-use edifact_types_macros::{DisplayOuterSegment, ParseSegment};
-use nom::{bytes::complete::take_until, character::complete::not_line_ending, IResult};
+use crate::util::{clean_num, parse_una, Delimiters, IResult, Parser};
+use edifact_types_macros::{DisplayOuterSegment, ParseSegment, Validate};
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::{self, Debug},
@@ -11,391 +10,731 @@ use std::{
 /// BGM - BEGINNING OF MESSAGE
 ///
 /// A segment indicating the beginning of a message and identifying the consignment for which status is being reported.
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct BGM {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_name", alias = "_010")
+    )]
     pub _010: Option<C002>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_identification", alias = "_020")
+    )]
     pub _020: Option<C106>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_function_code", alias = "_030")
+    )]
     pub _030: Option<_1225>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "response_type_code", alias = "_040")
+    )]
     pub _040: Option<_4343>,
 }
 
 /// CNI - CONSIGNMENT INFORMATION
 ///
 /// A segment to identify a consignment for which status details are given.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct CNI {
     /// CONSOLIDATION ITEM NUMBER
     ///
     /// To specify a consignment within a consolidation.
+    #[edifact(element = "1490", repr = "n..4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "consolidation_item_number", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// C503 - DOCUMENT/MESSAGE DETAILS
     ///
     /// Identification of document/message by number, status, source and/or language.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_details", alias = "_020")
+    )]
     pub _020: Option<C503>,
     /// CONSIGNMENT LOAD SEQUENCE IDENTIFIER
     ///
     /// To identify the loading sequence of a consignment or consignments.
+    #[edifact(element = "1312", repr = "n..4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "consignment_load_sequence_identifier", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct CNT {
     /// CONTROL
     ///
     /// Control total for checking integrity of a message or part of a message.
+    #[cfg_attr(feature = "semantic-names", serde(rename = "control", alias = "_010"))]
     pub _010: C270,
 }
 
 /// COD - Component details
 ///
 /// To provide component details of an object (e.g. product, container) such as its type and the material of which it is composed.
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COD {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "type_of_unit_component", alias = "_010")
+    )]
     pub _010: Option<C823>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "component_material", alias = "_020")
+    )]
     pub _020: Option<C824>,
 }
 
 /// COM - COMMUNICATION CONTACT
 ///
 /// A segment to specify a communication number related to the contact.
-#[derive(Debug, Serialize, Deserialize, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COM {
     /// C076 - COMMUNICATION CONTACT
     ///
     /// Communication number of a department or employee in a specified channel.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "communication_contact", alias = "_010")
+    )]
     pub _010: C076,
 }
 
 /// CPI Charge payment instructions
 ///
 /// To identify a charge.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct CPI {
     /// CONTACT FUNCTION CODE
     ///
     /// Code specifying the function of a contact (e.g. department or person).
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_function_code", alias = "_010")
+    )]
     pub _010: Option<C229>,
     /// C056 - DEPARTMENT OR EMPLOYEE DETAILS
     ///
     /// Code and/or name of a department or employee. Code preferred.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "department_or_employee_details", alias = "_020")
+    )]
     pub _020: Option<C231>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "payment_arrangement_code", alias = "_030")
+    )]
     pub _030: Option<_4237>,
 }
 
 /// CTA - CONTACT INFORMATION
 ///
 /// A segment to specify a contact name associated with the party.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct CTA {
     /// CONTACT FUNCTION CODE
     ///
     /// Code specifying the function of a contact (e.g. department or person).
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_function_code", alias = "_010")
+    )]
     pub _010: Option<_3139>,
     /// C056 - DEPARTMENT OR EMPLOYEE DETAILS
     ///
     /// Code and/or name of a department or employee. Code preferred.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "department_or_employee_details", alias = "_020")
+    )]
     pub _020: Option<C056>,
 }
 
 /// Currencies
 ///
 /// A segment to specify a contact name associated with the party.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct CUX {
     /// Currency details
     ///
     /// The usage to which a currency relates.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "currency_details_010", alias = "_010")
+    )]
     pub _010: Option<C504>,
     /// Currency details
     ///
     /// The usage to which a currency relates.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "currency_details_020", alias = "_020")
+    )]
     pub _020: Option<C504>,
     /// Currency exchange rate
     ///
     /// To specify the rate at which one specified currency is expressed in another specified currency.
+    #[edifact(element = "5402", repr = "n..12")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "currency_exchange_rate", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Exchange rate currency market identifier
     ///
     /// To identify an exchange rate currency market.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "exchange_rate_currency_market_identifier", alias = "_040")
+    )]
     pub _040: Option<_6341>,
 }
 
 /// DAM - Damage
 ///
 /// To specify damage including action taken.
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct DAM {
+    #[edifact(element = "4131", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "damage_details_code_qualifier", alias = "_010")
+    )]
     pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "type_of_damage", alias = "_020")
+    )]
     pub _020: Option<C821>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "damage_area", alias = "_030")
+    )]
     pub _030: Option<C822>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "damage_severity", alias = "_040")
+    )]
     pub _040: Option<C825>,
+    #[cfg_attr(feature = "semantic-names", serde(rename = "action", alias = "_050"))]
     pub _050: Option<C826>,
 }
 
 /// DGS - DANGEROUS GOODS
 ///
 /// A segment to specify dangerous goods details related to the goods item.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct DGS {
     /// DANGEROUS GOODS REGULATIONS CODE
     ///
     /// Code specifying a dangerous goods regulation.
+    #[edifact(element = "8273", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods_regulations_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// C205 - HAZARD CODE
     ///
     /// The identification of the dangerous goods in code.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "hazard_code", alias = "_020")
+    )]
     pub _020: Option<C205>,
     /// C234 - UNDG INFORMATION
     ///
     /// Information on dangerous goods, taken from the United Nations Dangerous Goods classification.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "undg_information", alias = "_030")
+    )]
     pub _030: Option<C234>,
     /// C223 - DANGEROUS GOODS SHIPMENT FLASHPOINT
     ///
     /// Temperature at which a vapor can be ignited as per ISO 1523/73.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods_shipment_flashpoint", alias = "_040")
+    )]
     pub _040: Option<C223>,
     /// PACKAGING DANGER LEVEL CODE
     ///
     /// Code specifying the level of danger for which the packaging must cater.
+    #[edifact(element = "8339", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "packaging_danger_level_code", alias = "_050")
+    )]
     pub _050: Option<String>,
     /// EMERGENCY PROCEDURE FOR SHIPS IDENTIFIER
     ///
     /// To identify the emergency procedure number for ships transporting dangerous goods. Synonym: EMS Number.
+    #[edifact(element = "8364", repr = "an..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "emergency_procedure_for_ships_identifier", alias = "_060")
+    )]
     pub _060: Option<String>,
     /// HAZARD MEDICAL FIRST AID GUIDE IDENTIFIER
     ///
     /// To identify a Medical First Aid Guide (MFAG) for hazardous goods.
+    #[edifact(element = "8410", repr = "an..4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "hazard_medical_first_aid_guide_identifier", alias = "_070")
+    )]
     pub _070: Option<String>,
     /// TRANSPORT EMERGENCY CARD IDENTIFIER
     ///
     /// To identify a transport emergency (TREM) card.
+    #[edifact(element = "8126", repr = "an..10")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_emergency_card_identifier", alias = "_080")
+    )]
     pub _080: Option<String>,
     /// C235 - HAZARD IDENTIFICATION PLACARD DETAILS
     ///
     /// These numbers appear on the hazard identification placard required on the means of transport.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "hazard_identification_placard_details", alias = "_090")
+    )]
     pub _090: Option<C235>,
     /// C236 - DANGEROUS GOODS LABEL
     ///
     /// Markings identifying the type of hazardous goods and similar information.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods_label", alias = "_100")
+    )]
     pub _100: Option<C236>,
     /// PACKING INSTRUCTION TYPE CODE
     ///
     /// Code specifying a type of packing instruction.
+    #[edifact(element = "8255", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "packing_instruction_type_code", alias = "_110")
+    )]
     pub _110: Option<String>,
     /// HAZARDOUS MEANS OF TRANSPORT CATEGORY CODE
     ///
     /// Code specifying the category of means of transport for carrying hazardous goods.
+    #[edifact(element = "8325", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "hazardous_means_of_transport_category_code", alias = "_120")
+    )]
     pub _120: Option<String>,
     /// HAZARDOUS CARGO TRANSPORT AUTHORISATION CODE
     ///
     /// Code specifying the authorisation for the transportation of hazardous cargo.
+    #[edifact(element = "8211", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "hazardous_cargo_transport_authorisation_code",
+            alias = "_130"
+        )
+    )]
     pub _130: Option<String>,
 }
 
 /// DIM - DIMENSIONS
 ///
 /// A segment specifying dimensions of a goods item.
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct DIM {
     /// DIMENSION TYPE CODE QUALIFIER
     ///
     /// Code qualifying the type of the dimension.
+    #[edifact(element = "6145", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dimension_type_code_qualifier", alias = "_010")
+    )]
     pub _010: String,
     /// C211 - DIMENSIONS
     ///
     /// Specification of the dimensions of a transportable unit.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dimensions", alias = "_020")
+    )]
     pub _020: C211,
 }
 
 /// DOC - DOCUMENT/MESSAGE DETAILS
 ///
 /// A segment to specify document details related to the status code, such as indication which document is missing (status code: document missing).
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct DOC {
     /// C002 - DOCUMENT/MESSAGE NAME
     ///
     /// Identification of a type of document/message by code or name. Code preferred.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_name", alias = "_010")
+    )]
     pub _010: Option<C002>,
     /// C503 - DOCUMENT/MESSAGE DETAILS
     ///
     /// Identification of document/message by number, status, source and/or language.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_details", alias = "_020")
+    )]
     pub _020: Option<C503>,
     /// COMMUNICATION MEDIUM TYPE CODE
     ///
     /// Code specifying the type of communication medium.
+    #[edifact(element = "3153", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "communication_medium_type_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// DOCUMENT COPIES REQUIRED QUANTITY
     ///
     /// Quantity of document copies required.
+    #[edifact(element = "1220", repr = "n..2")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_copies_required_quantity", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// DOCUMENT ORIGINALS REQUIRED QUANTITY
     ///
     /// Quantity of document originals required.
+    #[edifact(element = "1218", repr = "n..2")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_originals_required_quantity", alias = "_050")
+    )]
     pub _050: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct DTM {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "_010")
+    )]
     pub _010: C507,
 }
 
 /// EQA - ATTACHED EQUIPMENT
 ///
 /// A segment identifying attached equipment or related equipment such as a chassis attached to a container.
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct EQA {
     /// EQUIPMENT TYPE CODE QUALIFIER
     ///
     /// Code qualifying a type of equipment.
+    #[edifact(element = "8053", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_type_code_qualifier", alias = "_010")
+    )]
     pub _010: String,
     /// C237 - EQUIPMENT IDENTIFICATION
     ///
     /// Marks (letters/numbers) identifying equipment.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_identification", alias = "_020")
+    )]
     pub _020: Option<C237>,
 }
 
 /// EQD - EQUIPMENT DETAILS
 ///
 /// A segment identifying equipment related to status or event such as a container of a multi-container consignment.
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct EQD {
     /// EQUIPMENT TYPE CODE QUALIFIER
     ///
     /// Code qualifying a type of equipment.
-    pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_type_code_qualifier", alias = "_010")
+    )]
+    pub _010: _8053,
     /// C237 - EQUIPMENT IDENTIFICATION
     ///
     /// Marks (letters/numbers) identifying equipment.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_identification", alias = "_020")
+    )]
     pub _020: Option<C237>,
     /// C224 - EQUIPMENT SIZE AND TYPE
     ///
     /// Code and or name identifying size and type of equipment. Code preferred.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_size_and_type", alias = "_030")
+    )]
     pub _030: Option<C224>,
     /// EQUIPMENT SUPPLIER CODE
     ///
     /// Code specifying the party that is the supplier of the equipment.
+    #[edifact(element = "8077", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_supplier_code", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// EQUIPMENT STATUS CODE
     ///
     /// Code specifying the status of equipment.
+    #[edifact(element = "8249", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_status_code", alias = "_050")
+    )]
     pub _050: Option<String>,
     /// FULL OR EMPTY INDICATOR CODE
     ///
     /// Code indicating whether an object is full or empty.
+    #[edifact(element = "8169", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "full_or_empty_indicator_code", alias = "_060")
+    )]
     pub _060: Option<String>,
 }
 
 /// EQN - NUMBER OF UNITS
 ///
 /// A segment specifying the number of units to which the given measurement is applicable.
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct EQN {
     /// C523 - NUMBER OF UNIT DETAILS
     ///
     /// Identification of number of units and its purpose.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_unit_details", alias = "_010")
+    )]
     pub _010: C523,
 }
 
 /// FTX - FREE TEXT
 ///
 /// A segment specifying free form or processable supplementary or other information.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct FTX {
     /// TEXT SUBJECT CODE QUALIFIER
     ///
     /// Code qualifying the subject of the text.
-    pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "text_subject_code_qualifier", alias = "_010")
+    )]
+    pub _010: _4451,
     /// FREE TEXT FUNCTION CODE
     ///
     /// Code specifying the function of free text.
+    #[edifact(element = "4453", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "free_text_function_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// TEXT REFERENCE
     ///
     /// Coded reference to a standard text and its source.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "text_reference", alias = "_030")
+    )]
     pub _030: Option<C107>,
     /// TEXT LITERAL
     ///
     /// Free text; one to five lines.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "text_literal", alias = "_040")
+    )]
     pub _040: Option<C108>,
     /// LANGUAGE NAME CODE
     ///
     /// Code specifying the language name.
+    #[edifact(element = "3453", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "language_name_code", alias = "_050")
+    )]
     pub _050: Option<String>,
     /// FREE TEXT FORMAT CODE
     ///
     /// Code specifying the format of free text.
+    #[edifact(element = "4447", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "free_text_format_code", alias = "_060")
+    )]
     pub _060: Option<String>,
 }
 
 /// GDS Nature of cargo
 ///
 /// To indicate the type of cargo as a general classification.
-#[derive(Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct GDS {
     /// C703 Nature of cargo
     ///
     /// Rough classification of a type of cargo.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "nature_of_cargo", alias = "_010")
+    )]
     pub _010: C703,
 }
 
 /// GID - GOODS ITEM DETAILS
 ///
 /// A segment identifying a goods item.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct GID {
     /// GOODS ITEM NUMBER
     ///
     /// To specify a goods item within a consignment.
+    #[edifact(element = "1496", repr = "n..5")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "goods_item_number", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// C213 - NUMBER AND TYPE OF PACKAGES
     ///
     /// Number and type of individual parts of a shipment.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_and_type_of_packages_020", alias = "_020")
+    )]
     pub _020: Option<C213>,
     /// C213 - NUMBER AND TYPE OF PACKAGES
     ///
     /// Number and type of individual parts of a shipment.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_and_type_of_packages_030", alias = "_030")
+    )]
     pub _030: Option<C213>,
     /// C213 - NUMBER AND TYPE OF PACKAGES
     ///
     /// Number and type of individual parts of a shipment.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_and_type_of_packages_040", alias = "_040")
+    )]
     pub _040: Option<C213>,
     /// C213 - NUMBER AND TYPE OF PACKAGES
     ///
     /// Number and type of individual parts of a shipment.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_and_type_of_packages_050", alias = "_050")
+    )]
     pub _050: Option<C213>,
     /// C213 - NUMBER AND TYPE OF PACKAGES
     ///
     /// Number and type of individual parts of a shipment.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_and_type_of_packages_060", alias = "_060")
+    )]
     pub _060: Option<C213>,
 }
 
 /// GIN - GOODS IDENTITY NUMBER
 ///
 /// A segment specifying identity numbers related to the transport line items.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct GIN {
     /// OBJECT IDENTIFICATION CODE QUALIFIER
     ///
     /// Code qualifying the identification of an object.
+    #[edifact(element = "7405", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "object_identification_code_qualifier", alias = "_010")
+    )]
     pub _010: String,
     /// C208 - IDENTITY NUMBER RANGE
     ///
     /// Goods item identification numbers, start and end of consecutively numbered range.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "identity_number_range_020", alias = "_020")
+    )]
     pub _020: C208,
     /// C208 - IDENTITY NUMBER RANGE
     ///
     /// Goods item identification numbers, start and end of consecutively numbered range.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "identity_number_range_030", alias = "_030")
+    )]
     pub _030: Option<C208>,
     /// C208 - IDENTITY NUMBER RANGE
     ///
     /// Goods item identification numbers, start and end of consecutively numbered range.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "identity_number_range_040", alias = "_040")
+    )]
     pub _040: Option<C208>,
     /// C208 - IDENTITY NUMBER RANGE
     ///
     /// Goods item identification numbers, start and end of consecutively numbered range.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "identity_number_range_050", alias = "_050")
+    )]
     pub _050: Option<C208>,
     /// C208 - IDENTITY NUMBER RANGE
     ///
     /// Goods item identification numbers, start and end of consecutively numbered range.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "identity_number_range_060", alias = "_060")
+    )]
     pub _060: Option<C208>,
 }
 
@@ -403,493 +742,853 @@ pub struct GIN {
 ///
 /// To indicate the requirement for a specific governmental action and/or
 /// procedure or which specific procedure is valid for a specific part of the transport.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct GOR {
     /// Transport movement code
     ///
     /// Code specifying the transport movement.
+    #[edifact(element = "8323", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_movement_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// C232 Government action
     ///
     /// Code indicating a type of government action.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "government_action_020", alias = "_020")
+    )]
     pub _020: Option<C232>,
     /// C232 Government action
     ///
     /// Code indicating a type of government action.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "government_action_030", alias = "_030")
+    )]
     pub _030: Option<C232>,
     /// C232 Government action
     ///
     /// Code indicating a type of government action.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "government_action_040", alias = "_040")
+    )]
     pub _040: Option<C232>,
     /// C232 Government action
     ///
     /// Code indicating a type of government action.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "government_action_050", alias = "_050")
+    )]
     pub _050: Option<C232>,
 }
 
 /// HAN - HANDLING INSTRUCTIONS
 ///
 /// A segment identifying handling instructions.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct HAN {
     /// C524 - HANDLING INSTRUCTIONS
     ///
     /// Instruction for the handling of goods, products or articles in shipment, storage etc.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "handling_instructions", alias = "_010")
+    )]
     pub _010: Option<C524>,
     /// C218 - HAZARDOUS MATERIAL
     ///
     /// To specify a hazardous material.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "hazardous_material", alias = "_020")
+    )]
     pub _020: Option<C218>,
 }
 
 /// LOC - PLACE/LOCATION IDENTIFICATION
 ///
 /// A segment identifying a place/location which applies to the consignment such as consignment origin and destination.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct LOC {
     /// LOCATION FUNCTION CODE QUALIFIER
     ///
     /// Code identifying the function of a location.
-    pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "location_function_code_qualifier", alias = "_010")
+    )]
+    pub _010: _3227,
     /// LOCATION IDENTIFICATION
     ///
     /// Identification of a location by code or name.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "location_identification", alias = "_020")
+    )]
     pub _020: Option<C517>,
     /// RELATED LOCATION ONE IDENTIFICATION
     ///
     /// Identification the first related location by code or name.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "related_location_one_identification", alias = "_030")
+    )]
     pub _030: Option<C519>,
     /// RELATED LOCATION TWO IDENTIFICATION
     ///
     /// Identification of second related location by code or name.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "related_location_two_identification", alias = "_040")
+    )]
     pub _040: Option<C553>,
     /// RELATION CODE
     ///
     /// Code specifying a relation.
+    #[edifact(element = "5479", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "relation_code", alias = "_050")
+    )]
     pub _050: Option<String>,
 }
 
 /// MEA - MEASUREMENTS
 ///
 /// A segment specifying measurements, other than dimension, of a goods item.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct MEA {
     /// MEASUREMENT PURPOSE CODE QUALIFIER
     ///
     /// Code qualifying the purpose of the measurement.
-    pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurement_purpose_code_qualifier", alias = "_010")
+    )]
+    pub _010: _6311,
     /// C502 - MEASUREMENT DETAILS
     ///
     /// Identification of measurement type.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurement_details", alias = "_020")
+    )]
     pub _020: Option<C502>,
     /// C174 - VALUE/RANGE
     ///
     /// Measurement value and relevant minimum and maximum values of the measurement range.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "value_range", alias = "_030")
+    )]
     pub _030: Option<C174>,
     /// SURFACE OR LAYER CODE
     ///
     /// Code specifying the surface or layer of an object.
+    #[edifact(element = "7383", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "surface_or_layer_code", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
 /// MOA Monetary amount
 ///
 /// To specify a monetary amount.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct MOA {
     /// C516 Monetary amount
     ///
     /// Amount of goods or services stated as a monetary amount in a specified currency.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "monetary_amount", alias = "_010")
+    )]
     pub _010: C516,
 }
 
 /// NAD - NAME AND ADDRESS
 ///
 /// A segment specifying the name and/or address associated with the event such as notify party, terminal address, trucking company for gate move.
-#[derive(Debug, Serialize, Deserialize, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct NAD {
     /// PARTY FUNCTION CODE QUALIFIER
     ///
     /// Code giving specific meaning to a party.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_function_code_qualifier", alias = "_010")
+    )]
     pub _010: _3035,
     /// C082 - PARTY IDENTIFICATION DETAILS
     ///
     /// Identification of a transaction party by code.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_identification_details", alias = "_020")
+    )]
     pub _020: Option<C082>,
     /// C058 - NAME AND ADDRESS
     ///
     /// Unstructured name and address: one to five lines.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address", alias = "_030")
+    )]
     pub _030: Option<C058>,
     /// C080 - PARTY NAME
     ///
     /// Identification of a transaction party by name, one to five lines. Party name may be formatted.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_name", alias = "_040")
+    )]
     pub _040: Option<C080>,
     /// C059 - STREET
     ///
     /// Street address and/or PO Box number in a structured address: one to four lines.
+    #[cfg_attr(feature = "semantic-names", serde(rename = "street", alias = "_050"))]
     pub _050: Option<C059>,
     /// CITY NAME
     ///
     /// Name of a city.
+    #[edifact(element = "3164", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "city_name", alias = "_060")
+    )]
     pub _060: Option<String>,
     /// C819 - COUNTRY SUB-ENTITY DETAILS
     ///
     /// To specify a part of a country (eg county or part of a city).
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "country_sub_entity_details", alias = "_070")
+    )]
     pub _070: Option<C819>,
     /// POSTAL IDENTIFICATION CODE
     ///
     /// Code specifying the postal zone or address.
+    #[edifact(element = "3251", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "postal_identification_code", alias = "_080")
+    )]
     pub _080: Option<String>,
     /// COUNTRY NAME CODE
     ///
     /// Identification of the name of the country or other geographical entity as specified in ISO 3166.
+    #[edifact(element = "3207", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "country_name_code", alias = "_090")
+    )]
     pub _090: Option<String>,
 }
 
 /// PIA Additional product id
 ///
 /// To specify additional or substitutional item identification codes.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct PIA {
     /// Product identifier code qualifier
     ///
     /// Code qualifying the product identifier.
+    #[edifact(element = "4347", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "product_identifier_code_qualifier", alias = "_010")
+    )]
     pub _010: String,
     /// C212 Item number identification
     ///
     /// Goods identification for a specified source.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "item_number_identification_020", alias = "_020")
+    )]
     pub _020: C212,
     /// C212 Item number identification
     ///
     /// Goods identification for a specified source.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "item_number_identification_030", alias = "_030")
+    )]
     pub _030: Option<C212>,
     /// C212 Item number identification
     ///
     /// Goods identification for a specified source.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "item_number_identification_040", alias = "_040")
+    )]
     pub _040: Option<C212>,
     /// C212 Item number identification
     ///
     /// Goods identification for a specified source.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "item_number_identification_050", alias = "_050")
+    )]
     pub _050: Option<C212>,
     /// C212 Item number identification
     ///
     /// Goods identification for a specified source.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "item_number_identification_060", alias = "_060")
+    )]
     pub _060: Option<C212>,
 }
 
 /// PCD Percentage details
 ///
 /// To specify percentage information.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct PCD {
     /// Product identifier code qualifier
     ///
     /// Code qualifying the product identifier.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "product_identifier_code_qualifier", alias = "_010")
+    )]
     pub _010: C501,
     /// Status description code
     ///
     /// Code specifying a status.
     ///
     /// 1 For transport status, use UN/ECE Recommendation 24.
+    #[edifact(element = "4405", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "status_description_code", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
 /// PCI - PACKAGE IDENTIFICATION
 ///
 /// A segment specifying marks related to the transport line items.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct PCI {
     /// MARKING INSTRUCTIONS CODE
     ///
     /// Code specifying instructions for marking.
+    #[edifact(element = "4233", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "marking_instructions_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// C210 - MARKS & LABELS
     ///
     /// Shipping marks on packages in free text; one to ten lines.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "marks_labels", alias = "_020")
+    )]
     pub _020: Option<C210>,
     /// CONTAINER OR PACKAGE CONTENTS INDICATOR CODE
     ///
     /// Code indicating the contents of container or package.
+    #[edifact(element = "8275", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "container_or_package_contents_indicator_code",
+            alias = "_030"
+        )
+    )]
     pub _030: Option<String>,
     /// C827 - TYPE OF MARKING
     ///
     /// Specification of the type of marking that reflects the method that was used and the conventions adhered to for marking (e.g. of packages).
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "type_of_marking", alias = "_040")
+    )]
     pub _040: Option<C827>,
 }
 
 /// PRI Price details
 ///
 /// To specify price information.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct PRI {
     /// C509 Price information
     ///
     /// Identification of price type, price and related details.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "price_information", alias = "_010")
+    )]
     pub _010: Option<C509>,
     /// Sub-line item price change operation code
     ///
     /// Code specifying the price change operation for a sub- line item.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "sub_line_item_price_change_operation_code", alias = "_020")
+    )]
     pub _020: Option<_5213>,
 }
 
 /// QTY Quantity
 ///
 /// To specify a pertinent quantity.
-#[derive(Debug, Serialize, Deserialize, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct QTY {
     /// C186 Quantity details
     ///
     /// Quantity information in a transaction, qualified when relevant.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "quantity_details", alias = "_010")
+    )]
     pub _010: C186,
 }
 
 /// RFF - REFERENCE
 ///
 /// A segment to specify a reference number to equipment.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct RFF {
     /// C506 - REFERENCE
     ///
     /// Identification of a reference.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "reference", alias = "_010")
+    )]
     pub _010: C506,
 }
 
 /// RNG Range details
 ///
 /// To identify a range.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct RNG {
     /// Range type code qualifier
     ///
     /// Code qualifying a type of range.
+    #[edifact(element = "6167", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "range_type_code_qualifier", alias = "_010")
+    )]
     pub _010: String,
     /// C280 Range
     ///
     /// Range minimum and maximum limits.
+    #[cfg_attr(feature = "semantic-names", serde(rename = "range", alias = "_020"))]
     pub _020: Option<C280>,
 }
 
 /// SEL - SEAL NUMBER
 ///
 /// A segment identifying seal and seal issuer associated with the equipment.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct SEL {
     /// SEAL IDENTIFIER
     ///
     /// To identify a seal.
+    #[edifact(element = "9308", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "seal_identifier", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// SEAL ISSUER
     ///
     /// Identification of the issuer of a seal on equipment either by code or by name.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "seal_issuer", alias = "_020")
+    )]
     pub _020: Option<C215>,
     /// SEAL CONDITION CODE
     ///
     /// Code specifying the condition of a seal.
+    #[edifact(element = "4517", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "seal_condition_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// C208 - IDENTITY NUMBER RANGE
     ///
     /// Goods item identification numbers, start and end of consecutively numbered range.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "identity_number_range", alias = "_040")
+    )]
     pub _040: Option<C208>,
 }
 
 /// SGP - SPLIT GOODS PLACEMENT
 ///
 /// A segment to identify equipment in which (part of) a goods item is transported.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct SGP {
     /// C237 - EQUIPMENT IDENTIFICATION
     ///
     /// Marks (letters/numbers) identifying equipment.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_identification", alias = "_010")
+    )]
     pub _010: C237,
     /// PACKAGE QUANTITY
     ///
     /// To specify the number of packages.
+    #[edifact(element = "7224", repr = "n..8")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "package_quantity", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
 /// STS - STATUS
 ///
 /// A segment specifying the status relating to a consignment (e.g. loaded).
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct STS {
     /// C601 - STATUS CATEGORY
     ///
     /// To specify the category of the status.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "status_category", alias = "_010")
+    )]
     pub _010: Option<C601>,
     /// C555 - STATUS
     ///
     /// To specify a status.
+    #[cfg_attr(feature = "semantic-names", serde(rename = "status", alias = "_020"))]
     pub _020: Option<C555>,
     /// C556 - STATUS REASON
     ///
     /// To specify the reason for a status.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "status_reason_030", alias = "_030")
+    )]
     pub _030: Option<C556>,
     /// C556 - STATUS REASON
     ///
     /// To specify the reason for a status.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "status_reason_040", alias = "_040")
+    )]
     pub _040: Option<C556>,
     /// C556 - STATUS REASON
     ///
     /// To specify the reason for a status.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "status_reason_050", alias = "_050")
+    )]
     pub _050: Option<C556>,
     /// C556 - STATUS REASON
     ///
     /// To specify the reason for a status.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "status_reason_060", alias = "_060")
+    )]
     pub _060: Option<C556>,
     /// C556 - STATUS REASON
     ///
     /// To specify the reason for a status.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "status_reason_070", alias = "_070")
+    )]
     pub _070: Option<C556>,
 }
 
 /// TCC Transport charge/rate calculations
 ///
 /// To specify charges.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct TCC {
     /// C200 Charge
     ///
     /// Identification of a charge by code and/or by name.
+    #[cfg_attr(feature = "semantic-names", serde(rename = "charge", alias = "_010"))]
     pub _010: Option<C200>,
     /// C203 Rate/tariff class
     ///
     /// Identification of the applicable rate/tariff class.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "rate_tariff_class", alias = "_020")
+    )]
     pub _020: Option<C203>,
     /// C528 Commodity/rate detail
     ///
     /// Identification of commodity/rates.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "commodity_rate_detail", alias = "_030")
+    )]
     pub _030: Option<C528>,
     /// C554 Rate/tariff class detail
     ///
     /// Identification of the applicable rate/tariff class.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "rate_tariff_class_detail", alias = "_040")
+    )]
     pub _040: Option<C554>,
 }
 
 /// TDT - DETAILS OF TRANSPORT
 ///
 /// A segment identifying conveyance related to the status or event such as flight, vessel/voyage.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct TDT {
     /// TRANSPORT STAGE CODE QUALIFIER
     ///
     /// Code qualifying a specific stage of transport.
-    pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_stage_code_qualifier", alias = "_010")
+    )]
+    pub _010: _8051,
     /// MEANS OF TRANSPORT JOURNEY IDENTIFIER
     ///
     /// To identify a journey of a means of transport.
+    #[edifact(element = "8028", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "means_of_transport_journey_identifier", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// C220 - MODE OF TRANSPORT
     ///
     /// Method of transport code or name. Code preferred.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "mode_of_transport", alias = "_030")
+    )]
     pub _030: Option<C220>,
     /// C228- TRANSPORT MEANS
     ///
     /// Code and/or name identifying the type of means of transport.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_means", alias = "_040")
+    )]
     pub _040: Option<C228>,
     /// C040 - CARRIER
     ///
     /// Identification of a carrier by code and/or by name. Code preferred.
+    #[cfg_attr(feature = "semantic-names", serde(rename = "carrier", alias = "_050"))]
     pub _050: Option<C040>,
     /// TRANSIT DIRECTION INDICATOR CODE
     ///
     /// Code specifying the direction of transport.
+    #[edifact(element = "8101", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transit_direction_indicator_code", alias = "_060")
+    )]
     pub _060: Option<String>,
     /// C401 - EXCESS TRANSPORTATION INFORMATION
     ///
     /// To provide details of reason for, and responsibility for, use of transportation other than normally utilized.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "excess_transportation_information", alias = "_070")
+    )]
     pub _070: Option<C401>,
     /// C222 - TRANSPORT IDENTIFICATION
     ///
     /// Code and/or name identifying the means of transport.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_identification", alias = "_080")
+    )]
     pub _080: Option<C222>,
     /// TRANSPORT MEANS OWNERSHIP INDICATOR CODE
     ///
     /// Code indicating the ownership of a means of transport.
+    #[edifact(element = "8281", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_means_ownership_indicator_code", alias = "_090")
+    )]
     pub _090: Option<String>,
 }
 
 /// TMD - TRANSPORT MOVEMENT DETAILS
 ///
 /// A segment to specify transport movement details related to the equipment.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct TMD {
     /// C219 - MOVEMENT TYPE
     ///
     /// Description of type of service for movement of cargo.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "movement_type", alias = "_010")
+    )]
     pub _010: Option<C219>,
     /// EQUIPMENT PLAN DESCRIPTION
     ///
     /// Free form description of the equipment plan.
+    #[edifact(element = "8332", repr = "an..26")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_plan_description", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// HAULAGE ARRANGEMENTS CODE
     ///
     /// Code specifying the arrangement for the haulage of goods.
+    #[edifact(element = "8341", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "haulage_arrangements_code", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
 /// TMP Temperature
 ///
 /// To specify the temperature setting.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct TMP {
     /// Temperature type code qualifier
     ///
     /// Code qualifying the type of a temperature.
+    #[edifact(element = "6245", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "temperature_type_code_qualifier", alias = "_010")
+    )]
     pub _010: String,
     /// Temperature setting
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "temperature_setting", alias = "_020")
+    )]
     pub _020: Option<C239>,
 }
 
 /// TOD Terms of delivery or transport
 ///
 /// To specify terms of delivery or transport.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct TOD {
     /// Delivery or transport terms function code
     ///
     /// Code specifying the function of delivery or transport terms.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "delivery_or_transport_terms_function_code", alias = "_010")
+    )]
     pub _010: Option<_4055>,
     /// Transport charges payment method
     ///
     /// Code specifying the payment method for transport charges.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_charges_payment_method", alias = "_020")
+    )]
     pub _020: Option<_4215>,
     /// Terms of delivery or transport
     ///
     /// Terms of delivery or transport code from a specified source.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "terms_of_delivery_or_transport", alias = "_030")
+    )]
     pub _030: Option<C100>,
 }
 
 /// TPL - TRANSPORT PLACEMENT
 ///
 /// A segment to identify the means of transport to which the equipment is linked, necessary in cases where this forms the key to retrieve relevant information.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct TPL {
     /// C222 - TRANSPORT IDENTIFICATION
     ///
     /// Code and/or name identifying the means of transport.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_identification", alias = "_010")
+    )]
     pub _010: C222,
 }
 
 /// TSR - TRANSPORT SERVICE REQUIREMENTS
 ///
 /// A segment identifying the transport service relating to the consignment.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct TSR {
     /// CONTRACT AND CARRIAGE CONDITION
     ///
     /// To identify a contract and carriage condition.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contract_and_carriage_condition", alias = "_010")
+    )]
     pub _010: Option<C536>,
     /// SERVICE
     ///
     /// To identify a service (which may constitute an additional component to a basic contract).
+    #[cfg_attr(feature = "semantic-names", serde(rename = "service", alias = "_020"))]
     pub _020: Option<C233>,
     /// TRANSPORT PRIORITY
     ///
     /// To indicate the priority of requested transport service.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_priority", alias = "_030")
+    )]
     pub _030: Option<C537>,
     /// NATURE OF CARGO
     ///
     /// Rough classification of a type of cargo.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "nature_of_cargo", alias = "_040")
+    )]
     pub _040: Option<C703>,
 }
 
@@ -916,7 +1615,8 @@ pub struct TSR {
 /// an1 | M | RELEASE INDICATOR | If not used, insert space character
 /// an1 | M | Reserved for future use | Insert space character
 /// an1 | M | SEGMENT TERMINATOR |
-#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayOuterSegment)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct UNA {
     /// an1    M     COMPONENT DATA ELEMENT SEPARATOR
     pub component_data_element_seperator: char,
@@ -932,32 +1632,56 @@ pub struct UNA {
     pub segment_terminator: char,
 }
 
-impl<'a> Parser<&'a str, UNA, nom::error::Error<&'a str>> for UNA {
+impl<'a> Parser<&'a str, UNA, crate::util::Error<&'a str>> for UNA {
     fn parse(input: &'a str) -> IResult<&'a str, UNA> {
-        let (rest, vars) = take_until("UNB")(input)?;
-        if vars.is_empty() {
-            return Err(nom::Err::Error(nom::error::Error::new(
-                rest,
-                nom::error::ErrorKind::TakeUntil,
-            )));
+        let (rest, delimiters) = parse_una(input)?;
+        Ok((rest, UNA::from(delimiters)))
+    }
+}
+
+impl Default for UNA {
+    fn default() -> Self {
+        UNA::from(Delimiters::default())
+    }
+}
+
+impl fmt::Display for UNA {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "UNA{}{}{}{}{}{}",
+            self.component_data_element_seperator,
+            self.data_element_seperator,
+            self.decimal_notation,
+            self.release_indicator,
+            self.reserved_for_future_use,
+            self.segment_terminator
+        )
+    }
+}
+
+impl From<Delimiters> for UNA {
+    fn from(delimiters: Delimiters) -> Self {
+        UNA {
+            component_data_element_seperator: delimiters.component,
+            data_element_seperator: delimiters.element,
+            decimal_notation: delimiters.decimal,
+            release_indicator: delimiters.release.unwrap_or(' '),
+            reserved_for_future_use: ' ',
+            segment_terminator: delimiters.segment,
         }
-        // look for trailing newline
-        let vars = not_line_ending(vars)?.1;
-        if vars.len() != 9 {
-            println!("UNA Segment found, but malformed:\n{vars:?}");
-            panic!("UNA Segment malformed, needs to be exactly 6 characters")
+    }
+}
+
+impl From<&UNA> for Delimiters {
+    fn from(una: &UNA) -> Self {
+        Delimiters {
+            component: una.component_data_element_seperator,
+            element: una.data_element_seperator,
+            decimal: una.decimal_notation,
+            release: Some(una.release_indicator).filter(|c| *c != ' '),
+            segment: una.segment_terminator,
         }
-        let vars = vars.strip_prefix("UNA").unwrap();
-        let mut vars = vars.chars();
-        let una = UNA {
-            component_data_element_seperator: vars.next().unwrap(),
-            data_element_seperator: vars.next().unwrap(),
-            decimal_notation: vars.next().unwrap(),
-            release_indicator: vars.next().unwrap(),
-            reserved_for_future_use: vars.next().unwrap(),
-            segment_terminator: vars.next().unwrap(),
-        };
-        Ok((rest, una))
     }
 }
 
@@ -965,49 +1689,278 @@ impl<'a> Parser<&'a str, UNA, nom::error::Error<&'a str>> for UNA {
 ///
 /// To start, identify and specify an interchange.
 #[derive(
-    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default, DisplayOuterSegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Default,
+    DisplayOuterSegment,
+    ParseSegment,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct UNB {
-    _010: S001,
-    _020: S002,
-    _030: S003,
-    _040: S004,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "syntax_identifier", alias = "_010")
+    )]
+    pub _010: S001,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "interchange_sender", alias = "_020")
+    )]
+    pub _020: S002,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "interchange_recipient", alias = "_030")
+    )]
+    pub _030: S003,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_of_preparation", alias = "_040")
+    )]
+    pub _040: S004,
     /// Interchange control reference
     ///
     /// Unique reference assigned by the sender to an interchange.
     /// Shall be identical in UNB and UNZ.
-    _050: String,
-    _060: Option<S005>,
-    _070: Option<String>,
-    _080: Option<_0029>,
-    _090: Option<_0031>,
-    _100: Option<String>,
-    _110: Option<_0035>,
+    /// M   an..14
+    #[edifact(element = "0020", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "interchange_control_reference", alias = "_050")
+    )]
+    pub _050: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "recipients_reference_password", alias = "_060")
+    )]
+    pub _060: Option<S005>,
+    /// Application reference
+    ///
+    /// Identification of the application area assigned by the sender, to which the messages in the interchange relate.
+    /// C   an..14
+    #[edifact(element = "0026", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "application_reference", alias = "_070")
+    )]
+    pub _070: Option<String>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "processing_priority_code", alias = "_080")
+    )]
+    pub _080: Option<_0029>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "acknowledgement_request", alias = "_090")
+    )]
+    pub _090: Option<_0031>,
+    /// Interchange agreement identifier
+    ///
+    /// Identification by name or code of the type of agreement under which the interchange takes place.
+    /// C   an..35
+    #[edifact(element = "0032", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "interchange_agreement_identifier", alias = "_100")
+    )]
+    pub _100: Option<String>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "test_indicator", alias = "_110")
+    )]
+    pub _110: Option<_0035>,
+}
+
+/// UNE Functional group trailer
+///
+/// To end and check the completeness of a functional group.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    PartialEq,
+    Eq,
+    DisplayOuterSegment,
+    ParseSegment,
+    Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct UNE {
+    /// Number of messages
+    ///
+    /// Count of the number of messages in a functional group.
+    /// M   n..6
+    #[edifact(element = "0060", repr = "n..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_messages", alias = "_010")
+    )]
+    pub _010: String,
+    /// Functional group reference number
+    ///
+    /// Reference number for the functional group assigned by and unique within the sender's division, department etc.
+    /// Shall be identical in UNG and UNE.
+    #[edifact(element = "0048", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "functional_group_reference_number", alias = "_020")
+    )]
+    pub _020: String,
+}
+
+/// UNG Functional group header
+///
+/// To head, identify and specify a functional group.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    PartialEq,
+    Eq,
+    DisplayOuterSegment,
+    ParseSegment,
+    Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct UNG {
+    /// Functional group identification
+    ///
+    /// Identification of the one type of messages in a functional group.
+    /// M   an..6
+    #[edifact(element = "0038", repr = "an..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "functional_group_identification", alias = "_010")
+    )]
+    pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "application_sender_identification", alias = "_020")
+    )]
+    pub _020: S006,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "application_recipient_identification", alias = "_030")
+    )]
+    pub _030: S007,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_of_preparation", alias = "_040")
+    )]
+    pub _040: S004,
+    /// Functional group reference number
+    ///
+    /// Reference number for the functional group assigned by and unique within the sender's division, department etc.
+    /// Shall be identical in UNG and UNE.
+    #[edifact(element = "0048", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "functional_group_reference_number", alias = "_050")
+    )]
+    pub _050: String,
+    /// Controlling agency
+    ///
+    /// Code to identify the agency controlling the specification, maintenance and publication of the message type.
+    /// M   an..2
+    #[edifact(element = "0051", repr = "an..2")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "controlling_agency", alias = "_060")
+    )]
+    pub _060: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_version", alias = "_070")
+    )]
+    pub _070: Option<S008>,
+    /// Application password
+    ///
+    /// Password to the recipient's division, department or sectional system (if required).
+    /// C   an..14
+    #[edifact(element = "0058", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "application_password", alias = "_080")
+    )]
+    pub _080: Option<String>,
 }
 
 /// UNH - MESSAGE HEADER
 ///
 /// To head, identify and specify a message.
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct UNH {
+    #[edifact(element = "0062", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_reference_number", alias = "_010")
+    )]
     pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_identifier", alias = "_020")
+    )]
     pub _020: S009,
+    #[edifact(element = "0068", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "common_access_reference", alias = "_030")
+    )]
     pub _030: Option<String>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "status_of_the_transfer", alias = "_040")
+    )]
     pub _040: Option<S010>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_subset_identification", alias = "_050")
+    )]
     pub _050: Option<S016>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(
+            rename = "message_implementation_guideline_identification",
+            alias = "_060"
+        )
+    )]
     pub _060: Option<S017>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "scenario_identification", alias = "_070")
+    )]
     pub _070: Option<S018>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment)]
+#[derive(Debug, Serialize, Deserialize, Default, DisplayOuterSegment, ParseSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct UNT {
     /// NUMBER OF SEGMENTS IN A MESSAGE
     ///
     /// The number of segments in a message body, plus the message header segment and message trailer segment.
+    #[edifact(element = "0074", repr = "n..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_segments_in_a_message", alias = "_010")
+    )]
     pub _010: String,
     /// MESSAGE REFERENCE NUMBER
     ///
     /// Unique message reference assigned by the sender.
+    #[edifact(element = "0062", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_reference_number", alias = "_020")
+    )]
     pub _020: String,
 }
 
@@ -1015,16 +1968,36 @@ pub struct UNT {
 ///
 /// To end and check the completeness of an interchange.
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayOuterSegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    PartialEq,
+    Eq,
+    DisplayOuterSegment,
+    ParseSegment,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct UNZ {
     /// Interchange control count
     ///
     /// The count either of the number of messages or, if used, of the number of functional groups in an interchange. One of these counts shall appear.
-    _010: String,
+    #[edifact(element = "0036", repr = "n..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "interchange_control_count", alias = "_010")
+    )]
+    pub _010: String,
     /// Interchange control reference
     ///
     /// Unique reference assigned by the sender to an interchange.
     /// Shall be identical in UNB and UNZ.
-    _020: String,
+    #[edifact(element = "0020", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "interchange_control_reference", alias = "_020")
+    )]
+    pub _020: String,
 }
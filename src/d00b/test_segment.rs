@@ -20,3 +20,138 @@ fn test_unh() {
     println!("{obj}");
     assert_eq!(format!("{obj}"), "UNH+2805567+IFTSTA:D:00B:UN");
 }
+
+#[test]
+fn test_ftx_release_character() {
+    let str = "FTX+AAA+++PLASTICS?:ARTICLES?+SPARE PARTS??'";
+    let (rest, obj) = FTX::parse(str).unwrap();
+    assert!(rest.is_empty());
+    let text = obj._040.as_ref().unwrap();
+    assert_eq!(text._010, "PLASTICS:ARTICLES+SPARE PARTS?");
+    assert_eq!(text._020, None);
+    assert_eq!(format!("{obj}'"), str);
+}
+
+#[test]
+fn test_nad_escape_on_output() {
+    let obj = NAD {
+        _010: _3035::CA,
+        _040: Some(C080 {
+            _010: "A+B".to_string(),
+            _020: Some("C:D'E".to_string()),
+            ..Default::default()
+        }),
+        _020: None,
+        _030: None,
+        _050: None,
+        _060: None,
+        _070: None,
+        _080: None,
+        _090: None,
+    };
+    let str = format!("{obj}");
+    assert_eq!(str, "NAD+CA+++A?+B:C?:D?'E");
+    let (_, parsed) = NAD::parse(&format!("{str}'")).unwrap();
+    assert_eq!(parsed._040.unwrap()._020.as_deref(), Some("C:D'E"));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn dtm_datetime() {
+    use crate::datetime::{DateTimeError, DateTimeValue};
+    let (_, obj) = DTM::parse("DTM+334:202211190710:203'").unwrap();
+    let DateTimeValue::DateTime(datetime) = obj.value().unwrap() else {
+        panic!("expected a datetime");
+    };
+    assert_eq!(datetime.to_string(), "2022-11-19 07:10:00");
+    let dtm = DTM::new(_2005::_137, datetime, _2379::_204).unwrap();
+    assert_eq!(dtm.to_string(), "DTM+137:20221119071000:204");
+    assert_eq!(
+        DTM::new(_2005::_137, datetime, _2379::_102).unwrap_err(),
+        DateTimeError::Mismatch {
+            format: "102".to_string()
+        }
+    );
+    let (_, obj) = DTM::parse("DTM+137:20221119'").unwrap();
+    assert_eq!(obj.value(), Err(DateTimeError::MissingFormat));
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn numeric_values() {
+    use crate::util::{with_delimiters, Delimiters};
+    use rust_decimal::Decimal;
+    let (_, mea) = MEA::parse("MEA+AAE+G+KGM:11740'").unwrap();
+    assert_eq!(mea.measure(), Ok(Some(Decimal::new(11740, 0))));
+    let (_, mut moa) = MOA::parse("MOA+9:-1250,50:EUR'").unwrap();
+    assert_eq!(moa.amount(), Ok(Some(Decimal::new(-125050, 2))));
+    let comma = Delimiters {
+        decimal: ',',
+        ..Default::default()
+    };
+    with_delimiters(comma, || moa.set_amount(Decimal::new(99990, 3))).unwrap();
+    assert_eq!(moa.to_string(), "MOA+9:99,99:EUR");
+    let (_, mut qty) = QTY::parse("QTY+1:5-'").unwrap();
+    assert!(qty.quantity().is_err());
+    qty.set_quantity(Decimal::new(5, 0)).unwrap();
+    assert_eq!(qty.to_string(), "QTY+1:5");
+    let (_, pri) = PRI::parse("PRI+AAA:12.5'").unwrap();
+    assert_eq!(pri.price(), Ok(Some(Decimal::new(125, 1))));
+    let (_, mut tmp) = TMP::parse("TMP+2'").unwrap();
+    assert_eq!(tmp.temperature(), Ok(None));
+    tmp.set_temperature(Decimal::new(-180, 1)).unwrap();
+    assert_eq!(tmp.to_string(), "TMP+2+-18");
+}
+
+#[test]
+fn code_list_metadata() {
+    use crate::codes::CodeList;
+    use std::str::FromStr;
+
+    assert_eq!(_2005::list_id(), "2005");
+    assert_eq!(_2005::_137.code(), "137");
+    assert_eq!(_2005::_137.name(), "Document/message date/time");
+    assert_eq!(
+        _2005::_137.description(),
+        "(2006) Date/time when a document/message is issued. This may include authentication."
+    );
+    assert_eq!(_0001::UNOA.name(), "UN/ECE level A");
+    assert_eq!(_0001::all().len(), 8);
+
+    // the codes are the ones which are parsed and written
+    fn check<T: CodeList + FromStr + ToString>() {
+        for value in T::all() {
+            assert_eq!(value.code(), value.to_string());
+            let parsed = T::from_str(value.code()).ok().map(|v| v.to_string());
+            assert_eq!(parsed.as_deref(), Some(value.code()));
+        }
+    }
+    check::<_0001>();
+    check::<_1001>();
+    check::<_2005>();
+    check::<_2379>();
+    check::<_4343>();
+    // no documentation of these codes yet
+    assert_eq!(_4343::AA.name(), "");
+    assert!(_2005::all().iter().all(|v| !v.name().is_empty()));
+}
+
+#[cfg(feature = "xml")]
+#[test]
+fn cux_xml_gap() {
+    use crate::xml::{Element, FromXml, ToXml};
+    // only the target currency, which is also a C504
+    let (_, cux) = CUX::parse("CUX++2:EUR:4'").unwrap();
+    let mut xml = String::new();
+    cux.write_xml("CUX", &mut xml);
+    assert!(xml.starts_with("<CUX><C504/><C504>"));
+    let read = CUX::read_xml(&Element::parse(&xml).unwrap()).unwrap();
+    assert!(read._010.is_none());
+    assert_eq!(read.to_string(), "CUX++2:EUR:4");
+    // without gap
+    let (_, cux) = CUX::parse("CUX+2:EUR:4+3:USD:4+1.1'").unwrap();
+    let mut xml = String::new();
+    cux.write_xml("CUX", &mut xml);
+    let read = CUX::read_xml(&Element::parse(&xml).unwrap()).unwrap();
+    assert_eq!(read.to_string(), "CUX+2:EUR:4+3:USD:4+1.1");
+}
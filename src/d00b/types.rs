@@ -1,10 +1,14 @@
+use edifact_types_macros::CodeList;
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 
 /// Syntax identifier
 ///
 /// Coded identification of the agency controlling a syntax and syntax level used in an interchange.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default)]
+#[derive(
+    Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default, CodeList,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _0001 {
     /// UN/ECE level A
     /// As defined in the basic code table of ISO 646 with the exceptions of lower case letters, alternative graphic character allocations and national or application-oriented graphic character allocations.
@@ -25,12 +29,25 @@ pub enum _0001 {
     /// UN/ECE level F
     /// As defined in ISO 8859-7 : Information processing - Part 7: Latin/Greek alphabet.
     UNOF,
+    /// UN/ECE level W
+    /// ISO 10646-1 multiple octet with code extension technique to support UTF-8 (UCS Transformation Format, 8 bit) encoding.
+    UNOW,
+    /// UN/ECE level Y
+    /// ISO 10646-1 octet without code extension technique.
+    UNOY,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Syntax version number
 ///
 /// Version number of the syntax identified in the syntax identifier (0001)
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default)]
+#[derive(
+    Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default, CodeList,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _0002 {
     /// Version 1
     /// ISO 9735:1988.
@@ -45,12 +62,17 @@ pub enum _0002 {
     #[default]
     #[strum(serialize = "3")]
     _3,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Partner identification code qualifier
 ///
 /// Qualifier referring to the source of codes for the identifiers of interchanging partners.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _0007 {
     /// DUNS (Data Universal Numbering System)
     ///
@@ -242,12 +264,17 @@ pub enum _0007 {
     /// Mutually defined between trading partners.
     #[strum(ascii_case_insensitive)]
     ZZZ,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Recipient's reference/password qualifier
 ///
 /// Qualifier for the recipient's reference or password.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _0025 {
     /// Reference
     /// Recipient's reference/password is a reference.
@@ -255,44 +282,64 @@ pub enum _0025 {
     /// Password
     /// Recipient's reference/password is a password.
     BB,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Processing priority code
 ///
 /// Code determined by the sender requesting processing priority for the interchange.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _0029 {
     /// Highest priority
     /// Requested processing priority is the highest.
     A,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Acknowledgement request
 ///
 /// Code determined by the sender for acknowledgement of the interchange.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _0031 {
     /// Requested
     /// Acknowledgement is requested.
     #[strum(serialize = "1")]
     _1,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Test indicator
 ///
 /// Indication that the interchange is a test.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _0035 {
     /// Interchange is a test
     /// Indicates that the interchange is a test.
     #[strum(serialize = "1")]
     _1,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Document name code
 ///
 /// Code specifying the document name.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _1001 {
     /// Certificate of analysis
     ///
@@ -3124,12 +3171,17 @@ pub enum _1001 {
     /// Indication of the previous Customs document/message concerning the same transaction.
     #[strum(serialize = "998")]
     _998,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Message function code
 ///
 /// Code indicating the function of the message.
-#[derive(Debug, Serialize, Deserialize, Clone, EnumString, Display, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, EnumString, Display, PartialEq, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _1225 {
     /// Cancellation
     ///
@@ -3261,12 +3313,17 @@ pub enum _1225 {
     _62,
     #[strum(serialize = "63")]
     _63,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Code list identification code
 ///
 /// Code identifying a code list.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _1131 {
     /// Logistics code list
     ///
@@ -4489,12 +4546,138 @@ pub enum _1131 {
     /// Mutually defined
     #[strum(ascii_case_insensitive)]
     _ZZZ,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// Reference code qualifier
+///
+/// Code qualifying a reference.
+#[derive(
+    Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default, CodeList,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum _1153 {
+    /// Order acknowledgement number
+    AAA,
+    /// Proforma invoice number
+    AAB,
+    /// Documentary credit number
+    AAC,
+    /// Contract document addendum number
+    AAD,
+    /// Goods declaration number
+    AAE,
+    /// Offer number
+    AAG,
+    /// Delivery order number
+    AAJ,
+    /// Despatch advice number
+    AAK,
+    /// Drawing number
+    AAL,
+    /// Waybill number
+    AAM,
+    /// Delivery schedule number
+    AAN,
+    /// Consignment identifier, consignee assigned
+    AAO,
+    /// Partial shipment identifier
+    AAP,
+    /// Transport equipment identifier
+    AAQ,
+    AAS,
+    /// Master label number
+    AAT,
+    /// Despatch note number
+    AAU,
+    /// Enquiry number
+    AAV,
+    /// Carrier's agent reference number
+    AAY,
+    /// Standard Carrier Alpha Code (SCAC) number
+    AAZ,
+    /// Declarant's reference number
+    ABE,
+    /// Originator's reference
+    ABO,
+    /// Additional reference number
+    ACD,
+    /// Related document number
+    ACE,
+    ADE,
+    /// Accounts receivable number
+    AP,
+    AQW,
+    /// Bill of lading number
+    BM,
+    /// Consignment identifier, carrier assigned
+    BN,
+    /// Blanket order number
+    BO,
+    /// Batch number/lot number
+    BT,
+    /// Credit note number
+    CD,
+    CMR,
+    /// Carrier's reference number
+    CN,
+    /// Buyers order number
+    CO,
+    /// Customer reference number
+    CR,
+    /// Contract number
+    CT,
+    /// Consignor's reference number
+    CU,
+    /// Container operators reference number
+    CV,
+    /// Package number
+    CW,
+    /// Debit note number
+    DL,
+    /// Document number
+    DM,
+    /// Delivery note number
+    DQ,
+    /// Equipment number
+    EQ,
+    /// Consignment identifier, freight forwarder assigned
+    FF,
+    /// House waybill number
+    HWB,
+    /// Invoice number
+    IV,
+    /// Master bill of lading number
+    MB,
+    /// Order number (buyer)
+    ON,
+    /// Packing list number
+    PK,
+    /// Sellers reference number
+    SS,
+    /// Order number (supplier)
+    VN,
+    /// Voyage number
+    VON,
+    /// Mutually defined reference number
+    #[default]
+    ZZZ,
+    #[strum(serialize = "4F")]
+    _4F,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Date or time or period function code qualifier
 ///
 /// Code qualifying the function of a date, time or period.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _2005 {
     /// Service completion date/time, actual
     ///
@@ -8119,12 +8302,17 @@ pub enum _2005 {
     /// Mutually defined
     #[strum(ascii_case_insensitive)]
     ZZZ,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Date or time or period format code
 ///
 /// Code specifying the representation of a date, time or period.
-#[derive(Debug, Serialize, Deserialize, Clone, EnumString, Display)]
+#[derive(Debug, Serialize, Deserialize, Clone, EnumString, Display, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _2379 {
     /// DDMMYY
     ///
@@ -8581,12 +8769,18 @@ pub enum _2379 {
     /// Number of working days.
     #[strum(serialize = "814")]
     _814,
+
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Party function code qualifier
 ///
 /// Code giving specific meaning to a party.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _3035 {
     ///Party to be billed (AAR Accounting rule 11)
     ///
@@ -10626,12 +10820,17 @@ pub enum _3035 {
     ///
     ///Party specification mutually agreed between interchanging parties.
     ZZZ,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Code list responsible agency code
 ///
 /// Code specifying the agency responsible for a code list.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _3055 {
     /// CCC (Customs Co-operation Council)
     ///
@@ -11947,12 +12146,17 @@ pub enum _3055 {
     /// Mutually defined
     #[strum(ascii_case_insensitive)]
     ZZZ,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Contact function code
 ///
 /// Code specifying the function of a contact (e.g. department or person).
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _3139 {
     /// Insurance contact
     ///
@@ -12312,12 +12516,17 @@ pub enum _3139 {
     WJ,
     /// Mutually defined
     ZZZ,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Communication address code qualifier
 ///
 /// Code qualifying the communication address.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _3155 {
     /// Circuit switching
     ///
@@ -12467,12 +12676,111 @@ pub enum _3155 {
     ///
     /// The national telephone switchboard number.
     XJ,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// Location function code qualifier
+///
+/// Code identifying the function of a location.
+#[derive(
+    Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default, CodeList,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum _3227 {
+    /// Place of terms of delivery
+    #[strum(serialize = "1")]
+    _1,
+    #[strum(serialize = "2")]
+    _2,
+    /// Place of despatch
+    #[strum(serialize = "3")]
+    _3,
+    /// Goods receipt place
+    #[strum(serialize = "4")]
+    _4,
+    /// Place of departure
+    #[strum(serialize = "5")]
+    _5,
+    /// Place of delivery
+    #[strum(serialize = "7")]
+    _7,
+    /// Place of destination
+    #[strum(serialize = "8")]
+    _8,
+    /// Place/port of loading
+    #[strum(serialize = "9")]
+    _9,
+    /// Place/port of discharge
+    #[strum(serialize = "11")]
+    _11,
+    /// Place of transhipment
+    #[strum(serialize = "13")]
+    _13,
+    /// Warehouse
+    #[strum(serialize = "18")]
+    _18,
+    /// Factory/plant
+    #[strum(serialize = "19")]
+    _19,
+    /// Place of ultimate destination of goods
+    #[strum(serialize = "20")]
+    _20,
+    /// Port of entry
+    #[strum(serialize = "24")]
+    _24,
+    /// Country of origin
+    #[strum(serialize = "27")]
+    _27,
+    /// Country of destination of goods
+    #[strum(serialize = "28")]
+    _28,
+    #[strum(serialize = "29")]
+    _29,
+    /// Next port of call
+    #[strum(serialize = "61")]
+    _61,
+    /// Original port of loading
+    #[strum(serialize = "76")]
+    _76,
+    /// Place of receipt
+    #[strum(serialize = "88")]
+    _88,
+    /// Place of registration
+    #[strum(serialize = "89")]
+    _89,
+    /// Place of document issue
+    #[strum(serialize = "91")]
+    _91,
+    /// Routing
+    #[strum(serialize = "92")]
+    _92,
+    /// Previous port of call
+    #[strum(serialize = "94")]
+    _94,
+    /// Stowage cell
+    #[strum(serialize = "147")]
+    _147,
+    #[strum(serialize = "175")]
+    _175,
+    #[strum(serialize = "202")]
+    _202,
+    /// Mutually defined
+    #[default]
+    ZZZ,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Delivery or transport terms description code
 ///
 /// Code specifying the delivery or transport terms.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _4053 {
     /// Delivery arranged by the supplier
     ///
@@ -12484,12 +12792,17 @@ pub enum _4053 {
     /// Code indicating that the logistic service provider has arranged the delivery of goods.
     #[strum(serialize = "2")]
     _2,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Delivery or transport terms function code
 ///
 /// Code specifying the function of delivery or transport terms.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _4055 {
     /// Price condition
     ///
@@ -12521,12 +12834,17 @@ pub enum _4055 {
     /// Specifies the conditions under which the goods must be delivered to the consignee.
     #[strum(serialize = "6")]
     _6,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// 4065 Contract and carriage condition code
 ///
 /// Code to identify the conditions of contract and carriage.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _4065 {
     /// AVC conditions
     /// General conditions of transport 1983 latest revision laid down by the Stichting Vervoeradres The Hague.
@@ -12688,12 +13006,17 @@ pub enum _4065 {
     /// The patient has the responsibility of making transport arrangement.
     #[strum(serialize = "40")]
     _40,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Transport charges payment method code
 ///
 /// Code specifying the payment method for transport charges.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _4215 {
     /// Account
     ///
@@ -12806,12 +13129,17 @@ pub enum _4215 {
     WE,
     /// Mutually defined
     ZZZ,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Transport service priority code
 ///
 /// Code specifying the priority of a transport service.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _4219 {
     /// Express
     ///
@@ -12833,12 +13161,17 @@ pub enum _4219 {
     /// Transport under conditions specified by UPU (Universal Postal Union) and Rail organizations (parcels transport only).
     #[strum(serialize = "4")]
     _4,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Payment arrangement code
 ///
 /// Code specifying the arrangements for a payment.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _4237 {
     /// Payable elsewhere
     ///
@@ -12856,10 +13189,15 @@ pub enum _4237 {
     ///
     /// Charges are (to be) prepaid before the transport actually leaves.
     P,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Response type code
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _4343 {
     AA,
     AB,
@@ -12881,12 +13219,248 @@ pub enum _4343 {
     RE,
     UR,
     US,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// Text subject code qualifier
+///
+/// Code qualifying the subject of the text.
+#[derive(
+    Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default, CodeList,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum _4451 {
+    /// Goods item description
+    AAA,
+    /// Payment term
+    AAB,
+    /// Dangerous goods additional information
+    AAC,
+    /// Dangerous goods technical name
+    AAD,
+    /// Acknowledgement description
+    AAE,
+    /// Rate additional information
+    AAF,
+    /// Party instructions
+    AAG,
+    /// General information
+    AAI,
+    /// Additional conditions of sale/purchase
+    AAJ,
+    /// Price conditions
+    AAK,
+    /// Goods dimensions in characters
+    AAL,
+    /// Equipment re-usage restrictions
+    AAM,
+    /// Handling restriction
+    AAN,
+    /// Error description (free text)
+    AAO,
+    /// Response (free text)
+    AAP,
+    /// Package content's description
+    AAQ,
+    /// Terms of delivery
+    AAR,
+    /// Bill of lading remarks
+    AAS,
+    /// Mode of settlement information
+    AAT,
+    /// Consignment invoice information
+    AAU,
+    /// Clearance invoice information
+    AAV,
+    /// Letter of credit information
+    AAW,
+    /// License information
+    AAX,
+    /// Certification statements
+    AAY,
+    /// Additional export information
+    AAZ,
+    /// Tariff statements
+    ABA,
+    /// Medical history
+    ABB,
+    /// Conditions of sale or purchase
+    ABC,
+    /// Contract document type
+    ABD,
+    /// Additional information
+    ACB,
+    /// Reason
+    ACD,
+    /// Allowance/charge information
+    ALC,
+    /// Authentication
+    AUT,
+    /// Transport contract document clause
+    BLC,
+    /// Customs clearance instructions
+    CCI,
+    /// Customs clearance instructions export
+    CEX,
+    /// Change information
+    CHG,
+    /// Customs clearance instruction import
+    CIP,
+    /// Clearance place requested
+    CLP,
+    /// Loading remarks
+    CLR,
+    /// Order information
+    COI,
+    /// Customer remarks
+    CUR,
+    /// Customs declaration information
+    CUS,
+    /// Damage remarks
+    DAR,
+    /// Document issuance information
+    DCL,
+    /// Delivery information
+    DEL,
+    /// Delivery instructions
+    DIN,
+    /// Documentation instructions
+    DOC,
+    /// Duty declaration
+    DUT,
+    /// Effective used routing
+    EUR,
+    /// First block to be printed on the transport contract
+    FBC,
+    /// Government bill of lading information
+    GBL,
+    /// Entire transaction set
+    GEN,
+    /// Consignment handling instruction
+    HAN,
+    /// Hazard information
+    HAZ,
+    /// Consignment information for consignee
+    ICN,
+    /// Invoice mailing instructions
+    IMI,
+    /// Commercial invoice item description
+    IND,
+    /// Insurance instructions
+    INS,
+    /// Invoice instruction
+    INV,
+    /// Information for railway purpose
+    IRP,
+    /// Inland transport details
+    ITR,
+    /// Testing instructions
+    ITS,
+    /// Language
+    LAN,
+    /// Line item
+    LIN,
+    /// Loading instruction
+    LOI,
+    /// Miscellaneous charge order
+    MCO,
+    /// Additional marks/numbers information
+    MKS,
+    /// Order instruction
+    ORI,
+    /// Other service information
+    OSI,
+    /// Packing/marking information
+    PAC,
+    /// Payment instructions information
+    PAI,
+    /// Payables information
+    PAY,
+    /// Packaging information
+    PKG,
+    /// Packaging terms information
+    PKT,
+    /// Payment detail/remittance information
+    PMD,
+    /// Payment information
+    PMT,
+    /// Product information
+    PRD,
+    /// Price calculation formula
+    PRF,
+    /// Priority information
+    PRI,
+    /// Purchasing information
+    PUR,
+    /// Quarantine instructions
+    QIN,
+    /// Quality demands/requirements
+    QQD,
+    /// Quotation instruction/information
+    QUT,
+    /// Risk and handling information
+    RAH,
+    /// Regulatory information
+    REG,
+    /// Return to origin information
+    RET,
+    /// Receivables
+    REV,
+    /// Consignment route
+    RQR,
+    /// Safety information
+    SAF,
+    /// Consignment documentary instruction
+    SIC,
+    /// Special instructions
+    SIN,
+    /// Ship line requested
+    SLR,
+    /// Special permission for transport, generally
+    SPA,
+    /// Special permission concerning the goods to be transported
+    SPG,
+    /// Special handling
+    SPH,
+    /// Special permission concerning package
+    SPP,
+    /// Special permission concerning transport means
+    SPT,
+    /// Subsidiary risk number (IATA/DGR)
+    SRN,
+    /// Special service request
+    SSR,
+    /// Supplier remarks
+    SUR,
+    /// Consignment tariff
+    TCA,
+    /// Consignment transport
+    TDT,
+    /// Transportation information
+    TRA,
+    /// Requested tariff
+    TRR,
+    /// Tax declaration
+    TXD,
+    /// Warehouse instruction/information
+    WHI,
+    /// Mutually defined
+    #[default]
+    ZZZ,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Price code qualifier
 ///
 /// Code qualifying a price.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _5125 {
     /// Calculation net
     ///
@@ -12926,12 +13500,17 @@ pub enum _5125 {
     INF,
     /// Invoice price
     INV,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Sub-line item price change operation code
 ///
 /// Code specifying the price change operation for a sub- line item.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _5213 {
     /// Added to the baseline item unit price
     A,
@@ -12939,12 +13518,17 @@ pub enum _5213 {
     I,
     /// Subtracted from the baseline item unit price
     S,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Charge category code
 ///
 /// Code specifying the category of charges.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _5237 {
     /// All charges
     ///
@@ -13066,12 +13650,17 @@ pub enum _5237 {
     /// Charges payable for the supply of import customs formalities.
     #[strum(serialize = "24")]
     _24,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Rate or tariff class description code
 ///
 /// Code specifying an applicable rate or tariff class.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _5243 {
     /// Senior person rate
     ///
@@ -13107,12 +13696,17 @@ pub enum _5243 {
     ///
     /// Description to be provided.
     S,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Price type code
 ///
 /// Code specifying the type of price.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _5375 {
     /// Cancellation price
     ///
@@ -13194,12 +13788,17 @@ pub enum _5375 {
     ///
     /// The price is calculated based on gross volume.
     WI,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Price specification code
 ///
 /// Code identifying pricing specification.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _5387 {
     /// Reference price
     AAA,
@@ -13569,12 +14168,17 @@ pub enum _5387 {
     ///
     /// Description to be provided.
     WH,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Quantity type code qualifier
 ///
 /// Code qualifying the type of quantity.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _6063 {
     /// Discrete quantity
     ///
@@ -16220,12 +16824,81 @@ pub enum _6063 {
     /// As agreed by the trading partners.
     #[strum(ascii_case_insensitive)]
     ZZZ,
+
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// Measurement purpose code qualifier
+///
+/// Code qualifying the purpose of the measurement.
+#[derive(
+    Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default, CodeList,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum _6311 {
+    /// Measurement
+    #[default]
+    AAE,
+    ABT,
+    /// Counts
+    CT,
+    /// Physical dimensions (product ordered)
+    PD,
+    /// Volume
+    VOL,
+    /// Weights
+    WT,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// Measured attribute code
+///
+/// Code specifying the attribute measured.
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum _6313 {
+    /// Unit net weight
+    AAA,
+    /// Unit gross weight
+    AAB,
+    /// Net weight
+    AAL,
+    /// Gross volume
+    AAW,
+    /// Net volume
+    AAX,
+    /// Gross weight
+    G,
+    /// Height dimension
+    HT,
+    /// Length dimension
+    LN,
+    /// Net weight
+    N,
+    SQ,
+    /// Tare weight
+    T,
+    /// Transport equipment verified gross mass (weight)
+    VGM,
+    /// Width dimension
+    WD,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Exchange rate currency market identifier
 ///
 /// To identify an exchange rate currency market.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _6341 {
     /// Paris exchange
     AAA,
@@ -16291,12 +16964,17 @@ pub enum _6341 {
     SWE,
     /// Zurich exchange
     ZUR,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Currency type code qualifier
 ///
 /// Code qualifying the type of currency.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _6343 {
     /// Customs valuation currency
     ///
@@ -16388,12 +17066,17 @@ pub enum _6343 {
     /// The currency in which tax amounts are due or have been paid.
     #[strum(serialize = "18")]
     _18,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Currency usage code qualifier
 ///
 /// Code qualifying the usage of a currency.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _6347 {
     /// Charge payment currency
     ///
@@ -16430,12 +17113,17 @@ pub enum _6347 {
     /// Currency in which the account is held.
     #[strum(serialize = "7")]
     _7,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Cargo type classification code
 ///
 /// Code specifying the classification of a type of cargo.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _7085 {
     #[strum(serialize = "1")]
     /// Documents
@@ -16547,12 +17235,17 @@ pub enum _7085 {
     ///
     /// Cargo of frozen products.
     _22,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Service requirement code
 ///
 /// Code specifying a service requirement.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _7273 {
     #[strum(serialize = "1")]
     /// Carrier loads
@@ -16854,6 +17547,105 @@ pub enum _7273 {
     ///
     /// An escort is not required.
     _60,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// Transport stage code qualifier
+///
+/// Code qualifying a specific stage of transport.
+#[derive(
+    Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default, CodeList,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum _8051 {
+    /// Inland transport
+    #[strum(serialize = "1")]
+    _1,
+    /// Pre-carriage transport
+    #[strum(serialize = "10")]
+    _10,
+    /// At border
+    #[strum(serialize = "11")]
+    _11,
+    /// At departure
+    #[strum(serialize = "12")]
+    _12,
+    /// At destination
+    #[strum(serialize = "13")]
+    _13,
+    /// Main-carriage transport
+    #[default]
+    #[strum(serialize = "20")]
+    _20,
+    /// On-carriage transport
+    #[strum(serialize = "30")]
+    _30,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// Equipment type code qualifier
+///
+/// Code qualifying a type of equipment.
+#[derive(
+    Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default, CodeList,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum _8053 {
+    /// Ground equipment
+    AA,
+    /// Chain
+    AB,
+    /// Temperature recorder
+    AD,
+    /// Temperature probe
+    AE,
+    /// Slipsheet
+    AG,
+    /// Vessel hold
+    AI,
+    /// Flat rack
+    AJ,
+    /// Blocks
+    BL,
+    /// Barge
+    BR,
+    /// Boxcar
+    BX,
+    /// Chassis
+    CH,
+    /// Container
+    #[default]
+    CN,
+    /// Exchangeable EUR flat pallet
+    EFP,
+    /// Exchangeable EUR box pallet
+    EYP,
+    /// Load/unload device on equipment
+    LU,
+    /// Flat car
+    RF,
+    /// Reefer generator
+    RG,
+    /// Rope
+    RO,
+    /// Rail car
+    RR,
+    /// Swap body
+    SW,
+    /// Trailer
+    TE,
+    /// ULD (Unit load device)
+    UL,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 #[cfg(test)]
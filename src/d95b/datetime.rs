@@ -0,0 +1,42 @@
+use super::{C507, DTM};
+use crate::datetime::{self, DateTimeError, DateTimeValue};
+
+impl C507 {
+    /// The value (2380) as date, time or period of its format qualifier (2379).
+    pub fn value(&self) -> Result<DateTimeValue, DateTimeError> {
+        let value = self._020.as_deref().ok_or(DateTimeError::MissingValue)?;
+        let format = self._030.as_deref().ok_or(DateTimeError::MissingFormat)?;
+        datetime::parse(value, format)
+    }
+
+    /// Creates the composite from a date, time or period, written with `format`, e.g. `"203"`.
+    pub fn new(
+        qualifier: &str,
+        value: impl Into<DateTimeValue>,
+        format: &str,
+    ) -> Result<C507, DateTimeError> {
+        Ok(C507 {
+            _010: qualifier.to_string(),
+            _020: Some(datetime::format(&value.into(), format)?),
+            _030: Some(format.to_string()),
+        })
+    }
+}
+
+impl DTM {
+    /// The date, time or period of C507, see [`C507::value`].
+    pub fn value(&self) -> Result<DateTimeValue, DateTimeError> {
+        self._010.value()
+    }
+
+    /// Creates the segment from a date, time or period, written with `format`, e.g. `"203"`.
+    pub fn new(
+        qualifier: &str,
+        value: impl Into<DateTimeValue>,
+        format: &str,
+    ) -> Result<DTM, DateTimeError> {
+        Ok(DTM {
+            _010: C507::new(qualifier, value, format)?,
+        })
+    }
+}
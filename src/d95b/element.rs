@@ -1,7 +1,6 @@
 use super::*;
-use crate::util::{clean_num, Parser};
-use edifact_types_macros::{DisplayInnerSegment, ParseElement};
-use nom::IResult;
+use crate::util::{clean_num, IResult, Parser};
+use edifact_types_macros::{DisplayInnerSegment, ParseElement, Validate};
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::{self, Debug},
@@ -10,12 +9,42 @@ use std::{
 
 /// C002 - DOCUMENT/MESSAGE NAME
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C002 {
+    #[edifact(element = "1001", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_name_coded", alias = "_010")
+    )]
     pub _010: Option<String>,
+    #[edifact(element = "1131", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_qualifier", alias = "_020")
+    )]
     pub _020: Option<String>,
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_coded", alias = "_030")
+    )]
     pub _030: Option<String>,
+    #[edifact(element = "1000", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_name", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
@@ -24,24 +53,54 @@ pub struct C002 {
 /// Identification of a carrier by code and/or by name. Code
 /// preferred.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C040 {
     /// Carrier identification
     ///
     /// C  an..17
+    #[edifact(element = "3127", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "carrier_identification", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list qualifier
     ///
     /// C  an..3
+    #[edifact(element = "1131", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_qualifier", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency, coded
     ///
     /// C  an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_coded", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Carrier name
     ///
     /// C  an..35
+    #[edifact(element = "3128", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "carrier_name", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
@@ -50,16 +109,36 @@ pub struct C040 {
 /// Code and/or name of a department or employee. Code
 /// preferred.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C056 {
     /// Department or employee identification
     ///
     /// C  an..17
+    #[edifact(element = "3413", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "department_or_employee_identification", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Department or employee
     ///
     /// C  an..35
+    #[edifact(element = "3412", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "department_or_employee", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
@@ -67,28 +146,63 @@ pub struct C056 {
 ///
 /// Unstructured name and address: one to five lines.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C058 {
     /// Name and address line
     ///
     /// M  an..35
+    #[edifact(element = "3124", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address_line_010", alias = "_010")
+    )]
     pub _010: String,
     /// Name and address line
     ///
     /// C  an..35
+    #[edifact(element = "3124", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address_line_020", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Name and address line
     ///
     /// C  an..35
+    #[edifact(element = "3124", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address_line_030", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Name and address line
     ///
     /// C  an..35
+    #[edifact(element = "3124", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address_line_040", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// Name and address line
     ///
     /// C  an..35
+    #[edifact(element = "3124", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address_line_050", alias = "_050")
+    )]
     pub _050: Option<String>,
 }
 
@@ -97,20 +211,45 @@ pub struct C058 {
 /// Street address and/or PO Box number in a structured
 /// address: one to three lines.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C059 {
     /// Street and number/p.o. box
     ///
     /// M  an..35
+    #[edifact(element = "3042", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "street_and_number_p_o_box_010", alias = "_010")
+    )]
     pub _010: String,
     /// Street and number/p.o. box
     ///
     /// C  an..35
+    #[edifact(element = "3042", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "street_and_number_p_o_box_020", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Street and number/p.o. box
     ///
     /// C  an..35
+    #[edifact(element = "3042", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "street_and_number_p_o_box_030", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
@@ -119,32 +258,72 @@ pub struct C059 {
 /// Identification of a transaction party by name, one to five
 /// lines. Party name may be formatted.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C080 {
     /// Party name
     ///
     /// M  an..35
+    #[edifact(element = "3036", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_name_010", alias = "_010")
+    )]
     pub _010: String,
     /// Party name
     ///
     /// C  an..35
+    #[edifact(element = "3036", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_name_020", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Party name
     ///
     /// C  an..35
+    #[edifact(element = "3036", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_name_030", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Party name
     ///
     /// C  an..35
+    #[edifact(element = "3036", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_name_040", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// Party name
     ///
     /// C  an..35
+    #[edifact(element = "3036", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_name_050", alias = "_050")
+    )]
     pub _050: Option<String>,
     /// Party name format, coded
     ///
     /// C  an..3
+    #[edifact(element = "3045", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_name_format_coded", alias = "_060")
+    )]
     pub _060: Option<String>,
 }
 
@@ -152,20 +331,45 @@ pub struct C080 {
 ///
 /// Identification of a transaction party by code.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C082 {
     /// Party id. identification
     ///
     /// M  an..35
+    #[edifact(element = "3039", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_id_identification", alias = "_010")
+    )]
     pub _010: String,
     /// Code list qualifier
     ///
     /// C  an..3
+    #[edifact(element = "1131", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_qualifier", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency, coded
     ///
     /// C  an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_coded", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
@@ -173,20 +377,45 @@ pub struct C082 {
 ///
 /// Coded reference to a standard text and its source.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C107 {
     /// Free text, coded
     ///
     /// M  an..3
+    #[edifact(element = "4441", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "free_text_description_code", alias = "_010")
+    )]
     pub _010: String,
     /// Code list qualifier
     ///
     /// C  an..3
+    #[edifact(element = "1131", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency, coded
     ///
     /// C  an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
@@ -194,28 +423,63 @@ pub struct C107 {
 ///
 /// Free text; one to five lines.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C108 {
     /// Free text
     ///
     /// M  an..70
+    #[edifact(element = "4440", repr = "an..70")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "free_text_010", alias = "_010")
+    )]
     pub _010: String,
     /// Free text
     ///
     /// C  an..70
+    #[edifact(element = "4440", repr = "an..70")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "free_text_020", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Free text
     ///
     /// C  an..70
+    #[edifact(element = "4440", repr = "an..70")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "free_text_030", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Free text
     ///
     /// C  an..70
+    #[edifact(element = "4440", repr = "an..70")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "free_text_040", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// Free text
     ///
     /// C  an..70
+    #[edifact(element = "4440", repr = "an..70")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "free_text_050", alias = "_050")
+    )]
     pub _050: Option<String>,
 }
 
@@ -224,28 +488,63 @@ pub struct C108 {
 /// Measurement value and relevant minimum and maximum
 /// tolerances in that order.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C174 {
     /// Measure unit qualifier
     ///
     /// M  an..3
+    #[edifact(element = "6411", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measure_unit_qualifier", alias = "_010")
+    )]
     pub _010: String,
     /// Measurement value
     ///
     /// C  n..18
+    #[edifact(element = "6314", repr = "n..18")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurement_value", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Range minimum
     ///
     /// C  n..18
+    #[edifact(element = "6162", repr = "n..18")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "range_minimum", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Range maximum
     ///
     /// C  n..18
+    #[edifact(element = "6152", repr = "n..18")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "range_maximum", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// Significant digits
     ///
     /// C  n..2
+    #[edifact(element = "6432", repr = "n..2")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "significant_digits", alias = "_050")
+    )]
     pub _050: Option<String>,
 }
 
@@ -253,14 +552,39 @@ pub struct C174 {
 ///
 /// The identification of the dangerous goods in code.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C205 {
     /// Hazard code identification                        M  an..7
+    #[edifact(element = "8351", repr = "an..7")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "hazard_code_identification", alias = "_010")
+    )]
     pub _010: String,
     /// Hazard substance/item/page number                 C  an..7
+    #[edifact(element = "8078", repr = "an..7")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "hazard_substance_item_page_number", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Hazard code version number                        C  an..10
+    #[edifact(element = "8092", repr = "an..10")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "hazard_code_version_number", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
@@ -268,24 +592,54 @@ pub struct C205 {
 ///
 /// Specification of the dimensions of a transportable unit.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C211 {
     /// Measure unit qualifier
     ///
     /// M  an..3
+    #[edifact(element = "6411", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measure_unit_qualifier", alias = "_010")
+    )]
     pub _010: String,
     /// Length dimension
     ///
     /// C  n..15
+    #[edifact(element = "6168", repr = "n..15")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "length_dimension", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Width dimension
     ///
     /// C  n..15
+    #[edifact(element = "6140", repr = "n..15")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "width_dimension", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Height dimension
     ///
     /// C  n..15
+    #[edifact(element = "6008", repr = "n..15")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "height_dimension", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
@@ -294,24 +648,54 @@ pub struct C211 {
 /// Identification of the issuer of a seal on equipment either
 /// by code or by name.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C215 {
     /// Sealing party, coded
     ///
     /// C  an..3
+    #[edifact(element = "9303", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "sealing_party_coded", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list qualifier
     ///
     /// C  an..3
+    #[edifact(element = "1131", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_qualifier", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency, coded
     ///
     /// C  an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_coded", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Sealing party
     ///
     /// C  an..35
+    #[edifact(element = "9302", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "sealing_party", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
@@ -319,16 +703,36 @@ pub struct C215 {
 ///
 /// Description of type of service for movement of cargo.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C219 {
     /// Movement type, coded
     ///
     /// C  an..3
+    #[edifact(element = "8335", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "movement_type_coded", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Movement type
     ///
     /// C  an..35
+    #[edifact(element = "8334", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "movement_type", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
@@ -336,16 +740,36 @@ pub struct C219 {
 ///
 /// Method of transport code or name. Code preferred.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C220 {
     /// Mode of transport, coded
     ///
     /// C  an..3
+    #[edifact(element = "8067", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "mode_of_transport_coded", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Mode of transport
     ///
     /// C  an..17
+    #[edifact(element = "8066", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "mode_of_transport", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
@@ -353,28 +777,63 @@ pub struct C220 {
 ///
 /// Code and/or name identifying the means of transport.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C222 {
     /// Id. of means of transport identification
     ///
     /// C  an..9
+    #[edifact(element = "8213", repr = "an..9")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "id_of_means_of_transport_identification", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list qualifier
     ///
     /// C  an..3
+    #[edifact(element = "1131", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_qualifier", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency, coded
     ///
     /// C  an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_coded", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Id. of the means of transport
     ///
     /// C  an..35
+    #[edifact(element = "8212", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "id_of_the_means_of_transport", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// Nationality of means of transport, coded
     ///
     /// C  an..3
+    #[edifact(element = "8453", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "nationality_of_means_of_transport_coded", alias = "_050")
+    )]
     pub _050: Option<String>,
 }
 
@@ -383,16 +842,36 @@ pub struct C222 {
 /// Temperature at which a vapor according to ISO 1523/73 can
 /// be ignited.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C223 {
     /// Shipment flashpoint
     ///
     /// C  n3
+    #[edifact(element = "7106", repr = "n3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "shipment_flashpoint", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Measure unit qualifier
     ///
     /// C  an..3
+    #[edifact(element = "6411", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measure_unit_qualifier", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
@@ -401,24 +880,53 @@ pub struct C223 {
 /// Code and/or name identifying size and type of equipment
 /// used in transport. Code preferred.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C224 {
     /// Equipment size and type identification
     ///
     /// C  an..10
+    #[edifact(element = "8155", repr = "an..10")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_size_and_type_identification", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list qualifier
     ///
     /// C  an..3
+    #[edifact(element = "1131", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_qualifier", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency, coded
     ///
     /// C  an..3
-    pub _030: Option<String>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_coded", alias = "_030")
+    )]
+    pub _030: Option<_3055>,
     /// Equipment size and type
     ///
     /// C  an..35
+    #[edifact(element = "8154", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_size_and_type", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
@@ -427,16 +935,36 @@ pub struct C224 {
 /// Code and/or name identifying the type of means of
 /// transport.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C228 {
     /// Type of means of transport identification
     ///
     /// C  an..8
+    #[edifact(element = "8179", repr = "an..8")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "type_of_means_of_transport_identification", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Type of means of transport
     ///
     /// C  an..17
+    #[edifact(element = "8178", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "type_of_means_of_transport", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
@@ -445,16 +973,36 @@ pub struct C228 {
 /// Information on United Nations Dangerous Goods
 /// classification.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C234 {
     /// UNDG number
     ///
     /// C  n4
+    #[edifact(element = "7124", repr = "n4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "undg_number", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Dangerous goods flashpoint
     ///
     /// C  an..8
+    #[edifact(element = "7088", repr = "an..8")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods_flashpoint", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
@@ -463,16 +1011,36 @@ pub struct C234 {
 /// Identification of the Orange placard required on the means
 /// of transport.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C235 {
     /// Hazard identification number, upper part
     ///
     /// C  an..4
+    #[edifact(element = "8158", repr = "an..4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "hazard_identification_number_upper_part", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Substance identification number, lower part
     ///
     /// C  an4
+    #[edifact(element = "8186", repr = "an4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "substance_identification_number_lower_part", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
@@ -481,14 +1049,39 @@ pub struct C235 {
 /// Markings identifying the type of hazardous goods and
 /// similar information.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C236 {
     /// Dangerous goods label marking                     C  an..4
+    #[edifact(element = "8246", repr = "an..4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods_label_marking_010", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Dangerous goods label marking                     C  an..4
+    #[edifact(element = "8246", repr = "an..4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods_label_marking_020", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Dangerous goods label marking                     C  an..4
+    #[edifact(element = "8246", repr = "an..4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods_label_marking_030", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
@@ -497,24 +1090,54 @@ pub struct C236 {
 /// Marks (letters and/or numbers) identifying equipment used
 /// for transport such as a container.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C237 {
     /// Equipment identification number
     ///
     /// C  an..17
+    #[edifact(element = "8260", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_identification_number", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list qualifier
     ///
     /// C  an..3
+    #[edifact(element = "1131", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_qualifier", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency, coded
     ///
     /// C  an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_coded", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Country, coded
     ///
     /// C  an..3
+    #[edifact(element = "3207", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "country_coded", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
@@ -523,12 +1146,32 @@ pub struct C237 {
 /// The temperature under which the goods are (to be) stored
 /// or shipped.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C239 {
     /// Temperature setting                               C  n3
+    #[edifact(element = "6246", repr = "n3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "temperature_setting", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Measure unit qualifier                            C  an..3
+    #[edifact(element = "6411", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measure_unit_qualifier", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
@@ -537,20 +1180,45 @@ pub struct C239 {
 /// Control total for checking integrity of a message or part
 /// of a message.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C270 {
     /// Control qualifier
     ///
     /// M  an..3
+    #[edifact(element = "6069", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "control_qualifier", alias = "_010")
+    )]
     pub _010: String,
     /// Control value
     ///
     /// M  n..18
+    #[edifact(element = "6066", repr = "n..18")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "control_value", alias = "_020")
+    )]
     pub _020: String,
     /// Measure unit qualifier
     ///
     /// C  an..3
+    #[edifact(element = "6411", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measure_unit_qualifier", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
@@ -558,14 +1226,39 @@ pub struct C270 {
 ///
 /// Range minimum and maximum limits.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C280 {
     /// Measure unit qualifier                            M  an..3
+    #[edifact(element = "6411", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measure_unit_qualifier", alias = "_010")
+    )]
     pub _010: String,
     /// Range minimum                                     C  n..18
+    #[edifact(element = "6162", repr = "n..18")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "range_minimum", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Range maximum                                     C  n..18
+    #[edifact(element = "6152", repr = "n..18")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "range_maximum", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
@@ -574,20 +1267,45 @@ pub struct C280 {
 /// To provide details of reason for, and responsibility for,
 /// use of transportation other than normally utilized.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C401 {
     /// Excess transportation reason, coded
     ///
     /// M  an..3
+    #[edifact(element = "8457", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "excess_transportation_reason_coded", alias = "_010")
+    )]
     pub _010: String,
     /// Excess transportation responsibility, coded
     ///
     /// M  an..3
+    #[edifact(element = "8459", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "excess_transportation_responsibility_coded", alias = "_020")
+    )]
     pub _020: String,
     /// Customer authorization number
     ///
     /// C  an..17
+    #[edifact(element = "7130", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "customer_authorization_number", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
@@ -595,16 +1313,45 @@ pub struct C401 {
 ///
 /// Identification of measurement type.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C502 {
     /// Measurement dimension, coded                      C  an..3
-    pub _010: Option<String>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurement_dimension_coded", alias = "_010")
+    )]
+    pub _010: Option<_6313>,
     /// Measurement significance, coded                   C  an..3
+    #[edifact(element = "6321", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurement_significance_coded", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Measurement attribute, coded                      C  an..3
+    #[edifact(element = "6155", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurement_attribute_coded", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Measurement attribute                             C  an..70
+    #[edifact(element = "6154", repr = "an..70")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurement_attribute", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
@@ -612,24 +1359,53 @@ pub struct C502 {
 ///
 /// Identification of a reference.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C506 {
     /// Reference qualifier
     ///
     /// M  an..3
-    pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "reference_qualifier", alias = "_010")
+    )]
+    pub _010: _1153,
     /// Reference number
     ///
     /// C  an..35
+    #[edifact(element = "1154", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "reference_number", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Line number
     ///
     /// C  an..6
+    #[edifact(element = "1156", repr = "an..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "line_number", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Reference version number
     ///
     /// C  an..35
+    #[edifact(element = "4000", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "reference_version_number", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
@@ -638,11 +1414,36 @@ pub struct C506 {
 /// Date and/or time, or period relevant to the specified
 /// date/time/period type.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C507 {
+    #[edifact(element = "2005", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period_qualifier", alias = "_010")
+    )]
     pub _010: String,
+    #[edifact(element = "2380", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "_020")
+    )]
     pub _020: Option<String>,
+    #[edifact(element = "2379", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period_format_qualifier", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
@@ -650,24 +1451,54 @@ pub struct C507 {
 ///
 /// Identification of a location by code or name.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C517 {
     /// Place/location identification
     ///
     /// C  an..25
+    #[edifact(element = "3225", repr = "an..25")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list qualifier
     ///
     /// C  an..3
+    #[edifact(element = "1131", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_qualifier", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency, coded
     ///
     /// C  an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_coded", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Place/location
     ///
     /// C  an..17
+    #[edifact(element = "3224", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
@@ -675,24 +1506,54 @@ pub struct C517 {
 ///
 /// Identification the first related location by code or name.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C519 {
     /// Related place/location one identification
     ///
     /// C  an..25
+    #[edifact(element = "3223", repr = "an..25")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "first_related_location_name_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list qualifier
     ///
     /// C  an..3
+    #[edifact(element = "1131", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency, coded
     ///
     /// C  an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Related place/location one
     ///
     /// C  an..70
+    #[edifact(element = "3222", repr = "an..70")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "first_related_location_name", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
@@ -700,12 +1561,32 @@ pub struct C519 {
 ///
 /// Identification of number of units and its purpose.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C523 {
     /// Number of units                                   C  n..15
+    #[edifact(element = "6350", repr = "n..15")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_units", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Number of units qualifier                         C  an..3
+    #[edifact(element = "6353", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_units_qualifier", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
 
@@ -713,24 +1594,54 @@ pub struct C523 {
 ///
 /// Identification of second related location by code or name.
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct C553 {
     /// Related place/location two identification
     ///
     /// C  an..25
+    #[edifact(element = "3233", repr = "an..25")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "second_related_location_name_code", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// Code list qualifier
     ///
     /// C  an..3
+    #[edifact(element = "1131", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_identification_code", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// Code list responsible agency, coded
     ///
     /// C  an..3
+    #[edifact(element = "3055", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "code_list_responsible_agency_code", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// Related place/location two
     ///
     /// C  an..70
+    #[edifact(element = "3232", repr = "an..70")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "second_related_location_name", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
@@ -738,10 +1649,28 @@ pub struct C553 {
 ///
 /// Identification of the agency controlling the syntax and indication of syntax level, plus the syntax version number.
 #[derive(
-    Debug, Serialize, Deserialize, Clone, DisplayInnerSegment, ParseElement, PartialEq, Eq, Default,
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    PartialEq,
+    Eq,
+    Default,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct S001 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "syntax_identifier", alias = "_010")
+    )]
     pub _010: _0001,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "syntax_version_number", alias = "_020")
+    )]
     pub _020: _0002,
 }
 
@@ -749,18 +1678,42 @@ pub struct S001 {
 ///
 /// Identification of the sender of the interchange.
 #[derive(
-    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, PartialEq, Eq,
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    DisplayInnerSegment,
+    ParseElement,
+    PartialEq,
+    Eq,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct S002 {
     /// Sender identification
     ///
     /// Name or coded representation of the sender of a data interchange.
     /// Code or name as specified in IA.
+    #[edifact(element = "0004", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "sender_identification", alias = "_010")
+    )]
     pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "partner_identification_code_qualifier", alias = "_020")
+    )]
     pub _020: Option<_0007>,
     /// Address for reverse routing
     ///
     /// Address specified by the sender of an interchange to be included by the recipient in the response interchanges to facilitate internal routing.
+    #[edifact(element = "0008", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "address_for_reverse_routing", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
@@ -768,19 +1721,43 @@ pub struct S002 {
 ///
 /// Identification of the recipient of the interchange.
 #[derive(
-    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, PartialEq, Eq,
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    DisplayInnerSegment,
+    ParseElement,
+    PartialEq,
+    Eq,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct S003 {
     /// Recipient identification
     ///
     /// Name or coded representation of the recipient of a data interchange.
     /// Code or name as specified in IA.
+    #[edifact(element = "0010", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "recipient_identification", alias = "_010")
+    )]
     pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "partner_identification_code_qualifier", alias = "_020")
+    )]
     pub _020: Option<_0007>,
     /// Routing address
     ///
     /// Address specified by the recipient of an interchange to be included by the sender and used by the recipient for routing of received interchanges inside his organization.
     /// If used, normally coded sub-address for onward routing.
+    #[edifact(element = "0014", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "routing_address", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
@@ -788,18 +1765,38 @@ pub struct S003 {
 ///
 /// Date/time of preparation of the interchange.
 #[derive(
-    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, PartialEq, Eq,
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    DisplayInnerSegment,
+    ParseElement,
+    PartialEq,
+    Eq,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct S004 {
     /// Date of preparation
     ///
     /// Local date when an interchange or a functional group was prepared.
     /// YYMMDD
+    #[edifact(element = "0017", repr = "n..8")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_of_preparation", alias = "_010")
+    )]
     pub _010: String,
     /// Time of preparation
     ///
     /// Local time of day when an interchange or a functional group was prepared.
     /// HHMM
+    #[edifact(element = "0019", repr = "n4")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "time_of_preparation", alias = "_020")
+    )]
     pub _020: String,
 }
 
@@ -807,63 +1804,238 @@ pub struct S004 {
 ///
 /// Reference or password as agreed between the communicating partners.
 #[derive(
-    Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, ParseElement, PartialEq, Eq,
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    DisplayInnerSegment,
+    ParseElement,
+    PartialEq,
+    Eq,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct S005 {
     /// Recipient's reference/password
     ///
     /// Unique reference assigned by the recipient to the data interchange or a password
     /// to the recipient's system or to a third party network as specified in the partners interchange agreement.
     /// As specified in IA. May be password to recipient's system or to third party network.
+    #[edifact(element = "0022", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "recipients_reference_password", alias = "_010")
+    )]
     pub _010: String,
     /// Recipient's reference/password qualifier
     ///
     /// Qualifier for the recipient's reference or password.
     /// Used if specified in IA.
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "recipients_reference_password_qualifier", alias = "_020")
+    )]
     pub _020: Option<_0025>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment)]
+/// Application sender identification
+///
+/// Identification of the sender division, department etc. from which a group of messages is sent.
+#[derive(
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    DisplayInnerSegment,
+    ParseElement,
+    PartialEq,
+    Eq,
+    Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct S006 {
+    /// Application sender identification
+    ///
+    /// Name or code identifying the division, department etc. within the originating sender's organization,
+    /// which may be the application sender.
+    /// M   an..35
+    #[edifact(element = "0040", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "application_sender_identification", alias = "_010")
+    )]
+    pub _010: String,
+    /// Partner identification code qualifier
+    ///
+    /// C   an..4
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "partner_identification_code_qualifier", alias = "_020")
+    )]
+    pub _020: Option<_0007>,
+}
+
+/// Application recipient identification
+///
+/// Identification of the recipient division, department etc. for which a group of messages is intended.
+#[derive(
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    DisplayInnerSegment,
+    ParseElement,
+    PartialEq,
+    Eq,
+    Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct S007 {
+    /// Application recipient identification
+    ///
+    /// Name or code identifying the division, department etc. within the recipient's organization
+    /// for which the group of messages is intended.
+    /// M   an..35
+    #[edifact(element = "0044", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "application_recipient_identification", alias = "_010")
+    )]
+    pub _010: String,
+    /// Partner identification code qualifier
+    ///
+    /// C   an..4
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "partner_identification_code_qualifier", alias = "_020")
+    )]
+    pub _020: Option<_0007>,
+}
+
+/// Message version
+///
+/// Specification of the message version and release number of the messages in the functional group.
+#[derive(
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    DisplayInnerSegment,
+    ParseElement,
+    PartialEq,
+    Eq,
+    Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct S008 {
+    /// Message version number
+    ///
+    /// M   an..3
+    #[edifact(element = "0052", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_version_number", alias = "_010")
+    )]
+    pub _010: String,
+    /// Message release number
+    ///
+    /// M   an..3
+    #[edifact(element = "0054", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_release_number", alias = "_020")
+    )]
+    pub _020: String,
+    /// Association assigned code
+    ///
+    /// C   an..6
+    #[edifact(element = "0057", repr = "an..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "association_assigned_code", alias = "_030")
+    )]
+    pub _030: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, DisplayInnerSegment, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct S009 {
     /// Message type
     ///
     /// M   an1..6
+    #[edifact(element = "0065", repr = "an1..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_type", alias = "_010")
+    )]
     pub _010: String,
     /// Message version number
     ///
     /// M   an1..3
+    #[edifact(element = "0052", repr = "an1..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_version_number", alias = "_020")
+    )]
     pub _020: String,
     /// Message release number
     ///
     /// M   an1..3
+    #[edifact(element = "0054", repr = "an1..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_release_number", alias = "_030")
+    )]
     pub _030: String,
     /// Controlling agency, coded
     ///
     /// M   an1..3
+    #[edifact(element = "0051", repr = "an1..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "controlling_agency_coded", alias = "_040")
+    )]
     pub _040: String,
     /// Association assigned code
     ///
     /// C   an1..6
+    #[edifact(element = "0057", repr = "an1..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "association_assigned_code", alias = "_050")
+    )]
     pub _050: Option<String>,
 }
 
-impl<'a> Parser<&'a str, S009, nom::error::Error<&'a str>> for S009 {
+impl<'a> Parser<&'a str, S009, crate::util::Error<&'a str>> for S009 {
     fn parse(input: &'a str) -> IResult<&'a str, S009> {
         let (_, vars) = crate::util::parse_colon_section(input)?;
-        let v = vars.get(1).unwrap().to_string();
-        let r = vars.get(2).unwrap().to_string();
+        let mandatory = |idx: usize, position: &'static str| match vars.get(idx) {
+            Some(x) if !x.is_empty() => Ok(crate::util::unescape(x)),
+            _ => Err(nom::Err::Failure(
+                crate::util::Error::missing(input, "String").within(position),
+            )),
+        };
+        let v = mandatory(1, "020")?;
+        let r = mandatory(2, "030")?;
         if format!("{v}{r}") != VERSION {
-            return Err(nom::Err::Error(nom::error::Error::new(
-                "File supplied EDIFACT Version/Release in UNH segment. Please use the correct parsing feature",
-                nom::error::ErrorKind::Verify,
+            // the supplied Version/Release needs the parser of another directory
+            return Err(nom::Err::Error(crate::util::Error::unexpected_value(
+                input,
+                &format!("{v}{r}"),
+                VERSION,
             )));
         }
         let output = S009 {
-            _010: vars.first().unwrap().to_string(),
+            _010: mandatory(0, "010")?,
             _020: v,
             _030: r,
-            _040: vars.get(3).unwrap().to_string(),
-            _050: vars.get(4).map(|x| x.to_string()),
+            _040: mandatory(3, "040")?,
+            _050: vars.get(4).map(|x| crate::util::unescape(x)),
         };
         Ok(("", output))
     }
@@ -871,15 +2043,35 @@ impl<'a> Parser<&'a str, S009, nom::error::Error<&'a str>> for S009 {
 
 /// STATUS OF THE TRANSFER
 #[derive(
-    Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone, DisplayInnerSegment, ParseElement,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    Clone,
+    DisplayInnerSegment,
+    ParseElement,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct S010 {
     /// Sequence of transfers
     ///
     /// M  n..2
+    #[edifact(element = "0070", repr = "n..2")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "sequence_of_transfers", alias = "_010")
+    )]
     pub _010: String,
     /// First and last transfer
     ///
     /// C  a1
+    #[edifact(element = "0073", repr = "a1")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "first_and_last_transfer", alias = "_020")
+    )]
     pub _020: Option<String>,
 }
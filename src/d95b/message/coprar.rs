@@ -1,60 +1,212 @@
 use crate::d95b::*;
-use edifact_types_macros::{DisplayEdifact, DisplayEdifactSg, ParseMsg, ParseSg};
+use edifact_types_macros::{DisplayEdifact, DisplayEdifactSg, ParseMsg, ParseSg, Validate};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Default, Debug, Serialize, Deserialize, DisplayEdifact, ParseMsg)]
+#[derive(Default, Debug, Serialize, Deserialize, DisplayEdifact, ParseMsg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPRAR {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_header", alias = "unh")
+    )]
     pub unh: UNH,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "beginning_of_message", alias = "bgm")
+    )]
     pub bgm: BGM,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Vec<FTX>,
+    #[edifact(min = 1, max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: Vec<RFF>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "details_of_transport_group", alias = "segment_group_1")
+    )]
     pub segment_group_1: COPRARSegmentgroup1,
+    #[edifact(min = 1, max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address_group", alias = "segment_group_2")
+    )]
     pub segment_group_2: Vec<COPRARSegmentgroup2>,
+    #[edifact(min = 1, max = 9999)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_details_group", alias = "segment_group_3")
+    )]
     pub segment_group_3: Vec<COPRARSegmentgroup3>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "control_total", alias = "cnt")
+    )]
     pub cnt: CNT,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_trailer", alias = "unt")
+    )]
     pub unt: UNT,
 }
 
-#[derive(Default, Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Default, Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPRARSegmentgroup1 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "details_of_transport", alias = "tdt")
+    )]
     pub tdt: TDT,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: Vec<RFF>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Vec<LOC>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Vec<FTX>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPRARSegmentgroup2 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address", alias = "nad")
+    )]
     pub nad: NAD,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_information", alias = "cta")
+    )]
     pub cta: Vec<CTA>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPRARSegmentgroup3 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_details", alias = "eqd")
+    )]
     pub eqd: EQD,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: Vec<RFF>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_units", alias = "eqn")
+    )]
     pub eqn: Option<EQN>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_movement_details", alias = "tmd")
+    )]
     pub tmd: Vec<TMD>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Vec<LOC>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurements", alias = "mea")
+    )]
     pub mea: Vec<MEA>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dimensions", alias = "dim")
+    )]
     pub dim: Vec<DIM>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "temperature", alias = "tmp")
+    )]
     pub tmp: Vec<TMP>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "range_details", alias = "rng")
+    )]
     pub rng: Vec<RNG>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "seal_number", alias = "sel")
+    )]
     pub sel: Vec<SEL>,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "free_text", alias = "ftx"))]
     pub ftx: Vec<FTX>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods", alias = "dgs")
+    )]
     pub dgs: Vec<DGS>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "attached_equipment", alias = "eqa")
+    )]
     pub eqa: Vec<EQA>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "details_of_transport_group", alias = "segment_group_4")
+    )]
     pub segment_group_4: Option<COPRARSegmentgroup4>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address", alias = "nad")
+    )]
     pub nad: Option<NAD>,
 }
 
-#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]
+#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct COPRARSegmentgroup4 {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "details_of_transport", alias = "tdt")
+    )]
     pub tdt: TDT,
+    #[edifact(max = 9)]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "reference", alias = "rff"))]
     pub rff: Vec<RFF>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_identification", alias = "loc")
+    )]
     pub loc: Vec<LOC>,
+    #[edifact(max = 9)]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "dtm")
+    )]
     pub dtm: Vec<DTM>,
 }
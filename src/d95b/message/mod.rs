@@ -1,3 +1,4 @@
+pub mod any;
 // pub mod baplie;
 pub mod coprar;
 
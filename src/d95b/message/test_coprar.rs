@@ -46,7 +46,7 @@ fn render_bgm() {
 #[test]
 fn render_eqd() {
     let x = EQD {
-        _010: "CN".to_string(),
+        _010: _8053::CN,
         _020: Some(C237 {
             _010: Some("MSTI6415664".to_string()),
             ..Default::default()
@@ -54,7 +54,7 @@ fn render_eqd() {
         _030: Some(C224 {
             _010: Some("45G1".to_string()),
             _020: Some("102".to_string()),
-            _030: Some("5".to_string()),
+            _030: Some(_3055::_5),
             ..Default::default()
         }),
         _040: None,
@@ -90,7 +90,7 @@ fn render_coprar() {
             _040: Some(_4343::AB),
         },
         ftx: vec![FTX {
-            _010: "OSI".to_string(),
+            _010: _4451::OSI,
             _030: Some(C107 {
                 _010: "L".to_string(),
                 ..Default::default()
@@ -99,14 +99,14 @@ fn render_coprar() {
         }],
         rff: vec![RFF {
             _010: C506 {
-                _010: "XXX".to_string(),
+                _010: _1153::ACD,
                 _020: Some("1".to_string()),
                 ..Default::default()
             },
         }],
         segment_group_1: COPRARSegmentgroup1 {
             tdt: TDT {
-                _010: "20".to_string(),
+                _010: _8051::_20,
                 _020: Some("123W".to_string()),
                 _030: Some(C220 {
                     _010: Some("1".to_string()),
@@ -127,7 +127,7 @@ fn render_coprar() {
                 ..Default::default()
             },
             loc: vec![LOC {
-                _010: "9".to_string(),
+                _010: _3227::_9,
                 _020: Some(C517 {
                     _010: Some("AEJEA".to_string()),
                     _020: Some("139".to_string()),
@@ -156,7 +156,7 @@ fn render_coprar() {
         },
         segment_group_2: vec![COPRARSegmentgroup2 {
             nad: NAD {
-                _010: "CA".to_string(),
+                _010: _3035::CA,
                 _020: Some(C082 {
                     _010: "MSK".to_string(),
                     _020: Some("160".to_string()),
@@ -169,7 +169,7 @@ fn render_coprar() {
         segment_group_3: vec![
             COPRARSegmentgroup3 {
                 eqd: EQD {
-                    _010: "CN".to_string(),
+                    _010: _8053::CN,
                     _020: Some(C237 {
                         _010: Some("MSTI6415664".to_string()),
                         ..Default::default()
@@ -177,7 +177,7 @@ fn render_coprar() {
                     _030: Some(C224 {
                         _010: Some("45G1".to_string()),
                         _020: Some("102".to_string()),
-                        _030: Some("5".to_string()),
+                        _030: Some(_3055::_5),
                         ..Default::default()
                     }),
                     _050: Some("6".to_string()),
@@ -186,7 +186,7 @@ fn render_coprar() {
                 },
                 rff: vec![RFF {
                     _010: C506 {
-                        _010: "BN".to_string(),
+                        _010: _1153::BN,
                         _020: Some("2GO2005174".to_string()),
                         ..Default::default()
                     },
@@ -196,7 +196,7 @@ fn render_coprar() {
                 dtm: vec![],
                 loc: vec![
                     LOC {
-                        _010: "11".to_string(),
+                        _010: _3227::_11,
                         _020: Some(C517 {
                             _010: Some("INNSA".to_string()),
                             _020: Some("139".to_string()),
@@ -206,7 +206,7 @@ fn render_coprar() {
                         ..Default::default()
                     },
                     LOC {
-                        _010: "7".to_string(),
+                        _010: _3227::_7,
                         _020: Some(C517 {
                             _010: Some("INNSA".to_string()),
                             _020: Some("139".to_string()),
@@ -218,9 +218,9 @@ fn render_coprar() {
                 ],
                 mea: vec![
                     MEA {
-                        _010: "AAE".to_string(),
+                        _010: _6311::AAE,
                         _020: Some(C502 {
-                            _010: Some("G".to_string()),
+                            _010: Some(_6313::G),
                             ..Default::default()
                         }),
                         _030: Some(C174 {
@@ -231,9 +231,9 @@ fn render_coprar() {
                         ..Default::default()
                     },
                     MEA {
-                        _010: "AAE".to_string(),
+                        _010: _6311::AAE,
                         _020: Some(C502 {
-                            _010: Some("VGM".to_string()),
+                            _010: Some(_6313::VGM),
                             ..Default::default()
                         }),
                         _030: Some(C174 {
@@ -249,10 +249,9 @@ fn render_coprar() {
                 rng: vec![],
                 sel: vec![],
                 ftx: vec![FTX {
-                    _010: "AAA".to_string(),
+                    _010: _4451::AAA,
                     _040: Some(C108 {
-                        _010: "PLASTICS?".to_string(),
-                        _020: Some("ARTICLES".to_string()),
+                        _010: "PLASTICS:ARTICLES".to_string(),
                         ..Default::default()
                     }),
                     ..Default::default()
@@ -261,7 +260,7 @@ fn render_coprar() {
                 eqa: vec![],
                 segment_group_4: Some(COPRARSegmentgroup4 {
                     tdt: TDT {
-                        _010: "10".to_string(),
+                        _010: _8051::_10,
                         _020: Some("142E".to_string()),
                         _030: Some(C220 {
                             _010: Some("1".to_string()),
@@ -282,7 +281,7 @@ fn render_coprar() {
                     dtm: vec![],
                 }),
                 nad: Some(NAD {
-                    _010: "CF".to_string(),
+                    _010: _3035::CF,
                     _020: Some(C082 {
                         _010: "MSK".to_string(),
                         _020: Some("160".to_string()),
@@ -293,7 +292,7 @@ fn render_coprar() {
             },
             COPRARSegmentgroup3 {
                 eqd: EQD {
-                    _010: "CN".to_string(),
+                    _010: _8053::CN,
                     _020: Some(C237 {
                         _010: Some("MSTI6415664".to_string()),
                         ..Default::default()
@@ -301,7 +300,7 @@ fn render_coprar() {
                     _030: Some(C224 {
                         _010: Some("45G1".to_string()),
                         _020: Some("102".to_string()),
-                        _030: Some("5".to_string()),
+                        _030: Some(_3055::_5),
                         ..Default::default()
                     }),
                     _050: Some("6".to_string()),
@@ -310,7 +309,7 @@ fn render_coprar() {
                 },
                 rff: vec![RFF {
                     _010: C506 {
-                        _010: "BN".to_string(),
+                        _010: _1153::BN,
                         _020: Some("2GO2005174".to_string()),
                         ..Default::default()
                     },
@@ -320,7 +319,7 @@ fn render_coprar() {
                 dtm: vec![],
                 loc: vec![
                     LOC {
-                        _010: "11".to_string(),
+                        _010: _3227::_11,
                         _020: Some(C517 {
                             _010: Some("INNSA".to_string()),
                             _020: Some("139".to_string()),
@@ -330,7 +329,7 @@ fn render_coprar() {
                         ..Default::default()
                     },
                     LOC {
-                        _010: "7".to_string(),
+                        _010: _3227::_7,
                         _020: Some(C517 {
                             _010: Some("INNSA".to_string()),
                             _020: Some("139".to_string()),
@@ -342,9 +341,9 @@ fn render_coprar() {
                 ],
                 mea: vec![
                     MEA {
-                        _010: "AAE".to_string(),
+                        _010: _6311::AAE,
                         _020: Some(C502 {
-                            _010: Some("G".to_string()),
+                            _010: Some(_6313::G),
                             ..Default::default()
                         }),
                         _030: Some(C174 {
@@ -355,9 +354,9 @@ fn render_coprar() {
                         ..Default::default()
                     },
                     MEA {
-                        _010: "AAE".to_string(),
+                        _010: _6311::AAE,
                         _020: Some(C502 {
-                            _010: Some("VGM".to_string()),
+                            _010: Some(_6313::VGM),
                             ..Default::default()
                         }),
                         _030: Some(C174 {
@@ -373,10 +372,9 @@ fn render_coprar() {
                 rng: vec![],
                 sel: vec![],
                 ftx: vec![FTX {
-                    _010: "AAA".to_string(),
+                    _010: _4451::AAA,
                     _040: Some(C108 {
-                        _010: "PLASTICS?".to_string(),
-                        _020: Some("ARTICLES".to_string()),
+                        _010: "PLASTICS:ARTICLES".to_string(),
                         ..Default::default()
                     }),
                     ..Default::default()
@@ -385,7 +383,7 @@ fn render_coprar() {
                 eqa: vec![],
                 segment_group_4: Some(COPRARSegmentgroup4 {
                     tdt: TDT {
-                        _010: "10".to_string(),
+                        _010: _8051::_10,
                         _020: Some("142E".to_string()),
                         _030: Some(C220 {
                             _010: Some("1".to_string()),
@@ -406,7 +404,7 @@ fn render_coprar() {
                     dtm: vec![],
                 }),
                 nad: Some(NAD {
-                    _010: "CF".to_string(),
+                    _010: _3035::CF,
                     _020: Some(C082 {
                         _010: "MSK".to_string(),
                         _020: Some("160".to_string()),
@@ -433,3 +431,45 @@ fn render_coprar() {
     let edi = std::fs::read_to_string("./test-data/d95b_coprar.edi").unwrap();
     assert_eq!(edi, str.trim());
 }
+
+#[test]
+fn validate_coprar() {
+    use crate::control::Finalize;
+    use crate::validate::Validate;
+    let edi = std::fs::read_to_string("./test-data/d95b_coprar.edi").unwrap();
+    let (_, mut obj) = COPRAR::parse(&edi).unwrap();
+    // the sample counts 56 instead of 29 segments
+    let violations = obj.validate();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].path, "UNT/010");
+    obj.finalize();
+    assert_eq!(obj.unt._010, "29");
+    assert_eq!(obj.validate(), vec![]);
+    obj.segment_group_1.ftx = (0..10).map(|_| FTX::default()).collect();
+    obj.finalize();
+    let violations = obj.validate();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].path, "SG1/FTX");
+}
+
+#[cfg(feature = "xml")]
+#[test]
+fn coprar_xml_roundtrip() {
+    use crate::xml::XmlMessage;
+    let edi = std::fs::read_to_string("./test-data/d95b_coprar.edi").unwrap();
+    let (_, obj) = COPRAR::parse(&edi).unwrap();
+    let xml = obj.to_xml();
+    assert!(xml.contains("<COPRAR><UNH><E0062>"));
+    assert!(xml.contains("<SG1><TDT>"));
+    let parsed = COPRAR::from_xml(&xml).unwrap();
+    assert_eq!(parsed.to_string(), obj.to_string());
+}
+
+#[test]
+fn coprar_serde_format() {
+    let edi = std::fs::read_to_string("./test-data/d95b_coprar.edi").unwrap();
+    let (_, obj) = COPRAR::parse(&edi).unwrap();
+    let parsed: COPRAR = crate::format::from_str(&edi).unwrap();
+    assert_eq!(parsed.to_string(), obj.to_string());
+    assert_eq!(crate::format::to_string(&parsed).unwrap(), obj.to_string());
+}
@@ -0,0 +1,86 @@
+use super::{C174, C239, MEA, TMP};
+use crate::numeric::{self, NumericError};
+use crate::validate::{Representation, RepresentationKind};
+use rust_decimal::Decimal;
+
+/// Numeric representation with up to `max` digits, e.g. `n..18`.
+const fn digits(max: usize) -> Representation {
+    Representation {
+        kind: RepresentationKind::Numeric,
+        min: 0,
+        max,
+    }
+}
+
+fn read(value: Option<&str>) -> Result<Option<Decimal>, NumericError> {
+    value.map(numeric::parse).transpose()
+}
+
+impl C174 {
+    /// Measure (6314).
+    pub fn measure(&self) -> Result<Option<Decimal>, NumericError> {
+        read(self._020.as_deref())
+    }
+
+    /// Writes `value` as measure (6314) with the current decimal mark.
+    ///
+    /// Fails with [`NumericError::TooManyDigits`] for more than the 18 digits of `n..18`.
+    pub fn set_measure(&mut self, value: Decimal) -> Result<(), NumericError> {
+        self._020 = Some(numeric::format(value, &digits(18))?);
+        Ok(())
+    }
+}
+
+impl C239 {
+    /// Temperature degree (6246), written with three digits.
+    pub fn temperature(&self) -> Result<Option<Decimal>, NumericError> {
+        read(self._010.as_deref())
+    }
+
+    /// Writes `value` as temperature degree (6246) with the current decimal mark.
+    ///
+    /// Fails with [`NumericError::TooManyDigits`] for more than the 3 digits of `n3`.
+    pub fn set_temperature(&mut self, value: Decimal) -> Result<(), NumericError> {
+        let n3 = Representation {
+            min: 3,
+            ..digits(3)
+        };
+        self._010 = Some(numeric::format(value, &n3)?);
+        Ok(())
+    }
+}
+
+impl MEA {
+    /// The measure of C174, `None` without C174 or measure.
+    pub fn measure(&self) -> Result<Option<Decimal>, NumericError> {
+        self._030.as_ref().map_or(Ok(None), C174::measure)
+    }
+
+    /// Sets unit (6411) and measure of C174, adding C174 when missing.
+    ///
+    /// Fails like [`C174::set_measure`], leaving C174 unchanged.
+    pub fn set_measure(&mut self, unit: &str, value: Decimal) -> Result<(), NumericError> {
+        let mut measurement = self._030.clone().unwrap_or_default();
+        measurement._010 = unit.to_string();
+        measurement.set_measure(value)?;
+        self._030 = Some(measurement);
+        Ok(())
+    }
+}
+
+impl TMP {
+    /// The temperature degree of C239.
+    pub fn temperature(&self) -> Result<Option<Decimal>, NumericError> {
+        self._020.as_ref().map_or(Ok(None), C239::temperature)
+    }
+
+    /// Writes `value` as temperature degree of C239, adding C239 when missing.
+    ///
+    /// Fails like [`C239::set_temperature`].
+    pub fn set_temperature(&mut self, value: Decimal) -> Result<(), NumericError> {
+        let mut temperature = self._020.clone().unwrap_or_default();
+        temperature.set_temperature(value)?;
+        self._020 = Some(temperature);
+        Ok(())
+    }
+}
@@ -1,7 +1,6 @@
 use super::*;
-use crate::util::{clean_num, Parser};
-use edifact_types_macros::{DisplayOuterSegment, ParseSegment};
-use nom::{bytes::complete::take_until, character::complete::not_line_ending, IResult};
+use crate::util::{clean_num, parse_una, Delimiters, IResult, Parser};
+use edifact_types_macros::{DisplayOuterSegment, ParseSegment, Validate};
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::{self, Debug},
@@ -11,24 +10,48 @@ use std::{
 ///
 /// To indicate the type and function of a message and to
 /// transmit the identifying number.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct BGM {
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_name", alias = "_010")
+    )]
     pub _010: Option<C002>,
     /// 1004 - Document/message number
     ///
     /// Reference number assigned to the document/message by the issuer.
     /// an..35
+    #[edifact(element = "1004", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "document_message_number", alias = "_020")
+    )]
     pub _020: Option<String>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_function_coded", alias = "_030")
+    )]
     pub _030: Option<_1225>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "response_type_coded", alias = "_040")
+    )]
     pub _040: Option<_4343>,
 }
 
 /// CNT - CONTROL TOTAL
 ///
 /// To provide control total.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct CNT {
     /// CONTROL
+    #[cfg_attr(feature = "semantic-names", serde(rename = "control", alias = "_010"))]
     pub _010: C270,
 }
 
@@ -36,154 +59,319 @@ pub struct CNT {
 ///
 /// To identify a person or a department to whom
 /// communication should be directed.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct CTA {
     /// CONTACT FUNCTION, CODED
     ///
     /// C  an..3
+    #[edifact(element = "3139", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "contact_function_coded", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// DEPARTMENT OR EMPLOYEE DETAILS
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "department_or_employee_details", alias = "_020")
+    )]
     pub _020: Option<C056>,
 }
 
 /// DGS - DANGEROUS GOODS
 ///
 /// To identify dangerous goods.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct DGS {
     /// DANGEROUS GOODS REGULATIONS, CODED
     ///
     /// C  an..3
+    #[edifact(element = "8273", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods_regulations_coded", alias = "_010")
+    )]
     pub _010: Option<String>,
     /// HAZARD CODE
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "hazard_code", alias = "_020")
+    )]
     pub _020: Option<C205>,
     /// UNDG INFORMATION
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "undg_information", alias = "_030")
+    )]
     pub _030: Option<C234>,
     /// DANGEROUS GOODS SHIPMENT FLASHPOINT
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods_shipment_flashpoint", alias = "_040")
+    )]
     pub _040: Option<C223>,
     /// PACKING GROUP, CODED
     ///
     /// C  an..3
+    #[edifact(element = "8339", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "packing_group_coded", alias = "_050")
+    )]
     pub _050: Option<String>,
     /// EMS NUMBER
     ///
     /// C  an..6
+    #[edifact(element = "8364", repr = "an..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "ems_number", alias = "_060")
+    )]
     pub _060: Option<String>,
     /// MFAG
     ///
     /// C  an..4
+    #[edifact(element = "8410", repr = "an..4")]
+    #[cfg_attr(feature = "semantic-names", serde(rename = "mfag", alias = "_070"))]
     pub _070: Option<String>,
     /// TREM CARD NUMBER
     ///
     /// C  an..10
+    #[edifact(element = "8126", repr = "an..10")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "trem_card_number", alias = "_080")
+    )]
     pub _080: Option<String>,
     /// HAZARD IDENTIFICATION
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "hazard_identification", alias = "_090")
+    )]
     pub _090: Option<C235>,
     /// DANGEROUS GOODS LABEL
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dangerous_goods_label", alias = "_100")
+    )]
     pub _100: Option<C236>,
     /// PACKING INSTRUCTION, CODED
     ///
     /// C  an..3
+    #[edifact(element = "8255", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "packing_instruction_coded", alias = "_110")
+    )]
     pub _110: Option<String>,
     /// CATEGORY OF MEANS OF TRANSPORT, CODED
     ///
     /// C  an..3
+    #[edifact(element = "8325", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "category_of_means_of_transport_coded", alias = "_120")
+    )]
     pub _120: Option<String>,
     /// PERMISSION FOR TRANSPORT, CODED
     ///
     /// C  an..3
+    #[edifact(element = "8211", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "permission_for_transport_coded", alias = "_130")
+    )]
     pub _130: Option<String>,
 }
 
 /// DIM - DIMENSIONS
 ///
 /// To specify dimensions.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct DIM {
     /// DIMENSION QUALIFIER
     ///
     /// M  an..3
+    #[edifact(element = "6145", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dimension_qualifier", alias = "_010")
+    )]
     pub _010: String,
     /// DIMENSIONS
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "dimensions", alias = "_020")
+    )]
     pub _020: C211,
 }
 
 /// DTM - DATE/TIME/PERIOD
 ///
 /// To specify date, and/or time, or period.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct DTM {
     /// DATE/TIME/PERIOD
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_period", alias = "_010")
+    )]
     pub _010: C507,
 }
 
 /// EQA - ATTACHED EQUIPMENT
 ///
 /// To specify attached or related equipment.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct EQA {
     /// EQUIPMENT QUALIFIER
     ///
     /// M  an..3
+    #[edifact(element = "8053", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_qualifier", alias = "_010")
+    )]
     pub _010: String,
     /// EQUIPMENT IDENTIFICATION
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_identification", alias = "_020")
+    )]
     pub _020: Option<C237>,
 }
 
 /// EQD - EQUIPMENT DETAILS
 ///
 /// To identify a unit of equipment.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct EQD {
     /// EQUIPMENT QUALIFIER
     ///
     /// M  an..3
-    pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_qualifier", alias = "_010")
+    )]
+    pub _010: _8053,
     /// EQUIPMENT IDENTIFICATION
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_identification", alias = "_020")
+    )]
     pub _020: Option<C237>,
     /// EQUIPMENT SIZE AND TYPE
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_size_and_type", alias = "_030")
+    )]
     pub _030: Option<C224>,
     /// EQUIPMENT SUPPLIER, CODED
     ///
     /// C  an..3
+    #[edifact(element = "8077", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_supplier_coded", alias = "_040")
+    )]
     pub _040: Option<String>,
     /// EQUIPMENT STATUS, CODED
     ///
     /// C  an..3
+    #[edifact(element = "8249", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_status_coded", alias = "_050")
+    )]
     pub _050: Option<String>,
     /// FULL/EMPTY INDICATOR, CODED
     ///
     /// C  an..3
+    #[edifact(element = "8169", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "full_empty_indicator_coded", alias = "_060")
+    )]
     pub _060: Option<String>,
 }
 
 /// EQN - NUMBER OF UNITS
 ///
 /// To specify the number of units.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct EQN {
     /// NUMBER OF UNIT DETAILS
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_unit_details", alias = "_010")
+    )]
     pub _010: C523,
 }
 
 /// FTX - Free Text
 ///
 /// To provide free form or coded text information.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct FTX {
     /// TEXT SUBJECT QUALIFIER
     ///
     /// M  an..3
-    pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "text_subject_qualifier", alias = "_010")
+    )]
+    pub _010: _4451,
     /// TEXT FUNCTION, CODED
     ///
     /// C  an..3
+    #[edifact(element = "4453", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "text_function_coded", alias = "_020")
+    )]
     pub _020: Option<String>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "text_reference", alias = "_030")
+    )]
     pub _030: Option<C107>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "text_literal", alias = "_040")
+    )]
     pub _040: Option<C108>,
     /// LANGUAGE, CODED
     ///
     /// C  an..3
+    #[edifact(element = "3453", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "language_coded", alias = "_050")
+    )]
     pub _050: Option<String>,
 }
 
@@ -191,15 +379,35 @@ pub struct FTX {
 ///
 /// To specify physical measurements, including dimension
 /// tolerances, weights and counts.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct MEA {
     /// MEASUREMENT APPLICATION QUALIFIER                     M  an..3
-    pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurement_application_qualifier", alias = "_010")
+    )]
+    pub _010: _6311,
     /// MEASUREMENT DETAILS                                   C  
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "measurement_details", alias = "_020")
+    )]
     pub _020: Option<C502>,
     /// VALUE/RANGE                                           C  
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "value_range", alias = "_030")
+    )]
     pub _030: Option<C174>,
     /// SURFACE/LAYER INDICATOR, CODED                        C  an..3
+    #[edifact(element = "7383", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "surface_layer_indicator_coded", alias = "_040")
+    )]
     pub _040: Option<String>,
 }
 
@@ -207,15 +415,39 @@ pub struct MEA {
 ///
 /// To identify a country/place/location/related location
 /// one/related location two.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct LOC {
-    pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "place_location_qualifier", alias = "_010")
+    )]
+    pub _010: _3227,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "location_identification", alias = "_020")
+    )]
     pub _020: Option<C517>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "related_location_one_identification", alias = "_030")
+    )]
     pub _030: Option<C519>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "related_location_two_identification", alias = "_040")
+    )]
     pub _040: Option<C553>,
     /// RELATION, CODED
     ///
     /// C  an..3
+    #[edifact(element = "5479", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "relation_coded", alias = "_050")
+    )]
     pub _050: Option<String>,
 }
 
@@ -224,54 +456,127 @@ pub struct LOC {
 /// To specify the name/address and their related
 /// function, either by CO82 only and/or unstructured by
 /// CO58 or structured by CO80 thru 3207.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct NAD {
-    pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_qualifier", alias = "_010")
+    )]
+    pub _010: _3035,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_identification_details", alias = "_020")
+    )]
     pub _020: Option<C082>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "name_and_address", alias = "_030")
+    )]
     pub _030: Option<C058>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "party_name", alias = "_040")
+    )]
     pub _040: Option<C080>,
+    #[cfg_attr(feature = "semantic-names", serde(rename = "street", alias = "_050"))]
     pub _050: Option<C059>,
+    #[edifact(element = "3164", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "city_name", alias = "_060")
+    )]
     pub _060: Option<String>,
+    #[edifact(element = "3229", repr = "an..9")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "country_sub_entity_identification", alias = "_070")
+    )]
     pub _070: Option<String>,
+    #[edifact(element = "3251", repr = "an..9")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "postcode_identification", alias = "_080")
+    )]
     pub _080: Option<String>,
+    #[edifact(element = "3207", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "country_coded", alias = "_090")
+    )]
     pub _090: Option<String>,
 }
 
 /// RFF - REFERENCE
 ///
 /// To specify a reference.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct RFF {
     // REFERENCE
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "reference", alias = "_010")
+    )]
     pub _010: C506,
 }
 
 /// RNG - RANGE DETAILS
 ///
 /// To identify a range.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct RNG {
     /// RANGE TYPE QUALIFIER
     ///
     /// M  an..3
+    #[edifact(element = "6167", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "range_type_qualifier", alias = "_010")
+    )]
     pub _010: String,
     /// RANGE
+    #[cfg_attr(feature = "semantic-names", serde(rename = "range", alias = "_020"))]
     pub _020: Option<C280>,
 }
 /// SEL - SEAL NUMBER
 ///
 /// To specify a seal number related to equipment.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct SEL {
     /// SEAL NUMBER
     ///
     /// M  an..10
+    #[edifact(element = "9308", repr = "an..10")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "seal_number", alias = "_010")
+    )]
     pub _010: String,
     /// SEAL ISSUER
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "seal_issuer", alias = "_020")
+    )]
     pub _020: Option<C215>,
     /// SEAL CONDITION, CODED
     ///
     /// C  an..3
+    #[edifact(element = "4517", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "seal_condition_coded", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
@@ -282,16 +587,55 @@ pub struct SEL {
 /// reference number and the identification of the means
 /// of transport.
 /// The segment may be pointed to by the TPL segment.
-#[derive(Default, Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Default, Debug, Serialize, Deserialize, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct TDT {
-    pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_stage_qualifier", alias = "_010")
+    )]
+    pub _010: _8051,
+    #[edifact(element = "8028", repr = "an..17")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "conveyance_reference_number", alias = "_020")
+    )]
     pub _020: Option<String>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "mode_of_transport", alias = "_030")
+    )]
     pub _030: Option<C220>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_means", alias = "_040")
+    )]
     pub _040: Option<C228>,
+    #[cfg_attr(feature = "semantic-names", serde(rename = "carrier", alias = "_050"))]
     pub _050: Option<C040>,
+    #[edifact(element = "8101", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transit_direction_coded", alias = "_060")
+    )]
     pub _060: Option<String>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "excess_transportation_information", alias = "_070")
+    )]
     pub _070: Option<C401>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_identification", alias = "_080")
+    )]
     pub _080: Option<C222>,
+    #[edifact(element = "8281", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "transport_ownership_coded", alias = "_090")
+    )]
     pub _090: Option<String>,
 }
 
@@ -299,30 +643,59 @@ pub struct TDT {
 ///
 /// To specify transport movement details for a goods item
 /// or equipment.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct TMD {
     /// MOVEMENT TYPE
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "movement_type", alias = "_010")
+    )]
     pub _010: Option<C219>,
     /// EQUIPMENT PLAN
     ///
     /// C  an..26
+    #[edifact(element = "8332", repr = "an..26")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "equipment_plan", alias = "_020")
+    )]
     pub _020: Option<String>,
     /// HAULAGE ARRANGEMENTS, CODED
     ///
     /// C  an..3
+    #[edifact(element = "8341", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "haulage_arrangements_coded", alias = "_030")
+    )]
     pub _030: Option<String>,
 }
 
 /// TMP - TEMPERATURE
 ///
 /// To specify the temperature setting.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct TMP {
     /// TEMPERATURE QUALIFIER
     ///
     /// M  an..3
+    #[edifact(element = "6245", repr = "an..3")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "temperature_qualifier", alias = "_010")
+    )]
     pub _010: String,
     /// TEMPERATURE SETTING
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "temperature_setting", alias = "_020")
+    )]
     pub _020: Option<C239>,
 }
 
@@ -349,7 +722,8 @@ pub struct TMP {
 /// an1 | M | RELEASE INDICATOR | If not used, insert space character
 /// an1 | M | Reserved for future use | Insert space character
 /// an1 | M | SEGMENT TERMINATOR |
-#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayOuterSegment)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Validate)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct UNA {
     /// an1    M     COMPONENT DATA ELEMENT SEPARATOR
     pub component_data_element_seperator: char,
@@ -365,32 +739,56 @@ pub struct UNA {
     pub segment_terminator: char,
 }
 
-impl<'a> Parser<&'a str, UNA, nom::error::Error<&'a str>> for UNA {
+impl<'a> Parser<&'a str, UNA, crate::util::Error<&'a str>> for UNA {
     fn parse(input: &'a str) -> IResult<&'a str, UNA> {
-        let (rest, vars) = take_until("UNB")(input)?;
-        if vars.is_empty() {
-            return Err(nom::Err::Error(nom::error::Error::new(
-                rest,
-                nom::error::ErrorKind::TakeUntil,
-            )));
+        let (rest, delimiters) = parse_una(input)?;
+        Ok((rest, UNA::from(delimiters)))
+    }
+}
+
+impl Default for UNA {
+    fn default() -> Self {
+        UNA::from(Delimiters::default())
+    }
+}
+
+impl fmt::Display for UNA {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "UNA{}{}{}{}{}{}",
+            self.component_data_element_seperator,
+            self.data_element_seperator,
+            self.decimal_notation,
+            self.release_indicator,
+            self.reserved_for_future_use,
+            self.segment_terminator
+        )
+    }
+}
+
+impl From<Delimiters> for UNA {
+    fn from(delimiters: Delimiters) -> Self {
+        UNA {
+            component_data_element_seperator: delimiters.component,
+            data_element_seperator: delimiters.element,
+            decimal_notation: delimiters.decimal,
+            release_indicator: delimiters.release.unwrap_or(' '),
+            reserved_for_future_use: ' ',
+            segment_terminator: delimiters.segment,
         }
-        // look for trailing newline
-        let vars = not_line_ending(vars)?.1;
-        if vars.len() != 9 {
-            println!("UNA Segment found, but malformed:\n{vars:?}");
-            panic!("UNA Segment malformed, needs to be exactly 6 characters")
+    }
+}
+
+impl From<&UNA> for Delimiters {
+    fn from(una: &UNA) -> Self {
+        Delimiters {
+            component: una.component_data_element_seperator,
+            element: una.data_element_seperator,
+            decimal: una.decimal_notation,
+            release: Some(una.release_indicator).filter(|c| *c != ' '),
+            segment: una.segment_terminator,
         }
-        let vars = vars.strip_prefix("UNA").unwrap();
-        let mut vars = vars.chars();
-        let una = UNA {
-            component_data_element_seperator: vars.next().unwrap(),
-            data_element_seperator: vars.next().unwrap(),
-            decimal_notation: vars.next().unwrap(),
-            release_indicator: vars.next().unwrap(),
-            reserved_for_future_use: vars.next().unwrap(),
-            segment_terminator: vars.next().unwrap(),
-        };
-        Ok((rest, una))
     }
 }
 
@@ -398,57 +796,275 @@ impl<'a> Parser<&'a str, UNA, nom::error::Error<&'a str>> for UNA {
 ///
 /// To start, identify and specify an interchange.
 #[derive(
-    Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default, DisplayOuterSegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Default,
+    DisplayOuterSegment,
+    ParseSegment,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct UNB {
-    _010: S001,
-    _020: S002,
-    _030: S003,
-    _040: S004,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "syntax_identifier", alias = "_010")
+    )]
+    pub _010: S001,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "interchange_sender", alias = "_020")
+    )]
+    pub _020: S002,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "interchange_recipient", alias = "_030")
+    )]
+    pub _030: S003,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_of_preparation", alias = "_040")
+    )]
+    pub _040: S004,
     /// Interchange control reference
     ///
     /// Unique reference assigned by the sender to an interchange.
     /// Shall be identical in UNB and UNZ.
-    _050: String,
-    _060: Option<S005>,
-    _070: Option<String>,
-    _080: Option<_0029>,
-    _090: Option<_0031>,
-    _100: Option<String>,
-    _110: Option<_0035>,
+    /// M   an..14
+    #[edifact(element = "0020", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "interchange_control_reference", alias = "_050")
+    )]
+    pub _050: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "recipients_reference_password", alias = "_060")
+    )]
+    pub _060: Option<S005>,
+    /// Application reference
+    ///
+    /// Identification of the application area assigned by the sender, to which the messages in the interchange relate.
+    /// C   an..14
+    #[edifact(element = "0026", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "application_reference", alias = "_070")
+    )]
+    pub _070: Option<String>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "processing_priority_code", alias = "_080")
+    )]
+    pub _080: Option<_0029>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "acknowledgement_request", alias = "_090")
+    )]
+    pub _090: Option<_0031>,
+    /// Interchange agreement identifier
+    ///
+    /// Identification by name or code of the type of agreement under which the interchange takes place.
+    /// C   an..35
+    #[edifact(element = "0032", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "interchange_agreement_identifier", alias = "_100")
+    )]
+    pub _100: Option<String>,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "test_indicator", alias = "_110")
+    )]
+    pub _110: Option<_0035>,
+}
+
+/// UNE Functional group trailer
+///
+/// To end and check the completeness of a functional group.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    PartialEq,
+    Eq,
+    DisplayOuterSegment,
+    ParseSegment,
+    Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct UNE {
+    /// Number of messages
+    ///
+    /// Count of the number of messages in a functional group.
+    /// M   n..6
+    #[edifact(element = "0060", repr = "n..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_messages", alias = "_010")
+    )]
+    pub _010: String,
+    /// Functional group reference number
+    ///
+    /// Reference number for the functional group assigned by and unique within the sender's division, department etc.
+    /// Shall be identical in UNG and UNE.
+    #[edifact(element = "0048", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "functional_group_reference_number", alias = "_020")
+    )]
+    pub _020: String,
+}
+
+/// UNG Functional group header
+///
+/// To head, identify and specify a functional group.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    PartialEq,
+    Eq,
+    DisplayOuterSegment,
+    ParseSegment,
+    Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct UNG {
+    /// Functional group identification
+    ///
+    /// Identification of the one type of messages in a functional group.
+    /// M   an..6
+    #[edifact(element = "0038", repr = "an..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "functional_group_identification", alias = "_010")
+    )]
+    pub _010: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "application_sender_identification", alias = "_020")
+    )]
+    pub _020: S006,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "application_recipient_identification", alias = "_030")
+    )]
+    pub _030: S007,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "date_time_of_preparation", alias = "_040")
+    )]
+    pub _040: S004,
+    /// Functional group reference number
+    ///
+    /// Reference number for the functional group assigned by and unique within the sender's division, department etc.
+    /// Shall be identical in UNG and UNE.
+    #[edifact(element = "0048", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "functional_group_reference_number", alias = "_050")
+    )]
+    pub _050: String,
+    /// Controlling agency
+    ///
+    /// Code to identify the agency controlling the specification, maintenance and publication of the message type.
+    /// M   an..2
+    #[edifact(element = "0051", repr = "an..2")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "controlling_agency", alias = "_060")
+    )]
+    pub _060: String,
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_version", alias = "_070")
+    )]
+    pub _070: Option<S008>,
+    /// Application password
+    ///
+    /// Password to the recipient's division, department or sectional system (if required).
+    /// C   an..14
+    #[edifact(element = "0058", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "application_password", alias = "_080")
+    )]
+    pub _080: Option<String>,
 }
 
 /// UNH - MESSAGE HEADER
 ///
 /// To head, identify and specify a message.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct UNH {
     /// MESSAGE REFERENCE NUMBER
     ///
     /// M  an..14
+    #[edifact(element = "0062", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_reference_number", alias = "_010")
+    )]
     pub _010: String,
     /// MESSAGE IDENTIFIER
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_identifier", alias = "_020")
+    )]
     pub _020: Option<S009>,
     /// COMMON ACCESS REFERENCE
     ///
     /// C  an..35
+    #[edifact(element = "0068", repr = "an..35")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "common_access_reference", alias = "_030")
+    )]
     pub _030: Option<String>,
     /// STATUS OF THE TRANSFER
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "status_of_the_transfer", alias = "_040")
+    )]
     pub _040: Option<S010>,
 }
 
 /// UNT - MESSAGE TRAILER
 ///
 /// To end and check the completeness of a message.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment)]
+#[derive(
+    Debug, Serialize, Deserialize, Default, Clone, DisplayOuterSegment, ParseSegment, Validate,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct UNT {
     /// NUMBER OF SEGMENTS IN THE MESSAGE
     ///
     /// M  n..6
+    #[edifact(element = "0074", repr = "n..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "number_of_segments_in_the_message", alias = "_010")
+    )]
     pub _010: String,
     /// MESSAGE REFERENCE NUMBER
     ///
     /// M  an..14
+    #[edifact(element = "0062", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "message_reference_number", alias = "_020")
+    )]
     pub _020: String,
 }
 
@@ -456,16 +1072,36 @@ pub struct UNT {
 ///
 /// To end and check the completeness of an interchange.
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayOuterSegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    PartialEq,
+    Eq,
+    DisplayOuterSegment,
+    ParseSegment,
+    Validate,
 )]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct UNZ {
     /// Interchange control count
     ///
     /// The count either of the number of messages or, if used, of the number of functional groups in an interchange. One of these counts shall appear.
-    _010: String,
+    #[edifact(element = "0036", repr = "n..6")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "interchange_control_count", alias = "_010")
+    )]
+    pub _010: String,
     /// Interchange control reference
     ///
     /// Unique reference assigned by the sender to an interchange.
     /// Shall be identical in UNB and UNZ.
-    _020: String,
+    #[edifact(element = "0020", repr = "an..14")]
+    #[cfg_attr(
+        feature = "semantic-names",
+        serde(rename = "interchange_control_reference", alias = "_020")
+    )]
+    pub _020: String,
 }
@@ -11,3 +11,76 @@ fn test_bgm() {
     println!("{obj}");
     assert_eq!(format!("{obj}"), r#"NAD+CA+HAM:160:20"#);
 }
+
+#[cfg(feature = "chrono")]
+#[test]
+fn dtm_datetime() {
+    use crate::datetime::DateTimeValue;
+    let (_, obj) = DTM::parse("DTM+133:199808041200:203'").unwrap();
+    let DateTimeValue::DateTime(datetime) = obj.value().unwrap() else {
+        panic!("expected a datetime");
+    };
+    let dtm = DTM::new("132", datetime.date(), "102").unwrap();
+    assert_eq!(dtm.to_string(), "DTM+132:19980804:102");
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn numeric_values() {
+    use rust_decimal::Decimal;
+    let (_, mut tmp) = TMP::parse("TMP+2+-18:CEL'").unwrap();
+    assert_eq!(tmp.temperature(), Ok(Some(Decimal::new(-18, 0))));
+    tmp.set_temperature(Decimal::new(5, 0)).unwrap();
+    assert_eq!(tmp.to_string(), "TMP+2+005:CEL");
+    assert!(tmp.set_temperature(Decimal::new(1234, 0)).is_err());
+}
+
+#[test]
+fn code_list_metadata() {
+    use crate::codes::CodeList;
+
+    assert_eq!(_2379::list_id(), "2379");
+    assert_eq!(_2379::_203.code(), "203");
+    assert_eq!(_2379::_4.code(), "4");
+    assert_eq!(_2379::_102.name(), "CCYYMMDD");
+    assert_eq!(
+        _2379::_102.description(),
+        "Calendar date: C = Century ; Y = Year ; M = Month ; D = Day."
+    );
+    assert_eq!(
+        _0002::all().iter().map(|v| v.code()).collect::<Vec<_>>(),
+        ["1", "2", "3"]
+    );
+    assert_eq!(_1225::_1.name(), "Cancellation");
+    assert_eq!(_1225::_6.name(), "");
+}
+
+#[test]
+fn code_list_2379() {
+    use std::str::FromStr;
+
+    assert_eq!(_2379::from_str("203"), Ok(_2379::_203));
+    assert_eq!(_2379::_718.to_string(), "718");
+    #[cfg(not(feature = "lenient-codes"))]
+    assert!(_2379::from_str("999").is_err());
+    #[cfg(feature = "lenient-codes")]
+    assert_eq!(
+        _2379::from_str("999"),
+        Ok(_2379::Unknown("999".to_string()))
+    );
+}
+
+#[test]
+fn rff_qualifier() {
+    let (_, obj) = RFF::parse("RFF+BN:ABC123'").unwrap();
+    assert_eq!(obj._010._010, _1153::BN);
+    assert_eq!(obj.to_string(), "RFF+BN:ABC123");
+    #[cfg(not(feature = "lenient-codes"))]
+    assert!(RFF::parse("RFF+XXX:1'").is_err());
+    #[cfg(feature = "lenient-codes")]
+    {
+        let (_, obj) = RFF::parse("RFF+XXX:1'").unwrap();
+        assert_eq!(obj._010._010, _1153::Unknown("XXX".to_string()));
+        assert_eq!(obj.to_string(), "RFF+XXX:1");
+    }
+}
@@ -1,10 +1,14 @@
+use edifact_types_macros::CodeList;
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 
 /// Syntax identifier
 ///
 /// Coded identification of the agency controlling a syntax and syntax level used in an interchange.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default)]
+#[derive(
+    Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default, CodeList,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _0001 {
     /// UN/ECE level A
     /// As defined in the basic code table of ISO 646 with the exceptions of lower case letters, alternative graphic character allocations and national or application-oriented graphic character allocations.
@@ -25,12 +29,25 @@ pub enum _0001 {
     /// UN/ECE level F
     /// As defined in ISO 8859-7 : Information processing - Part 7: Latin/Greek alphabet.
     UNOF,
+    /// UN/ECE level W
+    /// ISO 10646-1 multiple octet with code extension technique to support UTF-8 (UCS Transformation Format, 8 bit) encoding.
+    UNOW,
+    /// UN/ECE level Y
+    /// ISO 10646-1 octet without code extension technique.
+    UNOY,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Syntax version number
 ///
 /// Version number of the syntax identified in the syntax identifier (0001)
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default)]
+#[derive(
+    Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default, CodeList,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _0002 {
     /// Version 1
     /// ISO 9735:1988.
@@ -45,12 +62,17 @@ pub enum _0002 {
     #[default]
     #[strum(serialize = "3")]
     _3,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Partner identification code qualifier
 ///
 /// Qualifier referring to the source of codes for the identifiers of interchanging partners.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _0007 {
     /// DUNS (Dun & Bradstreet)
     ///
@@ -217,12 +239,17 @@ pub enum _0007 {
     /// Self explanatory.
     #[strum(serialize = "ZZZ")]
     ZZZ,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Recipient's reference/password qualifier
 ///
 /// Qualifier for the recipient's reference or password.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _0025 {
     /// Reference
     /// Self explanatory.
@@ -230,42 +257,62 @@ pub enum _0025 {
     /// Password
     /// Self explanatory.
     BB,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Processing priority code
 ///
 /// Code determined by the sender requesting processing priority for the interchange.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _0029 {
     /// Highest priority
     /// Self explanatory.
     A,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Acknowledgement request
 ///
 /// Code determined by the sender for acknowledgement of the interchange.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _0031 {
     /// Requested
     /// Acknowledgement is requested.
     #[strum(serialize = "1")]
     _1,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// Test indicator
 ///
 /// Indication that the interchange is a test.
-#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _0035 {
     /// Interchange is a test
     /// Self explanatory.
     #[strum(serialize = "1")]
     _1,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// 1225  Message function code
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, EnumString, Display)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, EnumString, Display, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[strum(serialize_all = "camelCase")]
 pub enum _1225 {
     /// Cancellation
@@ -386,99 +433,230 @@ pub enum _1225 {
     /// test.
     #[serde(rename = "53")]
     _53,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// 1153  Reference qualifier
+#[derive(
+    Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default, CodeList,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum _1153 {
+    /// Order acknowledgement number
+    AAA,
+    /// Proforma invoice number
+    AAB,
+    /// Documentary credit number
+    AAC,
+    /// Contract document addendum number
+    AAD,
+    /// Goods declaration number
+    AAE,
+    /// Offer number
+    AAG,
+    /// Delivery order number
+    AAJ,
+    /// Despatch advice number
+    AAK,
+    /// Drawing number
+    AAL,
+    /// Waybill number
+    AAM,
+    /// Delivery schedule number
+    AAN,
+    /// Consignment identifier, consignee assigned
+    AAO,
+    /// Partial shipment identifier
+    AAP,
+    /// Transport equipment identifier
+    AAQ,
+    AAS,
+    /// Master label number
+    AAT,
+    /// Despatch note number
+    AAU,
+    /// Enquiry number
+    AAV,
+    /// Carrier's agent reference number
+    AAY,
+    /// Standard Carrier Alpha Code (SCAC) number
+    AAZ,
+    /// Declarant's reference number
+    ABE,
+    /// Originator's reference
+    ABO,
+    /// Additional reference number
+    ACD,
+    /// Related document number
+    ACE,
+    ADE,
+    /// Accounts receivable number
+    AP,
+    AQW,
+    /// Bill of lading number
+    BM,
+    /// Consignment identifier, carrier assigned
+    BN,
+    /// Blanket order number
+    BO,
+    /// Batch number/lot number
+    BT,
+    /// Credit note number
+    CD,
+    CMR,
+    /// Carrier's reference number
+    CN,
+    /// Buyers order number
+    CO,
+    /// Customer reference number
+    CR,
+    /// Contract number
+    CT,
+    /// Consignor's reference number
+    CU,
+    /// Container operators reference number
+    CV,
+    /// Package number
+    CW,
+    /// Debit note number
+    DL,
+    /// Document number
+    DM,
+    /// Delivery note number
+    DQ,
+    /// Equipment number
+    EQ,
+    /// Consignment identifier, freight forwarder assigned
+    FF,
+    /// House waybill number
+    HWB,
+    /// Invoice number
+    IV,
+    /// Master bill of lading number
+    MB,
+    /// Order number (buyer)
+    ON,
+    /// Packing list number
+    PK,
+    /// Sellers reference number
+    SS,
+    /// Order number (supplier)
+    VN,
+    /// Voyage number
+    VON,
+    /// Mutually defined reference number
+    #[default]
+    ZZZ,
+    #[strum(serialize = "4F")]
+    _4F,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
 /// 2379  Date or time or period format code
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, EnumString, Display, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum _2379 {
     /// DDMMYY
     ///
     /// Calendar date: D = Day; M = Month; Y = Year.
-    #[serde(rename = "2")]
+    #[strum(serialize = "2")]
     _2,
     /// MMDDYY
     ///
     /// Calendar date: M = Month; D = Day; Y = Year.
-    #[serde(rename = "3")]
+    #[strum(serialize = "3")]
     _3,
     /// DDMMCCYY
     ///
     /// Calendar date C=Century; Y=Year; M=Month; D=Day.
+    #[strum(serialize = "4")]
     _4,
     /// DDMMCCYYHHMM
     ///
     ///  Calendar date and time: C=Century; Y=Year; M=Month;
     /// D=Day; H=Hour; M=Minute.
+    #[strum(serialize = "5")]
     _5,
     /// YYMMDD
     ///
     /// Calendar date: Y = Year; M = Month; D = Day.
+    #[strum(serialize = "101")]
     _101,
     /// CCYYMMDD
     ///
     /// Calendar date: C = Century ; Y = Year ; M = Month ; D =
     /// Day.
+    #[strum(serialize = "102")]
     _102,
     /// YYWWD
     ///
     /// Calendar week day: Y = Year ; W = Week ; D = Day Week
     /// number 01 is always first week of January Day number 1
     /// is always Monday.
+    #[strum(serialize = "103")]
     _103,
     /// YYDDD
     ///
     /// Calendar day: Y = Year ; D = Day January the first = Day
     /// 001 Always start numbering the days of the year from
     /// January 1st through December 31st.
+    #[strum(serialize = "105")]
     _105,
     /// MMDD
     ///
     /// Day of a month: M = Month; D = Day.
-    #[serde(rename = "106")]
+    #[strum(serialize = "106")]
     _106,
     /// DDD
     ///
     /// Day's number within a specific year: D = Day.
-    #[serde(rename = "107")]
+    #[strum(serialize = "107")]
     _107,
     /// WW
     ///
     /// Week's number within a specific year: W = Week.
-    #[serde(rename = "108")]
+    #[strum(serialize = "108")]
     _108,
     /// MM
     ///
     /// Month's number within a specific year: M = Month.
-    #[serde(rename = "109")]
+    #[strum(serialize = "109")]
     _109,
     /// DD
     ///
     /// Day's number within is a specific month: D = Day.
-    #[serde(rename = "110")]
+    #[strum(serialize = "110")]
     _110,
     /// YYMMDDHHMM
     ///
     /// Calendar date including time without seconds: Y = Year;
     /// M = Month; D = Day; H = Hour; M = Minute.
-    #[serde(rename = "201")]
+    #[strum(serialize = "201")]
     _201,
     /// YYMMDDHHMMSS
     ///
     /// Calendar date including time with seconds: Y = Year; M =
     /// Month; D = Day; H = Hour; m = Minutes = Seconds.
-    #[serde(rename = "202")]
+    #[strum(serialize = "202")]
     _202,
     /// CCYYMMDDHHMM
     ///
     /// Calendar date including time with minutes: C=Century;
     /// Y=Year; M=Month; D=Day; H=Hour; M=Minutes.
-    #[serde(rename = "203")]
+    #[strum(serialize = "203")]
     _203,
     /// CCYYMMDDHHMMSS
     ///
     /// Calendar date including time with seconds:
     /// C=Century;Y=Year;
     /// M=Month;D=Day;H=Hour;M=Minute;S=Second.
+    #[strum(serialize = "204")]
     _204,
     /// CCYYMMDDHHMMZHHMM
     ///
@@ -486,293 +664,4200 @@ pub enum _2379 {
     /// hours and minutes.
     /// ZHHMM = time zone given as offset from Coordinated
     /// Universal Time (UTC).
+    #[strum(serialize = "205")]
     _205,
 
     /// YYMMDDHHMMZZZ
     ///
     /// See 201 + Z = Time zone.
+    #[strum(serialize = "301")]
     _301,
     /// YYMMDDHHMMSSZZZ
     ///
     /// See 202 + Z = Time zone.
+    #[strum(serialize = "302")]
     _302,
     /// CCYYMMDDHHMMZZZ
     ///
     /// See 203 plus Z=Time zone.
+    #[strum(serialize = "303")]
     _303,
 
     /// CCYYMMDDHHMMSSZZZ
     ///
     /// See 204 plus Z=Time zone.
+    #[strum(serialize = "304")]
     _304,
     /// MMDDHHMM
     ///
     /// Month, day, hours, minutes; M = Month; D = Day; H =
     /// Hour; M = Minute.
+    #[strum(serialize = "305")]
     _305,
     /// DDHHMM
     ///
     /// Day, hours, minutes; D = Day; H = Hour; M = Minute.
+    #[strum(serialize = "306")]
     _306,
     /// HHMM
     ///
     /// Time without seconds: H = Hour; m = Minute.
+    #[strum(serialize = "401")]
     _401,
     /// HHMMSS
     ///
     /// Time with seconds: H = Hour; m = Minute; s = Seconds.
+    #[strum(serialize = "402")]
     _402,
     /// HHMMSSZZZ
     ///
     /// See 402 plus Z=Time zone.
+    #[strum(serialize = "404")]
     _404,
     /// MMMMSS
     ///
     /// Time without hours: m=minutes, s=seconds.
+    #[strum(serialize = "405")]
     _405,
     /// ZHHMM
     ///
     /// Offset from Coordinated Universal Time (UTC) where Z is
     /// plus (+) or minus (-).
+    #[strum(serialize = "406")]
     _406,
     /// HHMMHHMM
     ///
     /// Time span without seconds: H = Hour; m = Minute;.
+    #[strum(serialize = "501")]
     _501,
     /// HHMMSS-HHMMSS
     ///
     /// Format of period to be given without hyphen.
+    #[strum(serialize = "502")]
     _502,
     /// HHMMSSZZZ-HHMMSSZZZ
     ///
     /// Format of period to be given without hyphen.
+    #[strum(serialize = "503")]
     _503,
     ///   CC
     ///              Century.
+    #[strum(serialize = "600")]
     _600,
     ///   YY
     ///              Calendar year: Y = Year.
+    #[strum(serialize = "601")]
     _601,
     ///   CCYY
     ///              Calendar year including century: C = Century; Y = Year.
+    #[strum(serialize = "602")]
     _602,
     ///   YYS
     ///              Semester in a calendar year: Y = Year; S = Semester.
+    #[strum(serialize = "603")]
     _603,
     /// CCYYS
     ///
     /// Semester in a calendar year: C = Century; Y = Year; S =
     /// Semester.
+    #[strum(serialize = "604")]
     _604,
     /// CCYYQ
     ///
     /// Quarter in a calendar year: C = Century; Y = Year; Q =
     /// Quarter.
+    #[strum(serialize = "608")]
     _608,
     /// YYMM
     ///
     /// Month within a calendar year: Y = Year; M = Month.
+    #[strum(serialize = "609")]
     _609,
     /// CCYYMM
     ///
     /// Month within a calendar year: CC = Century; Y = Year; M
     /// = Month.
+    #[strum(serialize = "610")]
     _610,
 
     /// YYMMA
     ///
     /// Format of period to be given without hyphen (A = ten
     ///     days period).
+    #[strum(serialize = "613")]
     _613,
 
     /// CCYYMMA
     ///
     /// Format of period to be given without hyphen (A = ten
     ///       days period).
+    #[strum(serialize = "614")]
     _614,
 
     /// YYWW
     /// Week within a calendar year: Y = Year; W = Week 1st week
     ///       of January = week 01.
+    #[strum(serialize = "615")]
     _615,
     /// CCYYWW
     ///
     /// Week within a calendar year: CC = Century; Y = Year; W =
     /// Week (1st week of January = week 01).
+    #[strum(serialize = "616")]
     _616,
     /// YY-YY
     ///
     /// Format of period to be given in actual message without
     /// hyphen.
+    #[strum(serialize = "701")]
     _701,
     /// CCYY-CCYY
     ///
     /// Format of period to be given in actual message without
     /// hyphen.
+    #[strum(serialize = "702")]
     _702,
     /// YYS-YYS
     ///
     /// Format of period to be given without hyphen.
+    #[strum(serialize = "703")]
     _703,
     /// CCYYS-CCYYS
     ///
     /// Format of period to be given in actual message without
     /// hyphen.
+    #[strum(serialize = "704")]
     _704,
     /// YYPYYP
     ///
     /// Format of period to be given without hyphen (P = period
     /// of 4 months).
+    #[strum(serialize = "705")]
     _705,
     /// CCYYP-CCYYP
     ///
     /// Format of period to be given without hyphen (P = period
     /// of 4 months).
+    #[strum(serialize = "706")]
     _706,
     /// YYQ-YYQ
     ///
     /// Format of period to be given without hyphen.
+    #[strum(serialize = "707")]
     _707,
     /// CCYYQ-CCYYQ
     ///
     /// Format of period to be given in actual message without
     /// hyphen.
+    #[strum(serialize = "708")]
     _708,
     /// YYMM-YYMM
     ///
     /// Format of period to be given in actual message without
     /// hyphen.
+    #[strum(serialize = "709")]
     _709,
     /// CCYYMM-CCYYMM
     ///
     /// Format of period to be given in actual message without
     /// hyphen.
-    #[serde(rename = "710")]
+    #[strum(serialize = "710")]
     _710,
     ///   CCYYMMDD-CCYYMMDD
     ///
     /// Format of period to be given in actual message without
     /// hyphen.
+    #[strum(serialize = "711")]
     _711,
     /// YYMMDDHHMM-YYMMDDHHMM
     ///
     /// Format of period to be given in actual message without
     /// hyphen.
+    #[strum(serialize = "713")]
     _713,
     /// YYWW-YYWW
     ///
     /// Format of period to be given in actual message without
     /// hyphen.
+    #[strum(serialize = "715")]
     _715,
     /// CCYYWW-CCYYWW
     ///
     /// Format of period to be given without hyphen.
+    #[strum(serialize = "716")]
     _716,
     /// YYMMDD-YYMMDD
     ///
     /// Format of period to be given in actual message without
     /// hyphen.
+    #[strum(serialize = "717")]
     _717,
     /// CCYYMMDD-CCYYMMDD
     ///
     /// Format of period to be given without hyphen.
+    #[strum(serialize = "718")]
     _718,
     /// CCYYMMDDHHMM-CCYYMMDDHHMM
     ///
     /// A period of time which includes the century, year,
     /// month, day, hour and minute. Format of period to be
     /// given in actual message without hyphen.
+    #[strum(serialize = "719")]
     _719,
     /// DHHMM-DHHMM
     ///
     /// Format of period to be given without hyphen (D=day of
     /// the week, 1=Monday; 2=Tuesday; ... 7=Sunday).
+    #[strum(serialize = "720")]
     _720,
     /// Year
     ///
     /// To indicate a quantity of years.
+    #[strum(serialize = "901")]
     _901,
     /// Month
     ///
     /// To indicate a quantity of months.
+    #[strum(serialize = "902")]
     _902,
     /// Week
     ///
     /// To indicate a quantity of weeks.
+    #[strum(serialize = "903")]
     _903,
     /// Day
     ///
     /// To indicate a quantity of days.
+    #[strum(serialize = "904")]
     _904,
     /// Hour
     ///
     /// To indicate a quantity of hours.
+    #[strum(serialize = "905")]
     _905,
     /// Minute
     ///
     /// To indicate a quantity of minutes.
+    #[strum(serialize = "906")]
     _906,
     /// Second
     ///
     /// To indicate a quantity of seconds.
-    #[serde(rename = "807")]
+    #[strum(serialize = "807")]
     _807,
     /// Semester
     ///
     /// To indicate a quantity of semesters (six months).
+    #[strum(serialize = "808")]
     _808,
     /// Four months period
     ///
     /// To indicate a quantity of four months periods.
-    #[serde(rename = "809")]
+    #[strum(serialize = "809")]
     _809,
     /// Trimester
     ///
     /// To indicate a quantity of trimesters (three months).
+    #[strum(serialize = "810")]
     _810,
     /// Half month
     ///
     /// To indicate a quantity of half months.
-    #[serde(rename = "811")]
+    #[strum(serialize = "811")]
     _811,
     /// Ten days
     ///
     /// To indicate a quantity of ten days periods.
-    #[serde(rename = "812")]
+    #[strum(serialize = "812")]
     _812,
     /// Day of the week
     ///
     /// Numeric representation of the day (Monday = 1).
-    #[serde(rename = "813")]
+    #[strum(serialize = "813")]
     _813,
     /// Working days
     ///
     /// Number of working days.
-    #[serde(rename = "814")]
+    #[strum(serialize = "814")]
     _814,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
 
-/// 4343  Response type code
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, EnumString, Display)]
-pub enum _4343 {
+/// 3035  Party qualifier
+#[derive(
+    Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default, CodeList,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum _3035 {
+    ///Party to be billed (AAR Accounting rule 11)
+    ///
+    ///Party to be billed in accordance with AAR Accounting rule 11.
     AA,
+    ///Buyer's agent/representative
+    ///
+    ///Third party who arranged the purchase of merchandise on behalf of the actual buyer.
     AB,
-    AC,
-    AD,
+    ///Declarant's agent/representative
+    ///
+    ///Any natural or legal person who makes a declaration to an official body on behalf of another natural or legal person, where legally permitted (CCC).
     AE,
+    ///Transit principal
+    ///
+    ///Natural or legal person responsible for the satisfactory performance of a Customs transit operation. Source: CCC.
     AF,
+    ///Agent/representative
+    ///
+    ///(3196) Party authorized to act on behalf of another party.
     AG,
+    ///Transit principal's agent/representative
+    ///
+    ///Agent acting on behalf of the transit principal (CCC).
     AH,
+    ///Successful job applicant
+    ///
+    ///Person who has been chosen for a job.
     AI,
+    ///Party issuing mutually agreed codes
+    ///
+    ///The party which has issued all mutually agreed codes used in the message.
     AJ,
+    ///Acknowledgement recipient
+    ///
+    ///Party to whom acknowledgement should be sent.
+    AK,
+    ///Principal
+    ///
+    ///(3340) Party accepting liability for goods held or moving (e.g. transit) under a Customs authorization and - when applicable - a guarantee.
+    AL,
+    ///Authorized official
+    ///
+    ///Employee of a company or firm authorized to act on behalf of that company or firm e.g. to make a Customs declaration.
+    AM,
+    ///Approved importer
+    ///
+    ///Person or company which is authorised by the relevant Customs authority to import goods without payment all taxes or specific taxes at the point of entry into the country.
+    AN,
+    ///Account of
+    ///
+    ///Party account is assigned to.
+    AO,
+    ///Accepting party
+    ///
+    ///(3352) Party accepting goods, products, services etc.
     AP,
+    ///Approved consignor
+    ///
+    ///Person or company approved by the relevant authority in the country to pack and export specific goods under Customs supervision.
     AQ,
+    ///Authorized exporter
+    ///
+    ///Exporter authorized/approved by Customs for special Customs procedures e.g. simplified procedure.
     AR,
+    ///Account servicing financial institution
+    ///
+    ///Identifies the financial institution servicing the account(s).
     AS,
+    ///Authorized importer
+    ///
+    ///Importer authorized/approved by Customs for special Customs procedures e.g. simplified procedure.
+    AT,
+    ///Authorized trader (transit)
+    ///
+    ///Trader authorized/approved by Customs for special transit procedures e.g. simplified procedure.
+    AU,
+    ///Authorizing official
+    ///
+    ///Party that has delegated the authority to take a certain action on behalf of a company or agency.
+    AV,
+    ///Applicant's bank
+    ///
+    ///Financial institution which is requested to issue the documentary credit.
+    AW,
+    ///Authenticating party
+    ///
+    ///Party which certifies that a document is authentic.
+    AX,
+    ///Animal being investigated
+    ///
+    ///Animal being investigated.
+    AY,
+    ///Issuing bank
+    ///
+    ///Financial institution which issues the documentary credit, if the applicant's bank is not acting as the issuing bank.
+    AZ,
+    ///Contact bank 1
+    ///
+    ///Identifies an additional bank which must be informed of certain aspects of the message.
+    B1,
+    ///Contact bank 2
+    ///
+    ///Identifies an additional bank which must be informed of certain aspects of the message.
+    B2,
+    ///Booking agent
+    ///
+    ///Party acting as a booking office for transport and forwarding services.
+    BA,
+    ///Buyer's bank
+    ///
+    ///[3420] Bank employed by the buyer to make payment.
+    BB,
+    ///Negotiating bank
+    ///
+    ///Financial institution to whom a negotiable documentary credit is directed.
+    BC,
+    ///Documentary credit reimbursing bank
+    ///
+    ///Self-explanatory.
+    BD,
+    ///Beneficiary
+    ///
+    ///The ultimate recipient of the funds. Normally the account owner who is reimbursed by the payor.
+    BE,
+    ///Beneficiary's bank
+    ///
+    ///Identifies the account servicer for the beneficiary or the payee.
+    BF,
+    ///Employer
+    ///
+    ///Self-explanatory.
+    BG,
+    ///Previous employer
+    ///
+    ///Previous employer of a person(s).
+    BH,
+    ///Buyer's financial institution
+    ///
+    ///Financial institution designated by buyer to make payment.
+    BI,
+    ///Release to party
+    ///
+    ///Party to which the goods or container(s) is (are) to be released.
+    BJ,
+    ///Financial institution
+    ///
+    ///Party acting as financial institution.
+    BK,
+    ///Bill of lading recipient
+    ///
+    ///Party to receive B/L.
+    BL,
+    ///Insured
+    ///
+    ///Party which is the object of an insurance contract.
+    BM,
+    ///Insurance beneficiary
+    ///
+    ///Party which benefits from insurance coverage.
+    BN,
+    ///Broker or sales office
+    ///
+    ///Party acting in the name of the seller as broker or as sales office.
+    BO,
+    ///Building site purchaser
+    ///
+    ///Party at the building site responsible for the purchasing of goods and services for that particular site.
+    BP,
+    ///Cheque drawn bank
+    ///
+    ///Identifies the bank on which the cheque should be drawn, as instructed by the ordering customer.
+    BQ,
+    ///Bill and ship to
+    ///
+    ///Party receiving goods and relevant invoice.
+    BS,
+    ///Party to be billed for other than freight (bill to)
+    ///
+    ///Party receiving invoice excluding freight costs.
+    BT,
+    ///Service bureau
+    ///
+    ///Party carrying out service bureau processing work, (e.g. a payroll bureau).
+    BU,
+    ///Member
+    ///
+    ///Member of a group (e.g. of a group of persons or a service scheme).
+    BV,
+    /// Borrower
+    BW,
+    ///Building site engineer
+    ///
+    ///Party at the building site responsible for engineering matters for that particular site.
+    BX,
+    ///Buyer
+    ///
+    ///Party to whom merchandise and/or service is sold.
+    BY,
+    ///Building site forwarder
+    ///
+    ///Party at the building site responsible for forwarding the received goods on that particular site.
+    BZ,
+    ///In care of party no. 1
+    ///
+    ///Description to be provided.
+    C1,
+    ///In care of party no. 2
+    ///
+    ///Description to be provided.
+    C2,
+    ///Carrier
+    ///
+    ///(3126) Party undertaking or arranging transport of goods between named points.
     CA,
+    ///Customs broker
+    ///
+    ///Agent or representative or a professional Customs clearing agent who deals directly with Customs on behalf of the importer or exporter (CCC).
+    CB,
+    ///Claimant
+    ///
+    ///Party who claims goods or insurance.
+    CC,
+    ///Agent's bank
+    ///
+    ///Bank of the agent.
+    CD,
+    ///Ceding company
+    ///
+    ///Description to be provided.
+    CE,
+    ///Container operator/lessee
+    ///
+    ///Party to whom the possession of specified property (e.g. container) has been conveyed for a period of time in return for rental payments.
+    CF,
+    ///Carrier's agent
+    ///
+    ///Party authorized to act for or on behalf of carrier.
+    CG,
+    ///Connecting carrier
+    ///
+    ///Owner or operator of a transportation conveyance to which goods in a given transaction will be transferred.
+    CH,
+    ///Commission processor
+    ///
+    ///Party who provides extra treatment to goods on commission base.
+    CI,
+    ///Previous member
+    ///
+    ///Previous member of a group of persons or a service scheme.
+    CJ,
+    ///Empty equipment despatch party
+    ///
+    ///Party from whose premises empty equipment will be or has been despatched.
+    CK,
+    ///Container location party
+    ///
+    ///Party from whose premises container will be or has been despatched.
+    CL,
+    ///Customs
+    ///
+    ///Identification of customs authority relevant to the transaction or shipment.
+    CM,
+    ///Consignee
+    ///
+    ///(3132) Party to which goods are consigned.
+    CN,
+    ///Cash pool top account servicing financial institution
+    ///
+    ///Identification of a financial institution servicing the top account of a cash pool.
+    CNX,
+    ///Cash pool level account servicing financial institution
+    ///
+    ///Identification of a financial institution servicing the level account of a cash pool.
+    CNY,
+    ///Cash pool sub-account servicing financial institution
+    ///
+    ///Identification of a financial institution servicing the sub-account of a cash pool.
+    CNZ,
+    ///Corporate office
+    ///
+    ///Identification of the Head Office within a company.
     CO,
-    NA,
-    RE,
-    UR,
-    US,
+    ///Entity in which a financial interest is held
+    ///
+    ///Business in which a financial interest is held.
+    COA,
+    ///Intermediate level parent company
+    ///
+    ///Identifies an intermediate parent company.
+    COB,
+    ///Transshipment party
+    ///
+    ///A party responsible for transshipment.
+    COC,
+    ///Quotation requesting party
+    ///
+    ///Party sending a request for a quotation.
+    COD,
+    ///Party maintaining the codes used in the message
+    ///
+    ///The party which maintains the codes used in the message.
+    COE,
+    ///Party maintaining the identifiers used in the message
+    ///
+    ///The party which maintains the identifiers used in the message.
+    COF,
+    ///Dispatcher
+    ///
+    ///An individual responsible for sending something to a destination.
+    COG,
+    ///Submitter of sample
+    ///
+    ///An entity responsible for the submission of a sample.
+    COH,
+    ///Institutional provider
+    ///
+    ///The institution providing the service.
+    COI,
+    ///Primary health care provider
+    ///
+    ///Health care provider that has primary responsibility for patient.
+    COJ,
+    ///Assistant surgeon
+    ///
+    ///Physician assisting in surgery.
+    COK,
+    ///Admitting health care provider
+    ///
+    ///Health care provider that admitted the patient.
+    COL,
+    ///Referring health care provider
+    ///
+    ///Health care provider that referred patient to current provider of services.
+    COM,
+    ///Supervising health care provider
+    ///
+    ///Health care provider that supervised the rendering of a service.
+    CON,
+    ///Party providing financing
+    ///
+    ///Identifies the party providing the financing.
+    COO,
+    ///Convoying party
+    ///
+    ///Party designated to escort the transported goods.
+    COP,
+    ///Nominated bank
+    ///
+    ///Identifies the nominated bank.
+    COQ,
+    ///Family member
+    ///
+    ///Identifies a family member.
+    COR,
+    ///Co-participant
+    ///
+    ///Identifies another party who participates in an activity.
+    COS,
+    ///Involved party
+    ///
+    ///Party which is involved in an activity.
+    COT,
+    ///Assigner
+    ///
+    ///Identifies the entity who assigns.
+    COU,
+    ///Registered principal
+    ///
+    ///An individual who is registered as a principal for an entity.
+    COV,
+    ///Freight payer on behalf of the consignor
+    ///
+    ///Freight payer is a third party acting on behalf of the consignor.
+    COW,
+    ///Freight payer on behalf of the consignee
+    ///
+    ///Freight payer is a third party acting on behalf of the consignee.
+    COX,
+    ///Party responsible for disinfection
+    ///
+    ///Party responsible for performing disinfection operations.
+    COY,
+    ///Party responsible for refueling
+    ///
+    ///Party responsible for performing refueling operations.
+    COZ,
+    ///Party to receive certificate of compliance
+    ///
+    ///Party acting for or on behalf of seller in matters concerning compliance.
+    CP,
+    ///Advising bank
+    ///
+    ///Identifies the financial institution used by the issuing bank to advise the documentary credit.
+    CPA,
+    ///Reimbursing bank
+    ///
+    ///Identifies the financial institution through which the reimbursement is to be effected.
+    CPB,
+    ///Advise through bank
+    ///
+    ///Identifies the financial institution through which the advising bank is to advise.
+    CPC,
+    ///Charges payer at destination
+    ///
+    ///Party, other than the ordering party, which has to pay the charges concerning the destination operations.
+    CPD,
+    ///Vessel master
+    ///
+    ///Master of the conveyance.
+    CPE,
+    ///Means of transport charterer
+    ///
+    ///Charterer of the means of transport.
+    CPF,
+    ///Excise party
+    ///
+    ///Party to whom excise must be paid.
+    CPG,
+    ///Copy report to
+    ///
+    ///Party receiving a copy of a report.
+    CPH,
+    ///Related healthcare party
+    ///
+    ///A healthcare party related to the subject.
+    CPI,
+    ///Clinical information provider
+    ///
+    ///Party providing clinical information.
+    CPJ,
+    ///Service requester
+    ///
+    ///Party requesting a service.
+    CPK,
+    ///Patient admitted by
+    ///
+    ///Party who admitted a patient.
+    CPL,
+    ///Patient discharged to
+    ///
+    ///The party who receives the discharged patient.
+    CPM,
+    ///Patient hosted by
+    ///
+    ///The party hosting the patient.
+    CPN,
+    ///Prescriber's contact person
+    ///
+    ///Contact person for the prescriber.
+    CPO,
+    ///Cheque order
+    ///
+    ///Party to which the cheque will be ordered, when different from the beneficiary.
+    CQ,
+    ///Empty equipment return party
+    ///
+    ///Party to whose premises empty equipment will be or has been returned.
+    CR,
+    ///Consolidator
+    ///
+    ///Party consolidating various consignments, payments etc.
+    CS,
+    ///Consignee to be specified
+    ///
+    ///The party to be identified at a later time as the consignee.
+    CT,
+    ///Container return company
+    ///
+    ///The company to which containers have to be returned.
+    CU,
+    ///Consignee of vessel
+    ///
+    ///Description to be provided.
+    CV,
+    ///Equipment owner
+    ///
+    ///Owner of equipment (container, etc.).
+    CW,
+    ///Consignee's agent
+    ///
+    ///Party authorized to act on behalf of the consignee.
+    CX,
+    /// Commissionable agent
+    ///
+    /// IATA cargo agent entitled to commission.
+    CY,
+    ///Consignor
+    ///
+    ///(3336) Party which, by contract with a carrier, consigns or sends goods with the carrier, or has them conveyed by him. Synonym: shipper, sender.
+    CZ,
+    ///Available with bank (documentary credits)
+    ///
+    ///Financial institution with whom the documentary credit is available.
+    DA,
+    ///Distributor branch
+    ///
+    ///The affiliate of a retailer or distributor.
+    DB,
+    ///Deconsolidator
+    ///
+    ///Party that splits up a large consignment composed of separate consignments of goods. The smaller consignments of goods were grouped together into that large consignment for carriage as a larger unit in order to obtain a reduced rate.
+    DC,
+    ///Despatch charge payer
+    ///
+    ///Party, other than the ordering party, which has to pay the charges concerning the despatch operations.
+    DCP,
+    ///Prescription database owner
+    ///
+    ///Organisation or person owning a prescription database.
+    DCQ,
+    ///Original prescriber
+    ///
+    ///The doctor who issued the original prescription.
+    DCR,
+    ///Temporary employee
+    ///
+    ///A person employed on a temporary basis.
+    DCS,
+    ///Designer
+    ///
+    ///A party who designs.
+    DCT,
+    ///Quotation delivered to
+    ///
+    ///Party to whom the quotation is to be or has been delivered.
+    DCU,
+    ///Developer
+    ///
+    ///A party who develops.
+    DCV,
+    ///Test execution party
+    ///
+    ///The party performing a test.
+    DCW,
+    ///Party to receive refund
+    ///
+    ///Party to whom a refund is given.
+    DCX,
+    ///Authorised issuer of prescription
+    ///
+    ///Party authorised to issue a prescription.
+    DCY,
+    ///Authorised dispenser of medicine
+    ///
+    ///Organisation or person authorised to dispense medicine.
+    DCZ,
+    ///Documentary credit account party's bank
+    ///
+    ///Bank of the documentary credit account party.
+    DD,
+    ///Report responsible party
+    ///
+    ///The party or person taking responsibility for a report.
+    DDA,
+    ///Initial sender
+    ///
+    ///The party who does the initial sending.
+    DDB,
+    ///The party authorising the original prescription
+    ///
+    ///The party authorising the issuer of the original prescription.
+    DDC,
+    ///Depositor
+    ///
+    ///Party depositing goods, financial payments or documents.
+    DE,
+    ///Documentary credit applicant
+    ///
+    ///Party at whose request the applicant's bank/issuing bank is to issue a documentary credit.
+    DF,
+    ///Documentary credit beneficiary
+    ///
+    ///Party in whose favour the documentary credit is to be issued and the party that must comply with the credit's terms and conditions.
+    DG,
+    ///Documentary credit account party
+    ///
+    ///Party which is responsible for the payment settlement of the documentary credit with the applicant's bank/issuing bank, if different from the documentary credit applicant.
+    DH,
+    ///Documentary credit second beneficiary
+    ///
+    ///Party to whom the documentary credit can be transferred.
+    DI,
+    ///Party according to documentary credit transaction
+    ///
+    ///Party related to documentary credit transaction.
+    DJ,
+    ///Documentary credit beneficiary's bank
+    ///
+    ///Financial institution with which the beneficiary of the documentary credit maintains an account.
+    DK,
+    ///Factor
+    ///
+    ///Company offering a financial service whereby a firm sells or transfers title to its accounts receivable to the factoring company.
+    DL,
+    /// Party to whom documents are to be presented
+    DM,
+    ///Owner of operation
+    ///
+    ///Owner of the operation.
+    DN,
+    ///Document recipient
+    ///
+    ///(1370) Party which should receive a specified document.
+    DO,
+    ///Delivery party
+    ///
+    ///(3144) Party to which goods should be delivered, if not identical with consignee.
+    DP,
+    ///Owner's agent
+    ///
+    ///Person acting on delegation of powers of the owner.
+    DQ,
+    ///Driver
+    ///
+    ///Person who drives a means of transport.
+    DR,
+    ///Distributor
+    ///
+    ///Party distributing goods, financial payments or documents.
+    DS,
+    ///Declarant
+    ///
+    ///(3140) Party who makes a declaration to an official body or - where legally permitted - in whose name, or on whose behalf, a declaration to an official body is made.
+    DT,
+    ///Owner's representative
+    ///
+    ///Person commissioned by the owner to represent him in certain circumstances.
+    DU,
+    ///Project management office
+    ///
+    ///Party commissioned by the owner to follow through the execution of all works.
+    DV,
+    ///Drawee
+    ///
+    ///Party on whom drafts must be drawn.
+    DW,
+    ///Engineer (construction)
+    ///
+    ///Party representing the contractor to advise and supervise engineering aspects of the works.
+    DX,
+    ///Engineer, resident (construction)
+    ///
+    ///Party commissioned by the owner to advise and supervise engineering aspects of the works.
+    DY,
+    /// Architect
+    DZ,
+    ///Architect-designer
+    ///
+    ///Designer of the construction project.
+    EA,
+    ///Building inspectorate
+    ///
+    ///Party controlling the conformity of works to legal and regulation rules.
+    EB,
+    ///Exchanger
+    ///
+    ///Party exchanging currencies or goods.
+    EC,
+    ///Engineer, consultant
+    ///
+    ///Party providing professional engineering services.
+    ED,
+    /// Location of goods for customs examination before clearance
+    ///
+    /// SE.
+    EE,
+    ///Project coordination office
+    ///
+    ///Party responsible for technical coordination of works.
+    EF,
+    ///Surveyor, topographical
+    ///
+    ///Party responsible for topographical measurements.
+    EG,
+    ///Engineer, measurement
+    ///
+    ///Party responsible for quantity measurements.
+    EH,
+    ///Controller, quality
+    ///
+    ///Party controlling the quality of goods and workmanship for the project.
+    EI,
+    ///Surveyor, quantity
+    ///
+    ///Party responsible for the quantification and valuation of the works on behalf of the contractor.
+    EJ,
+    ///Surveyor (professional), quantity
+    ///
+    ///Party responsible to the owner for the quantification and valuation of the works.
+    EK,
+    ///Project
+    ///
+    ///Party responsible for a project, e.g. a construction project.
+    EL,
+    ///Party to receive electronic memo of invoice
+    ///
+    ///Party being informed about invoice issue (via EDI).
+    EM,
+    ///Tenderer
+    ///
+    ///Firm answering an invitation to tender.
+    EN,
+    ///Owner of equipment
+    ///
+    ///Party who owns equipment.
+    EO,
+    ///Equipment drop-off party
+    ///
+    ///The party which drops off equipment.
+    EP,
+    ///Empty container responsible party
+    ///
+    ///Party responsible for the empty container.
+    EQ,
+    ///Empty container return agent
+    ///
+    ///Party, designated by owner of containers, responsible for their collection as agreed between the owner and customer/ consignee.
+    ER,
+    ///Contractor, lead
+    ///
+    ///Leader representing a grouping of co-contractors.
+    ES,
+    ///Co-contractor
+    ///
+    ///Member of a grouping of co-contractors.
+    ET,
+    ///Contractor, general
+    ///
+    ///Single contractor for the whole construction project, working by his own or with subcontractors.
+    EU,
+    ///Subcontractor
+    ///
+    ///Firm carrying out a part of the works for a contractor.
+    EV,
+    ///Subcontractor with direct payment
+    ///
+    ///Subcontractor benefiting from direct payments.
+    EW,
+    ///Exporter
+    ///
+    ///(3030) Party who makes - or on whose behalf a Customs clearing agent or other authorized person makes - an export declaration. This may include a manufacturer, seller or other person. Within a Customs union, consignor may have the same meaning as exporter.
+    EX,
+    ///Subcontractor, nominated
+    ///
+    ///Subcontractor authorized by the owner after having been proposed.
+    EY,
+    ///Operator, essential services
+    ///
+    ///Operator of essential services e.g. water, sewerage system, power.
+    EZ,
+    ///Operator, communication channel
+    ///
+    ///Operator of a communication channel.
+    FA,
+    ///Nominated freight company
+    ///
+    ///Party nominated to act as transport company or carrier for the goods.
+    FB,
+    ///Contractor, main
+    ///
+    ///Firm or grouping of co-contractors which has been awarded the contract.
+    FC,
+    ///Buyer's parent company
+    ///
+    ///Parent company, e.g. holding company.
+    FD,
+    /// Credit rating agency
+    FE,
+    ///Factor, correspondent
+    ///
+    ///Factoring company engaged by another factoring company to assist the letter with the services provided to the clients (sellers).
+    FF,
+    ///Buyer as officially registered
+    ///
+    ///Buying party as officially registered with government.
+    FG,
+    ///Seller as officially registered
+    ///
+    ///Selling party as officially registered with government.
+    FH,
+    ///Copy message to
+    ///
+    ///Party that is to receive a copy of a message.
+    FI,
+    ///Trade Union
+    ///
+    ///Organisation representing employees.
+    FJ,
+    ///Previous Trade Union
+    ///
+    ///Employee organisation who previously represented an employee .
+    FK,
+    ///Passenger
+    ///
+    ///A person conveyed by a means of transport, other than the crew.
+    FL,
+    ///Crew member
+    ///
+    ///A person manning a means of transport.
+    FM,
+    ///Tariff issuer
+    ///
+    ///The issuer of a tariff, e.g. a freight tariff.
+    FN,
+    /// Party performing inspection
+    FO,
+    ///Freight/charges payer
+    ///
+    ///Party responsible for the payment of freight.
+    FP,
+    ///Container survey agent
+    ///
+    ///The container survey agency that will survey the containers.
+    FQ,
+    ///Message from
+    ///
+    ///Party where the message comes from.
+    FR,
+    ///Party authorized to make definite a contract action
+    ///
+    ///Party who has the authority to make definite a contract action.
+    FS,
+    ///Party responsible for financial settlement
+    ///
+    ///(3450) Party responsible for either the transfer or repatriation of the funds relating to a transaction.
+    FT,
+    ///Hazardous material office
+    ///
+    ///The office responsible for providing information regarding hazardous material.
+    FU,
+    ///Party providing government furnished property
+    ///
+    ///The party responsible for providing government furnished property.
+    FV,
+    ///Freight forwarder
+    ///
+    ///Party arranging forwarding of goods.
+    FW,
+    ///Current receiver
+    ///
+    ///Current receiver of the goods in a multi-step transportation process (indirect flow) involving at least one grouping centre.
+    FX,
+    ///Current sender
+    ///
+    ///Current sender of the goods in a multi-step transportation process (indirect flow) involving at least one grouping centre.
+    FY,
+    ///Grouping centre
+    ///
+    ///A party in charge of groupage, including degroupage and regroupage.
+    FZ,
+    ///Road carrier
+    ///
+    ///A road carrier moving cargo.
+    GA,
+    ///Chamber of commerce
+    ///
+    ///Name of the Chamber of Commerce of the town where the company is registered.
+    GB,
+    ///Goods custodian
+    ///
+    ///(3024) Party responsible for the keeping of goods.
+    GC,
+    ///Producer
+    ///
+    ///Party or person who has produced the produce.
+    GD,
+    ///Registration tribunal
+    ///
+    ///Name of the tribunal where the company is registered.
+    GE,
+    ///Slot charter party
+    ///
+    ///An identification code of a participant or user that books slots (space) on a ship, more likely on a long term basis on a series of sailings. He pays for the space whether he uses it or not.
+    GF,
+    ///Warehouse
+    ///
+    ///The name of the warehouse where product is held.
+    GG,
+    ///Applicant for job
+    ///
+    ///A person who applied for a job.
+    GH,
+    ///Spouse
+    ///
+    ///Person is a spouse.
+    GI,
+    ///Mother
+    ///
+    ///Person is a mother.
+    GJ,
+    ///Father
+    ///
+    ///Person is a father.
+    GK,
+    ///Socially insured person
+    ///
+    ///A person who is registered in a social security scheme.
+    GL,
+    ///Inventory controller
+    ///
+    ///To specifically identify the party in charge of inventory control.
+    GM,
+    ///Processor
+    ///
+    ///Party or person who has or will apply a process.
+    GN,
+    ///Goods owner
+    ///
+    ///The party which owns the goods.
+    GO,
+    ///Packer
+    ///
+    ///Party or person who has undertaken or will undertake packing.
+    GP,
+    ///Slaughterer
+    ///
+    ///Party or person who has undertaken or will undertake a slaughter.
+    GQ,
+    ///Goods releaser
+    ///
+    ///(3026) Party entitled to authorize release of goods from custodian.
+    GR,
+    ///Consignor's representative
+    ///
+    ///Party authorised to represent the consignor.
+    GS,
+    ///Rail carrier
+    ///
+    ///A carrier moving cargo, including containers, via rail.
+    GT,
+    ///Originator of article number
+    ///
+    ///A code identifying the party which created a specific article number.
+    GU,
+    ///Procurement responsibility for order
+    ///
+    ///A code used to identify the organization which is responsible for the procurement.
+    GV,
+    ///Party fulfilling all operations
+    ///
+    ///Code indicating the fact that the party identified carries out all operations within that company's activities.
+    GW,
+    ///Central catalogue party
+    ///
+    ///Party controlling a central catalogue.
+    GX,
+    ///Inventory reporting party
+    ///
+    ///Party reporting inventory information.
+    GY,
+    ///Substitute supplier
+    ///
+    ///Party which may be in a position to supply products or services should the main usual supplier be unable to do so.
+    GZ,
+    ///Party which delivers consignments to the terminal
+    ///
+    ///Party which delivers consignments to a terminal.
+    HA,
+    ///Party which picks up consignments from the terminal
+    ///
+    ///Party which picks up consignments from a terminal.
+    HB,
+    ///Transit freight forwarder
+    ///
+    ///Freight forwarder to whom transit consignments are addressed, and from whom they are to be on-forwarded.
+    HC,
+    ///Inspection and acceptance party
+    ///
+    ///The party who will perform inspection and acceptance.
+    HD,
+    ///Transportation office
+    ///
+    ///The office that provides transportation information.
+    HE,
+    ///Contract administration office
+    ///
+    ///The office responsible for the administration of a contract.
+    HF,
+    ///Investigator
+    ///
+    ///A party who conducts investigations.
+    HG,
+    ///Audit office
+    ///
+    ///The office responsible for conducting audits.
+    HH,
+    ///Requestor
+    ///
+    ///The party requesting an action.
+    HI,
+    ///Foreign disclosure information office
+    ///
+    ///The office that reviews sensitive information for foreign disclosure.
+    HJ,
+    ///Mark-for party
+    ///
+    ///The party within an organization for whom the material is marked to be delivered.
+    HK,
+    ///Party to receive reports
+    ///
+    ///The party to whom reports are to be submitted.
+    HL,
+    ///Alternative manufacturer
+    ///
+    ///Party identification of an alternative manufacturer for a product.
+    HM,
+    ///Service performer
+    ///
+    ///The party who is performing a service.
+    HN,
+    ///Shipper's association
+    ///
+    ///An association of shippers.
+    HO,
+    ///Final message recipient
+    ///
+    ///To identify the final recipient of the message.
+    HP,
+    ///Account owner
+    ///
+    ///Identifies the owner of the account.
+    HQ,
+    ///Shipping line service
+    ///
+    ///Identifies the shipping line service organization.
+    HR,
+    ///Creditor
+    ///
+    ///Party to whom payment is due.
+    HS,
+    ///Clearing house
+    ///
+    ///Institution through which funds will be paid.
+    HT,
+    ///Ordering bank
+    ///
+    ///Bank which instructed the sender to act on the transaction(s).
+    HU,
+    ///Receiver of funds
+    ///
+    ///Identifies the party that receives the funds.
+    HV,
+    ///Sender of funds
+    ///
+    ///Identifies the party that sends the funds.
+    HW,
+    ///Debtor
+    ///
+    ///Party from whom payment is due.
+    HX,
+    ///Presenting bank
+    ///
+    ///The bank which presents documents to the drawee.
+    HY,
+    ///Work team
+    ///
+    ///Team responsible for performing work.
+    HZ,
+    ///Intermediary bank 1
+    ///
+    ///A financial institution between the ordered bank and the beneficiary's bank.
+    I1,
+    ///Intermediary bank 2
+    ///
+    ///A financial institution between the ordered bank and the beneficiary's bank.
+    I2,
+    ///Intermediary/broker
+    ///
+    ///Description to be provided.
+    IB,
+    ///Intermediate consignee
+    ///
+    ///The intermediate consignee.
+    IC,
+    ///Replacing manufacturer
+    ///
+    ///A code used to identify a party who replaces the previous party for the manufacture of an article.
+    ID,
+    ///Non-resident third party company with whom financial account is held
+    ///
+    ///Identifies the non-resident third party company with whom the financial account is held.
+    IE,
+    ///Non-resident group company with whom financial account is held
+    ///
+    ///Identifies the non-resident group company with whom the financial account is held.
+    IF,
+    ///Non-resident beneficiary
+    ///
+    ///The ultimate non-resident recipient of the funds. Normally the account owner who is reimbursed by the payor.
+    IG,
+    ///Resident beneficiary
+    ///
+    ///The ultimate resident recipient of the funds. Normally the account owner who is reimbursed by the payor.
+    IH,
+    ///Issuer of invoice
+    ///
+    ///(3028) Party issuing an invoice.
+    II,
+    ///Non-resident instructing party
+    ///
+    ///Identifies the non-resident party originating the instruction.
+    IJ,
+    ///Resident instructing party
+    ///
+    ///Identifies the resident party originating the instruction.
+    IL,
+    ///Importer
+    ///
+    ///(3020) Party who makes - or on whose behalf a Customs clearing agent or other authorized person makes - an import declaration. This may include a person who has possession of the goods or to whom the goods are consigned.
+    IM,
+    ///Insurer
+    ///
+    ///Description to be provided.
+    IN,
+    ///Insurance company
+    ///
+    ///Description to be provided.
+    IO,
+    ///Insurance claim adjuster
+    ///
+    ///Description to be provided.
+    IP,
+    ///Domestic financial institution
+    ///
+    ///Domestic party acting as financial institution.
+    IQ,
+    ///Non-domestic financial institution
+    ///
+    ///Non-domestic party acting as financial institution.
+    IR,
+    ///Party to receive certified inspection report
+    ///
+    ///Party (at buyer) to receive certified inspection report.
+    IS,
+    ///Installation on site
+    ///
+    ///Description to be provided.
+    IT,
+    ///Non-resident debtor
+    ///
+    ///Non-resident party who makes the payment or against whom a claim exists.
+    IU,
+    ///Invoicee
+    ///
+    ///(3006) Party to whom an invoice is issued.
+    IV,
+    ///Non-resident creditor
+    ///
+    ///Non-resident party receiving the payment or against whom a liability exists.
+    IW,
+    ///Supplier work team
+    ///
+    ///The supplier's team responsible for performing the work.
+    IX,
+    ///Tenant manager
+    ///
+    ///A code to identify the party who rents the rights to use the goodwill and facilities of an enterprise.
+    IY,
+    ///Party mandated to liquidate an enterprise
+    ///
+    ///A code to identify the party who has been legally mandated to sell off an enterprise.
+    IZ,
+    ///Certified accountant
+    ///
+    ///Code identifying the party as a certified accountant.
+    JA,
+    ///Goods collection party
+    ///
+    ///Party that will collect or has collected the goods.
+    JB,
+    ///Party at final place of positioning
+    ///
+    ///Identifies the party at the final place of positioning.
+    JC,
+    ///Customs office of clearance
+    ///
+    ///Identifies the office where customs clearance procedures take place.
+    JD,
+    ///Party from whom customs documents are to be picked up
+    ///
+    ///Identification of the party from whom customs documents are to be picked up.
+    JE,
+    ///Party from whom non-customs documents are to be picked up
+    ///
+    ///Identification of the party from whom non-customs documents are to be picked up.
+    JF,
+    ///Party to receive customs documents
+    ///
+    ///Identification of the party to whom customs documents are to be delivered.
+    JG,
+    ///Party to receive non-customs documents
+    ///
+    ///Identification of the party to whom non-customs documents are to be delivered.
+    JH,
+    ///Party designated to provide living animal care
+    ///
+    ///Party responsible to take care of transported living animals.
+    LA,
+    ///Co-producer
+    ///
+    ///A code used to identify a party who participates in production.
+    LB,
+    ///Party declaring the Value Added Tax (VAT)
+    ///
+    ///A code to identify the party who is responsible for declaring the Value Added Tax (VAT) on the sale of goods or services.
+    LC,
+    ///Party recovering the Value Added Tax (VAT)
+    ///
+    ///A code to identify the party who is eligible to recover the Value Added Tax (VAT) on the sale of goods or services.
+    LD,
+    ///Person on claim
+    ///
+    ///To identify the person who is the subject of the claim.
+    LE,
+    ///Buyer's corporate office
+    ///
+    ///The identification of the buyer's corporate office.
+    LF,
+    ///Supplier's corporate office
+    ///
+    ///The identification of the supplier's corporate office.
+    LG,
+    ///Liquidator
+    ///
+    ///The party responsible for settling or paying a debt.
+    LH,
+    ///Account coordinator
+    ///
+    ///An individual with coordination responsibilities for a specific account.
+    LI,
+    ///Inspection leader
+    ///
+    ///An individual responsible for an inspection team.
+    LJ,
+    ///Patient
+    ///
+    ///A person receiving or registered to receive medical treatment.
+    LK,
+    ///Patient companion
+    ///
+    ///Person accompanying the patient.
+    LL,
+    ///Medical treatment executant
+    ///
+    ///The party who executes a medical treatment.
+    LM,
+    ///Lender
+    ///
+    ///Party lending goods or equipment.
+    LN,
+    ///Medical treatment prescriber
+    ///
+    ///The party who prescribes a medical treatment.
+    LO,
+    ///Loading party
+    ///
+    ///Party responsible for the loading when other than carrier.
+    LP,
+    ///Debt payment authorisation party
+    ///
+    ///A party which authorises the payment of a debt.
+    LQ,
+    ///Administration centre
+    ///
+    ///Identification of an administration centre.
+    LR,
+    ///Product services and repairs centre
+    ///
+    ///A centre which services and repairs products.
+    LS,
+    ///Secretariat
+    ///
+    ///Party is a secretariat.
+    LT,
+    ///Entry point technical assessment group
+    ///
+    ///Party acts as an entry point for technical assessment.
+    LU,
+    ///Party assigning a status
+    ///
+    ///Party responsible for assigning a status.
+    LV,
+    /// Party for whom item is ultimately intended
+    MA,
+    ///Manufacturer of goods
+    ///
+    ///Party who manufactures the goods.
+    MF,
+    ///Party designated to execute re-icing
+    ///
+    ///Party designated to execute re-icing, selected in the official list of mandatories competent for this kind of operation.
+    MG,
+    /// Planning schedule/material release issuer
+    MI,
+    /// Manufacturing plant
+    MP,
+    /// Message recipient
+    MR,
+    ///Document/message issuer/sender
+    ///
+    ///Issuer of a document and/or sender of a message.
+    MS,
+    ///Party designated to execute sanitary procedures
+    MT,
+    /// Notify party no. 1
+    ///
+    /// The first party which is to be notified.
+    N1,
+    ///Notify party no. 2
+    ///
+    ///The second party which is to be notified.
+    N2,
+    ///Notify party
+    ///
+    ///(3180) Party to be notified of arrival of goods.
+    NI,
+    ///Break bulk berth operator
+    ///
+    ///Party who offers facilities for berthing of vessels, handling and storage of break bulk cargo.
+    OA,
+    ///Ordered by
+    ///
+    ///Party who issued an order.
+    OB,
+    ///Party data responsible party
+    ///
+    ///The party responsible for all party data.
+    OC,
+    ///Equipment repair party
+    ///
+    ///A party making repairs to equipment.
+    OD,
+    ///Owner of property
+    ///
+    ///Party owning a property.
+    OE,
+    ///On behalf of
+    ///
+    ///Party on behalf of which an action is executed.
+    OF,
+    ///Owner or lessor's surveyor
+    ///
+    ///Surveyor hired by the owner or lessor of the item.
+    OG,
+    ///Lessee's surveyor
+    ///
+    ///Surveyor hired by the lessee of the item.
+    OH,
+    ///Outside inspection agency
+    ///
+    ///Third party inspecting goods or equipment.
+    OI,
+    ///Third party
+    ///
+    ///Another party besides the two principals.
+    OJ,
+    ///Receiver's sub-entity
+    ///
+    ///Identifies a sub-entity within the receiver's organization.
+    OK,
+    ///Case of need party
+    ///
+    ///Party to be approached in case of difficulty.
+    OL,
+    ///Collecting bank
+    ///
+    ///Any bank, other than the remitting bank, involved in processing the collection.
+    OM,
+    ///Remitting bank
+    ///
+    ///The bank to which the principal has entrusted the handling of a collection.
+    ON,
+    ///Order of the shipper party
+    ///
+    ///The owner of goods under consignment which are moving under a negotiable transport document and will only be released upon receipt of the original transport document.
+    OO,
+    ///Operator of property or equipment
+    ///
+    ///The party which operates property or a unit of equipment.
+    OP,
+    ///Collection principal
+    ///
+    ///The party entrusting the handling of a collection to a bank.
+    OQ,
+    ///Ordered bank
+    ///
+    ///Identifies the account servicer for the ordering customer or payor.
+    OR,
+    ///Original shipper
+    ///
+    ///The original supplier of the goods.
+    OS,
+    ///Outside test agency
+    ///
+    ///Third party testing goods, equipment or services.
+    OT,
+    ///Account owner's servicing bank on the sending side
+    ///
+    ///Identifies the financial institution on the sending side which services the account owner's bank account(s).
+    OU,
+    ///Owner of means of transport
+    ///
+    ///(3126) Party owning the means of transport. No synonym of carrier = CA.
+    OV,
+    ///Account owner's servicing bank on the receiving side
+    ///
+    ///Identifies the financial institution on the receiving side which services the account owner's bank account(s).
+    OW,
+    ///Sender's correspondent bank
+    ///
+    ///The account, or branch of the sender, or another financial institution, through which the sender will reimburse the receiver.
+    OX,
+    ///Ordering customer
+    ///
+    ///Identifies the originator of the instruction.
+    OY,
+    ///Receiver's correspondent bank
+    ///
+    ///The branch of the receiver, or another financial institution, at which the funds will be made available to the receiver.
+    OZ,
+    ///Contact party 1
+    ///
+    ///First party to contact.
+    P1,
+    ///Contact party 2
+    ///
+    ///Second party to contact.
+    P2,
+    ///Contact party 3
+    ///
+    ///Third party to contact.
+    P3,
+    ///Contact party 4
+    ///
+    ///Fourth party to contact.
+    P4,
+    ///Party to receive inspection report
+    ///
+    ///Party to whom the inspection report should be sent.
+    PA,
+    ///Paying financial institution
+    ///
+    ///Financial institution designated to make payment.
+    PB,
+    ///Actual purchaser's customer
+    ///
+    ///Party the purchaser within the actual message is selling the ordered goods or services to.
+    PC,
+    ///Purchaser's department buyer
+    ///
+    ///Purchasing department of buyer.
+    PD,
+    ///Payee
+    ///
+    ///Identifies the credit party when other than the beneficiary.
+    PE,
+    ///Party to receive freight bill
+    ///
+    ///Party to whom the freight bill should be sent.
+    PF,
+    ///Prime contractor
+    ///
+    ///Party responsible for the whole project if other than the buyer.
+    PG,
+    /// Payer's financial institution
+    PH,
+    ///Payee's company name/ID
+    ///
+    ///Receiving company name/ID (ACH transfers).
+    PI,
+    ///Party to receive correspondence
+    ///
+    ///Second party designated by a first party to receive certain correspondence in lieu of it being mailed directly to this first party.
+    PJ,
+    ///Contact party
+    ///
+    ///Party to contact.
+    PK,
+    ///Payor
+    ///
+    ///Identifies the debit party when other than the ordering customer (for banking purposes).
+    PL,
+    ///Party to receive paper memo of invoice
+    ///
+    ///Party being informed about invoice issue (via paper).
+    PM,
+    ///Party to receive shipping notice
+    ///
+    ///The party is to be the recipient of the shipping notice.
+    PN,
+    ///Ordering party
+    ///
+    ///To be used only if ordering party and buyer are not identical.
+    PO,
+    /// Certifying party
+    PQ,
+    ///Payer
+    ///
+    ///(3308) Party initiating payment.
+    PR,
+    /// Payer's company name/ID (Check, Draft or Wire)
+    PS,
+    /// Party to receive test report
+    PT,
+    ///Despatch party
+    ///
+    ///(3282) Party where goods are collected or taken over by the carrier (i.e. if other than consignor).
+    PW,
+    /// Party to receive all documents
+    PX,
+    ///Checking party
+    ///
+    ///Party or contact designated on behalf of carrier or his agent to establish the actual figures for quantities, weight, volume and/or (cube) measurements of goods or containers which are to appear in the transport contract and on which charges will be based.
+    PY,
+    ///Party to print some document
+    ///
+    ///The party that is to print a specific document.
+    PZ,
+    ///Central bank or regulatory authority
+    ///
+    ///Identifies central bank or regulatory authority which must be informed of certain aspects of a message.
+    RA,
+    ///Receiving financial institution
+    ///
+    ///Financial institution designated to receive payment.
+    RB,
+    ///Party to receive commercial invoice remittance
+    ///
+    ///Party to whom payment for a commercial invoice or bill should be remitted.
+    RE,
+    ///Received from
+    ///
+    ///Name of a person or department which actually delivers the goods.
+    RF,
+    ///Seller's financial institution
+    ///
+    ///Financial institution designated by seller to receive payment. RDFI (ACH transfers).
+    RH,
+    ///Reinsurance intermediary/broker
+    ///
+    ///Intermediary party between ceding company and reinsurance.
+    RI,
+    ///Reporting carrier (Customs)
+    ///
+    ///Party who makes the cargo report to Customs.
+    RL,
+    ///Reporting carrier's nominated agent/representative (Customs)
+    ///
+    ///Agent who formally makes a cargo report to Customs on behalf of the carrier.
+    RM,
+    ///Routing party
+    ///
+    ///Party responsible for the selection of the carrier(s).
+    RP,
+    ///Party to receive statement of account
+    ///
+    ///Party to whom the statement of account should be sent.
+    RS,
+    ///Receiver of cheque
+    ///
+    ///Identifies the party which is to receive the actual cheque, when different from the receiver of funds.
+    RV,
+    ///Issuer of waybill
+    ///
+    ///Party issuing the contract (waybill) for carriage.
+    RW,
+    ///Sales responsibility
+    ///
+    ///Description to be provided.
+    SB,
+    ///Seller
+    ///
+    ///(3346) Party selling merchandise to a buyer.
+    SE,
+    ///Ship from
+    ///
+    ///Identification of the party from where goods will be or have been shipped.
+    SF,
+    ///Store group
+    ///
+    ///Description to be provided.
+    SG,
+    ///Shipping schedule issuer
+    ///
+    ///The party which issues a shipping schedule.
+    SI,
+    /// Plant
+    SK,
+    /// Store keeper
+    SN,
+    /// Sold to if different than bill to
+    SO,
+    ///Seller's agent/representative
+    ///
+    ///(3254) Party representing the seller for the purpose of the trade transaction.
+    SR,
+    ///Social securities collector's office
+    ///
+    ///Party collecting social securities premiums.
+    SS,
+    ///Ship to
+    ///
+    ///Identification of the party to where goods will be or have been shipped.
+    ST,
+    ///Supplier
+    ///
+    ///Party who supplies goods and/or services.
+    SU,
+    ///Surety for additions
+    ///
+    ///Natural of legal person (generally a bank of insurance company) who accepts responsibility in due legal form for the financial guarantee to Customs of the payment of additional duties or fees that become due against a particular shipment, which have not previously been covered by surety.
+    SX,
+    ///Surety
+    ///
+    ///Natural or legal person (generally a bank or insurance company) who accepts responsibility in due legal form for the financial consequences of non-fulfillment of another's obligations to the Customs (CCC).
+    SY,
+    ///Surety for antidumping/countervailing duty
+    ///
+    ///Natural or legal person that has been contracted by the importer to guarantee to Customs the payment of antidumping and/or countervailing duties that become due against a particular shipment.
+    SZ,
+    ///Legal receiver
+    ///
+    ///The party responsible for a receivership.
+    TA,
+    ///Submitter
+    ///
+    ///To specify that the party is a submitter.
+    TB,
+    ///Tax collector's office
+    ///
+    ///Party collecting taxes.
+    TC,
+    ///Transit charge payer
+    ///
+    ///Party, other than the ordering party, which has to pay the charges concerning the transit operations.
+    TCP,
+    ///Party to receive technical documentation
+    ///
+    ///Party to whom technical documentation should be sent.
+    TD,
+    ///Bankruptcy referee
+    ///
+    ///To specify that the party is a referee in a bankruptcy case.
+    TE,
+    ///Source of information
+    ///
+    ///To specify that the party is the source of information.
+    TF,
+    ///Judge
+    ///
+    ///To specify that the party is a judge.
+    TG,
+    ///Attorney
+    ///
+    ///To specify that the party is an attorney.
+    TH,
+    ///Law firm
+    ///
+    ///To specify that the party is a law firm.
+    TI,
+    ///Trustee
+    ///
+    ///To specify that the party is a trustee.
+    TJ,
+    ///Signatory
+    ///
+    ///To specify that the party is a signatory.
+    TK,
+    ///Occupant
+    ///
+    ///The party is an occupant.
+    TL,
+    ///Co-occupant
+    ///
+    ///The party is a co-occupant.
+    TM,
+    ///Subject of inquiry
+    ///
+    ///The party is the subject of an inquiry.
+    TN,
+    ///Lessor
+    ///
+    ///The party is a lessor.
+    TO,
+    ///Owner of residence
+    ///
+    ///Identifies the owner of a residence.
+    TP,
+    ///Founder
+    ///
+    ///Identifies the founder.
+    TQ,
+    ///Terminal operator
+    ///
+    ///A party which handles the loading and unloading of marine vessels.
+    TR,
+    ///Party to receive certified test results
+    ///
+    ///Party to whom the certified test results should be sent.
+    TS,
+    ///Transfer to
+    ///
+    ///The party which is the recipient of a transfer.
+    TT,
+    ///President
+    ///
+    ///Identifies the president.
+    TU,
+    ///Chairperson
+    ///
+    ///Identifies the chairperson.
+    TV,
+    ///Legal title holder
+    ///
+    ///Identifies the legal title holder.
+    TW,
+    ///Shareholder
+    ///
+    ///Identifies a shareholder.
+    TX,
+    ///Provider
+    ///
+    ///Identifies the provider.
+    TY,
+    ///Military branch
+    ///
+    ///Identifies the branch of the military.
+    TZ,
+    ///Educational institution
+    ///
+    ///Identifies a university, college or school.
+    UA,
+    ///Assignor
+    ///
+    ///Identifies the assignor.
+    UB,
+    ///Ultimate consignee
+    ///
+    ///Party who has been designated on the invoice or packing list as the final recipient of the stated merchandise.
+    UC,
+    ///Ultimate customer
+    ///
+    ///The final recipient of goods.
+    UD,
+    ///Advisor
+    ///
+    ///Identifies the advisor.
+    UE,
+    ///Co-defendant
+    ///
+    ///Identifies the co-defendant.
+    UF,
+    ///Merged company with retained identity
+    ///
+    ///Company whose identity has been retained from a merger.
+    UG,
+    ///Party represented
+    ///
+    ///Identifies the party represented.
+    UH,
+    ///Unexpected handling party
+    ///
+    ///Party authorized (during a voyage) to apply unexpected handling procedures or party having applied these procedures.
+    UHP,
+    ///Assignee
+    ///
+    ///Identifies the assignee.
+    UI,
+    ///Key person
+    ///
+    ///Identifies the key person.
+    UJ,
+    ///Author
+    ///
+    ///Identifies the author.
+    UK,
+    ///Ultimate parent company
+    ///
+    ///Identifies the ultimate parent company.
+    UL,
+    ///Party not to be confused with
+    ///
+    ///Identifies a party not to be confused with another party.
+    UM,
+    ///Accountant
+    ///
+    ///Identifies the accountant.
+    UN,
+    ///Plaintiff
+    ///
+    ///Identifies the plaintiff.
+    UO,
+    ///Unloading party
+    ///
+    ///Description to be provided.
+    UP,
+    ///Parent company
+    ///
+    ///Identifies the parent company.
+    UQ,
+    ///Affiliated company
+    ///
+    ///Identifies the affiliated company.
+    UR,
+    ///Bailiff
+    ///
+    ///Identifies the bailiff.
+    US,
+    ///Merged company
+    ///
+    ///Identifies the company involved in a merger.
+    UT,
+    ///Defendant
+    ///
+    ///Identifies the defendant.
+    UU,
+    ///Petitioning creditor
+    ///
+    ///Identifies the petitioning creditor.
+    UV,
+    ///Guarantee agency
+    ///
+    ///Identifies the guarantee agency.
+    UW,
+    ///Organization group
+    ///
+    ///Identifies the organization group.
+    UX,
+    ///Subsidiary
+    ///
+    ///Identifies the subsidiary.
+    UY,
+    ///Industry association
+    ///
+    ///Identifies the industry association.
+    UZ,
+    ///Joint owner
+    ///
+    ///Identifies the joint owner.
+    VA,
+    ///Joint venture
+    ///
+    ///Identifies the joint venture.
+    VB,
+    ///Filing office
+    ///
+    ///Identifies the filing office.
+    VC,
+    ///Court
+    ///
+    ///Identifies the court.
+    VE,
+    ///Liability holder
+    ///
+    ///Identifies the liability holder.
+    VF,
+    ///Local government sponsor
+    ///
+    ///Identifies the local government sponsor.
+    VG,
+    ///Mortgage company
+    ///
+    ///Identifies the mortgage company.
+    VH,
+    ///Notary public
+    ///
+    ///Identifies the notary public.
+    VI,
+    ///Officer
+    ///
+    ///Identifies the officer.
+    VJ,
+    ///Publisher
+    ///
+    ///Identifies the publisher.
+    VK,
+    ///Party manufactured for
+    ///
+    ///Identifies the party for whom manufacturing of goods is done.
+    VL,
+    ///Previous owner
+    ///
+    ///Identifies the previous owner.
+    VM,
+    ///Vendor
+    ///
+    ///Party vending goods or services.
+    VN,
+    ///Purchased company
+    ///
+    ///Identifies the purchased company.
+    VO,
+    ///Receiver manager
+    ///
+    ///Manager of a business which is in receivership status and which will not be liquidated.
+    VP,
+    ///Responsible government agency
+    ///
+    ///Identifies the responsible government agency.
+    VQ,
+    ///Sole proprietor
+    ///
+    ///Identifies the sole proprietor.
+    VR,
+    ///Auctioneer
+    ///
+    ///Identifies the auctioneer.
+    VS,
+    ///Branch
+    ///
+    ///Identifies the branch.
+    VT,
+    ///Business
+    ///
+    ///Identifies the business.
+    VU,
+    ///Ultimate same country parent company
+    ///
+    ///Identifies the highest level parent company in the same country.
+    VV,
+    ///Responsible party
+    ///
+    ///Identifies the party that can be called to account.
+    VW,
+    ///Secured party
+    ///
+    ///Identifies a party that is guaranteed against loss.
+    VX,
+    ///Other related party
+    ///
+    ///Identifies an entity as an unspecified but related party.
+    VY,
+    ///Co-debtor
+    ///
+    ///Identifies an entity as a joint or mutual debtor.
+    VZ,
+    ///Company which holds financial interest
+    ///
+    ///Identifies a company which holds any financial stake in an undertaking or organization.
+    WA,
+    ///Rating organization
+    ///
+    ///Identifies an organization responsible for assigning a classification or rating.
+    WB,
+    ///Information reference agency
+    ///
+    ///The agency responsible for the reference of information.
+    WC,
+    ///Warehouse depositor
+    ///
+    ///(3004) Party depositing goods in a warehouse.
+    WD,
+    ///Compilation agency
+    ///
+    ///The agency responsible for the compilation of information.
+    WE,
+    ///Information maintenance agency
+    ///
+    ///The agency responsible for the maintenance of information.
+    WF,
+    ///Information dissemination agency
+    ///
+    ///The agency responsible for the dissemination of information.
+    WG,
+    ///Warehouse keeper
+    ///
+    ///(3022) Party taking responsibility for goods entered into a warehouse.
+    WH,
+    ///Inspection address
+    ///
+    ///Specifies the address for an inspection.
+    WI,
+    ///Refusal party
+    ///
+    ///Identification of the party responsible for a refusal.
+    WJ,
+    ///Value added network provider
+    ///
+    ///A party that provides telecommunications interconnectivity services in an electronic data interchange environment.
+    WK,
+    ///Agency
+    ///
+    ///The business or establishment of an agent.
+    WL,
+    /// Works manager
+    WM,
+    ///Party to receive order to supply
+    ///
+    ///Party designated by the registering party to receive a binding direction to supply something.
+    WN,
+    ///Party to receive invitation to offer
+    ///
+    ///An entity to receive an invitation to offer.
+    WO,
+    ///Sub-entity
+    ///
+    ///A part into which an entity has been divided.
+    WP,
+    ///Weighting party
+    ///
+    ///Party designated (legally accepted) to ascertain the weight.
+    WPA,
+    ///Doing business as
+    ///
+    ///The name under which business is conducted.
+    WQ,
+    ///Party submitting quote
+    ///
+    ///The party stating the price of something to be purchased.
+    WR,
+    ///Wholesaler
+    ///
+    ///Seller of articles, often in large quantities, to be retailed by others.
+    WS,
+    ///Affiliated party
+    ///
+    ///A party attached or connected to another party.
+    WT,
+    ///Previous name
+    ///
+    ///Name of an entity used before the current name.
+    WU,
+    ///Party performing task
+    ///
+    ///An entity responsible for performing a task to be undertaken.
+    WV,
+    ///Registering party
+    ///
+    ///Party performing the registration.
+    WW,
+    ///No heading
+    ///
+    ///Description to be provided.
+    XX,
+    ///Mutually defined
+    ///
+    ///Party specification mutually agreed between interchanging parties.
+    #[default]
+    ZZZ,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// 3055  Code list responsible agency, coded
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum _3055 {
+    /// CCC (Customs Co-operation Council)
+    ///
+    #[strum(serialize = "1")]
+    _1,
+    /// CEC (Commission of the European Communities)
+    ///
+    /// Generic: see also 140, 141, 142, 162.
+    #[strum(serialize = "2")]
+    _2,
+    /// IATA (International Air Transport Association)
+    ///
+    /// The airline industry's international organisation.
+    #[strum(serialize = "3")]
+    _3,
+    /// ICC (International Chamber of Commerce)
+    ///
+    #[strum(serialize = "4")]
+    _4,
+    /// ISO (International Organization for Standardization)
+    ///
+    #[strum(serialize = "5")]
+    _5,
+    /// UN/ECE (United Nations - Economic Commission for Europe)
+    ///
+    #[strum(serialize = "6")]
+    _6,
+    /// CEFIC (Conseil Europeen des Federations de l'Industrie Chimique)
+    ///
+    /// EDI project for chemical industry.
+    #[strum(serialize = "7")]
+    _7,
+    /// EDIFICE
+    ///
+    /// Standardised electronic commerce forum for companies with interests in computing, electronics and telecommunications.
+    #[strum(serialize = "8")]
+    _8,
+    /// EAN (International Article Numbering association)
+    ///
+    #[strum(serialize = "9")]
+    _9,
+    /// ODETTE
+    ///
+    /// Organization for Data Exchange through Tele-Transmission in Europe (European automotive industry project).
+    #[strum(serialize = "10")]
+    _10,
+    /// Lloyd's register of shipping
+    ///
+    /// A register of ocean going vessels maintained by Lloyd's of London.
+    #[strum(serialize = "11")]
+    _11,
+    /// UIC (International union of railways)
+    ///
+    /// International Union of Railways.
+    #[strum(serialize = "12")]
+    _12,
+    /// ICAO (International Civil Aviation Organisation)
+    ///
+    #[strum(serialize = "13")]
+    _13,
+    /// ICS (International Chamber of Shipping)
+    ///
+    #[strum(serialize = "14")]
+    _14,
+    /// RINET (Reinsurance and Insurance Network)
+    ///
+    #[strum(serialize = "15")]
+    _15,
+    /// US, D&B (Dun & Bradstreet Corporation)
+    ///
+    /// Identifies the Dun & Bradstreet Corporation, United States.
+    #[strum(serialize = "16")]
+    _16,
+    /// S.W.I.F.T.
+    ///
+    /// Society for Worldwide Interbank Financial Telecommunications s.c.
+    #[strum(serialize = "17")]
+    _17,
+    /// Conventions on SAD and transit (EC and EFTA)
+    ///
+    /// SAD = Single Administrative Document.
+    #[strum(serialize = "18")]
+    _18,
+    /// FRRC (Federal Reserve Routing Code)
+    ///
+    #[strum(serialize = "19")]
+    _19,
+    /// BIC (Bureau International des Containeurs)
+    ///
+    /// The container industry's international organisation responsible for the issuance of container-related codes.
+    #[strum(serialize = "20")]
+    _20,
+    /// Assigned by transport company
+    ///
+    /// Codes assigned by a transport company.
+    #[strum(serialize = "21")]
+    _21,
+    /// US, ISA (Information Systems Agreement)
+    ///
+    /// Codes assigned by the ISA for use by its members.
+    #[strum(serialize = "22")]
+    _22,
+    /// FR, EDITRANSPORT
+    ///
+    /// French association developing EDI in transport logistics.
+    #[strum(serialize = "23")]
+    _23,
+    /// AU, ROA (Railways of Australia)
+    ///
+    /// Maintains code lists which are accepted by Australian government railways.
+    #[strum(serialize = "24")]
+    _24,
+    /// EDITEX (Europe)
+    ///
+    /// EDI group for the textile and clothing industry.
+    #[strum(serialize = "25")]
+    _25,
+    /// NL, Foundation Uniform Transport Code
+    ///
+    /// Foundation Uniform Transport Code is the EDI organisation for shippers, carriers and other logistic service providers in the Netherlands.
+    #[strum(serialize = "26")]
+    _26,
+    /// US, FDA (Food and Drug Administration)
+    ///
+    /// U.S. food and drug administration.
+    #[strum(serialize = "27")]
+    _27,
+    /// EDITEUR (European book sector electronic data interchange group)
+    ///
+    /// Code identifying the pan European user group for the book industry as an organisation responsible for code values in the book industry.
+    #[strum(serialize = "28")]
+    _28,
+    /// GB, FLEETNET
+    ///
+    /// Association of fleet vehicle hiring and leasing companies in the UK.
+    #[strum(serialize = "29")]
+    _29,
+    /// GB, ABTA (Association of British Travel Agencies)
+    ///
+    /// ABTA, Association of British Travel Agencies.
+    #[strum(serialize = "30")]
+    _30,
+    /// FI, Finish State Railway
+    ///
+    /// Finish State Railway.
+    #[strum(serialize = "31")]
+    _31,
+    /// PL, Polish State Railway
+    ///
+    /// Polish State Railway.
+    #[strum(serialize = "32")]
+    _32,
+    /// BG, Bulgaria State Railway
+    ///
+    /// Bulgaria State Railway.
+    #[strum(serialize = "33")]
+    _33,
+    /// RO, Rumanian State Railway
+    ///
+    /// Rumanian State Railway.
+    #[strum(serialize = "34")]
+    _34,
+    /// CZ, Tchechian State Railway
+    ///
+    /// Tchechian State Railway.
+    #[strum(serialize = "35")]
+    _35,
+    /// HU, Hungarian State Railway
+    ///
+    /// Hungarian State Railway.
+    #[strum(serialize = "36")]
+    _36,
+    /// GB, British Railways
+    ///
+    /// British Railways.
+    #[strum(serialize = "37")]
+    _37,
+    /// ES, Spanish National Railway
+    ///
+    /// Spanish National Railway.
+    #[strum(serialize = "38")]
+    _38,
+    /// SE, Swedish State Railway
+    ///
+    /// Swedish State Railway.
+    #[strum(serialize = "39")]
+    _39,
+    /// NO, Norwegian State Railway
+    ///
+    /// Norwegian State Railway.
+    #[strum(serialize = "40")]
+    _40,
+    /// DE, German Railway
+    ///
+    /// German Railway.
+    #[strum(serialize = "41")]
+    _41,
+    /// AT, Austrian Federal Railways
+    ///
+    /// Austrian Federal Railways.
+    #[strum(serialize = "42")]
+    _42,
+    /// LU, Luxembourg National Railway Company
+    ///
+    /// Luxembourg National Railway Company.
+    #[strum(serialize = "43")]
+    _43,
+    /// IT, Italian State Railways
+    ///
+    /// Italian State Railways.
+    #[strum(serialize = "44")]
+    _44,
+    /// NL, Netherlands Railways
+    ///
+    /// Netherlands Railways.
+    #[strum(serialize = "45")]
+    _45,
+    /// CH, Swiss Federal Railways
+    ///
+    /// Swiss Federal Railways.
+    #[strum(serialize = "46")]
+    _46,
+    /// DK, Danish State Railways
+    ///
+    /// Danish State Railways.
+    #[strum(serialize = "47")]
+    _47,
+    /// FR, French National Railway Company
+    ///
+    /// French National Railway Company.
+    #[strum(serialize = "48")]
+    _48,
+    /// BE, Belgian National Railway Company
+    ///
+    /// Belgian National Railway Company.
+    #[strum(serialize = "49")]
+    _49,
+    /// PT, Portuguese Railways
+    ///
+    /// Portuguese Railways.
+    #[strum(serialize = "50")]
+    _50,
+    /// SK, Slovakian State Railways
+    ///
+    /// Slovakian State Railways.
+    #[strum(serialize = "51")]
+    _51,
+    /// IE, Irish Transport Company
+    ///
+    /// Irish Transport Company.
+    #[strum(serialize = "52")]
+    _52,
+    /// FIATA (International Federation of Freight Forwarders Associations)
+    ///
+    /// International Federation of Freight Forwarders Associations.
+    #[strum(serialize = "53")]
+    _53,
+    /// IMO (International Maritime Organisation)
+    ///
+    /// International Maritime Organisation.
+    #[strum(serialize = "54")]
+    _54,
+    /// US, DOT (United States Department of Transportation)
+    ///
+    /// United States Department of Transportation.
+    #[strum(serialize = "55")]
+    _55,
+    /// TW, Trade-van
+    ///
+    /// Trade-van is an EDI/VAN service centre for customs, transport, and insurance in national and international trade.
+    #[strum(serialize = "56")]
+    _56,
+    /// TW, Chinese Taipei Customs
+    ///
+    /// Customs authorities of Chinese Taipei responsible for collecting import duties and preventing smuggling.
+    #[strum(serialize = "57")]
+    _57,
+    /// EUROFER
+    ///
+    /// European steel organisation - EDI project for the European steel industry.
+    #[strum(serialize = "58")]
+    _58,
+    /// DE, EDIBAU
+    ///
+    /// National body responsible for the German codification in the construction area.
+    #[strum(serialize = "59")]
+    _59,
+    /// Assigned by national trade agency
+    ///
+    /// The code list is from a national agency.
+    #[strum(serialize = "60")]
+    _60,
+    /// Association Europeenne des Constructeurs de Materiel Aerospatial (AECMA)
+    ///
+    /// A code to identify the Association Europeenne des Constructeurs de Materiel Aeropsatial (European Association of Aerospace Products Manufacturers) as an authorizing agency for code lists.
+    #[strum(serialize = "61")]
+    _61,
+    /// US, DIstilled Spirits Council of the United States (DISCUS)
+    ///
+    /// United States DIstilled Spirits Council of the United States (DISCUS).
+    #[strum(serialize = "62")]
+    _62,
+    /// North Atlantic Treaty Organization (NATO)
+    ///
+    /// A code to identify the North Atlantic Treaty Organization (NATO) as an authorizing agency for code lists.
+    #[strum(serialize = "63")]
+    _63,
+    /// FR, EDIFRANCE
+    ///
+    /// French association responsible for coordination and promotion of EDI application in France.
+    #[strum(serialize = "64")]
+    _64,
+    /// FR, GENCOD
+    ///
+    /// French organization responsible for EDI and Barcoding application in the retail sector.
+    #[strum(serialize = "65")]
+    _65,
+    /// MY, Malaysian Customs and Excise
+    ///
+    /// Malaysia Royal Customs and Excise.
+    #[strum(serialize = "66")]
+    _66,
+    /// MY, Malaysia Central Bank
+    ///
+    /// Malaysia Central Bank is a regulatory body set up by the government to charge with promoting economic monetary and credit condition favourable to commercial and industrial activity.
+    #[strum(serialize = "67")]
+    _67,
+    /// US, Bureau of Alcohol, Tobacco and Firearms (BATF)
+    ///
+    /// United States Bureau of Alcohol, Tobacco and Firearms (BATF).
+    #[strum(serialize = "68")]
+    _68,
+    /// US, National Alcohol Beverage Control Association (NABCA)
+    ///
+    /// United States National Alcohol Beverage Control Association (NABCA).
+    #[strum(serialize = "69")]
+    _69,
+    /// MY, Dagang.Net
+    ///
+    /// Malaysia, Dagang.Net is a national clearing house which provide EDI/VAN service for customs, transport, retail and financial and other industries in the national and international trade.
+    #[strum(serialize = "70")]
+    _70,
+    /// US, FCC (Federal Communications Commission)
+    ///
+    /// A code representing the United States Federal Communication Commission (FCC).
+    #[strum(serialize = "71")]
+    _71,
+    /// US, MARAD (Maritime Administration)
+    ///
+    /// A code representing the United States Maritime Administration (MARAD) under the Department of Transportation (DOT).
+    #[strum(serialize = "72")]
+    _72,
+    /// US, DSAA (Defense Security Assistance Agency)
+    ///
+    /// A code representing the United States Defense Security Assistance Agency (DSAA) under the Department of Defense (DOD).
+    #[strum(serialize = "73")]
+    _73,
+    /// US, NRC (Nuclear Regulatory Commission)
+    ///
+    /// A code representing the United States Nuclear Regulatory Commission (NRC).
+    #[strum(serialize = "74")]
+    _74,
+    /// US, ODTC (Office of Defense Trade Controls)
+    ///
+    /// A code representing the United States Office of Defense Trade Controls (ODTC) under the Department of State.
+    #[strum(serialize = "75")]
+    _75,
+    /// US, ATF (Bureau of Alcohol, Tobacco and Firearms)
+    ///
+    /// A code representing the United States Bureau of Alcohol, Tobacco and Firearms, Department of Treasury (ATF).
+    #[strum(serialize = "76")]
+    _76,
+    /// US, BXA (Bureau of Export Administration)
+    ///
+    /// A code representing the United States Bureau of Export Administration (BXA) under the Department of Commerce (DOC) .
+    #[strum(serialize = "77")]
+    _77,
+    /// US, FWS (Fish and Wildlife Service)
+    ///
+    /// A code depicting the United States Fish and Wildlife Service (FWS).
+    #[strum(serialize = "78")]
+    _78,
+    /// US, OFAC (Office of Foreign Assets Control)
+    ///
+    /// A code representing the United States Office of Foreign Assets Controls (OFAC).
+    #[strum(serialize = "79")]
+    _79,
+    /// BRMA/RAA - LIMNET - RINET Joint Venture
+    ///
+    /// Joint venture between BRMA (Brokers & Reinsurance Markets Association) / RAA (Reinsurance Association of America) - LIMNET (London Insurance Market Network) - RINET (Reinsurance and Insurance Network).
+    #[strum(serialize = "80")]
+    _80,
+    /// RU, (SFT) Society for Financial Telecommunications
+    ///
+    /// Russian company representing the users of the Global Financial Telecommunication Network (GFTN).
+    #[strum(serialize = "81")]
+    _81,
+    /// NO, Enhetsregisteret ved Bronnoysundregisterne
+    ///
+    /// The co-ordinating register for companies and business units of companies at the Bronnoysund register centre.
+    #[strum(serialize = "82")]
+    _82,
+    /// US, National Retail Federation
+    ///
+    /// The National Retail Federation is the trade association for the general merchandise retailing industry. In addition to providing support and education services, they also maintain and publish standard colour and size codes for the retail industry.
+    #[strum(serialize = "83")]
+    _83,
+    /// DE, BRD (Gesetzgeber der Bundesrepublik Deutschland)
+    ///
+    /// German legislature.
+    #[strum(serialize = "84")]
+    _84,
+    /// North America, Telecommunications Industry Forum
+    ///
+    /// Trade association representing telecommunications service providers, equipment manufacturers, suppliers to the industry and customers.
+    #[strum(serialize = "85")]
+    _85,
+    /// Assigned by party originating the message
+    ///
+    /// Codes assigned by the party originating the message.
+    #[strum(serialize = "86")]
+    _86,
+    /// Assigned by carrier
+    ///
+    /// Codes assigned by the carrier.
+    #[strum(serialize = "87")]
+    _87,
+    /// Assigned by owner of operation
+    ///
+    /// Assigned by owner of operation (e.g. used in construction).
+    #[strum(serialize = "88")]
+    _88,
+    /// Assigned by distributor
+    ///
+    #[strum(serialize = "89")]
+    _89,
+    /// Assigned by manufacturer
+    ///
+    #[strum(serialize = "90")]
+    _90,
+    /// Assigned by seller or seller's agent
+    ///
+    #[strum(serialize = "91")]
+    _91,
+    /// Assigned by buyer or buyer's agent
+    ///
+    #[strum(serialize = "92")]
+    _92,
+    /// AT, Austrian Customs
+    ///
+    #[strum(serialize = "93")]
+    _93,
+    /// AT, Austrian PTT
+    ///
+    #[strum(serialize = "94")]
+    _94,
+    /// AU, Australian Customs Service
+    ///
+    /// Australian Customs Service.
+    #[strum(serialize = "95")]
+    _95,
+    /// CA, Revenue Canada, Customs and Excise
+    ///
+    #[strum(serialize = "96")]
+    _96,
+    /// CH, Administration federale des contributions
+    ///
+    /// Indirect taxation (e.g. turn-over/sales taxes).
+    #[strum(serialize = "97")]
+    _97,
+    /// CH, Direction generale des douanes
+    ///
+    /// Customs (incl. ISO alpha 2 country code).
+    #[strum(serialize = "98")]
+    _98,
+    /// CH, Division des importations et exportations, OFAEE
+    ///
+    /// Import and export licences.
+    #[strum(serialize = "99")]
+    _99,
+    /// CH, Entreprise des PTT
+    ///
+    /// Telephone (voice/data) + telex numbers, postcodes, postal account numbers.
+    #[strum(serialize = "100")]
+    _100,
+    /// CH, Carbura
+    ///
+    /// Centrale suisse pour l'importation de carburants et combustibles liquides (Oil products).
+    #[strum(serialize = "101")]
+    _101,
+    /// CH, Centrale suisse pour l'importation du charbon
+    ///
+    /// Coal.
+    #[strum(serialize = "102")]
+    _102,
+    /// CH, Office fiduciaire des importateurs de denrees alimentaires
+    ///
+    /// Foodstuff.
+    #[strum(serialize = "103")]
+    _103,
+    /// CH, Association suisse code des articles
+    ///
+    /// Swiss article numbering association.
+    #[strum(serialize = "104")]
+    _104,
+    /// DK, Ministry of taxation, Central Customs and Tax Administration
+    ///
+    /// Danish Customs administration.
+    #[strum(serialize = "105")]
+    _105,
+    /// FR, Direction generale des douanes et droits indirects
+    ///
+    /// French Customs.
+    #[strum(serialize = "106")]
+    _106,
+    /// FR, INSEE
+    ///
+    /// Institut National de la Statistique et des Etudes Economiques.
+    #[strum(serialize = "107")]
+    _107,
+    /// FR, Banque de France
+    ///
+    #[strum(serialize = "108")]
+    _108,
+    /// GB, H.M. Customs & Excise
+    ///
+    #[strum(serialize = "109")]
+    _109,
+    /// IE, Revenue Commissioners, Customs AEP project
+    ///
+    #[strum(serialize = "110")]
+    _110,
+    /// US, U.S. Customs Service
+    ///
+    #[strum(serialize = "111")]
+    _111,
+    /// US, U.S. Census Bureau
+    ///
+    /// The Bureau of the Census of the U.S. Dept. of Commerce.
+    #[strum(serialize = "112")]
+    _112,
+    /// US, UCC (Uniform Code Council)
+    ///
+    /// The Uniform Code Council (UCC) is a not-for-profit organization which manages and administers EDI and product bar code standards for the U.S. retail industry. The UCC also maintains U.P.C. manufacturer identifiers, EDI communications identifiers and various EDI code lists specific to retailing. The UCC is located in Dayton, OH, USA.
+    #[strum(serialize = "113")]
+    _113,
+    /// US, ABA (American Bankers Association)
+    ///
+    #[strum(serialize = "114")]
+    _114,
+    /// US, DODAAC (Department Of Defense Active Agency Code)
+    ///
+    #[strum(serialize = "115")]
+    _115,
+    /// US, ANSI ASC X12
+    ///
+    /// American National Standards Institute ASC X12.
+    #[strum(serialize = "116")]
+    _116,
+    /// AT, Geldausgabeautomaten-Service Gesellschaft m.b.H.
+    ///
+    /// Description to be provided.
+    #[strum(serialize = "117")]
+    _117,
+    /// SE, Svenska Bankfoereningen
+    ///
+    /// Swedish bankers association.
+    #[strum(serialize = "118")]
+    _118,
+    /// IT, Associazione Bancaria Italiana
+    ///
+    #[strum(serialize = "119")]
+    _119,
+    /// IT, Socieata' Interbancaria per l'Automazione
+    ///
+    #[strum(serialize = "120")]
+    _120,
+    /// CH, Telekurs AG
+    ///
+    #[strum(serialize = "121")]
+    _121,
+    /// CH, Swiss Securities Clearing Corporation
+    ///
+    #[strum(serialize = "122")]
+    _122,
+    /// NO, Norwegian Interbank Research Organization
+    ///
+    #[strum(serialize = "123")]
+    _123,
+    /// NO, Norwegian Bankers' Association
+    ///
+    #[strum(serialize = "124")]
+    _124,
+    /// FI, The Finnish Bankers' Association
+    ///
+    #[strum(serialize = "125")]
+    _125,
+    /// US, NCCMA (Account Analysis Codes)
+    ///
+    #[strum(serialize = "126")]
+    _126,
+    /// DE, ARE (AbRechnungs Einheit)
+    ///
+    /// A German code for subsidiary unit number.
+    #[strum(serialize = "127")]
+    _127,
+    /// BE, Belgian Bankers' Association
+    ///
+    #[strum(serialize = "128")]
+    _128,
+    /// BE, Belgian Ministry of Finance
+    ///
+    /// VAT numbers.
+    #[strum(serialize = "129")]
+    _129,
+    /// DK, PBS (Pengainstitutternes Betalings Service)
+    ///
+    #[strum(serialize = "130")]
+    _130,
+    /// DE, German Bankers Association
+    ///
+    #[strum(serialize = "131")]
+    _131,
+    /// GB, BACS Limited
+    ///
+    #[strum(serialize = "132")]
+    _132,
+    /// GB, Association for Payment Clearing Services
+    ///
+    #[strum(serialize = "133")]
+    _133,
+    /// GB, CHAPS and Town Clearing Company Limited
+    ///
+    #[strum(serialize = "134")]
+    _134,
+    /// GB, The Clearing House
+    ///
+    #[strum(serialize = "135")]
+    _135,
+    /// GB, Article Number Association (UK) Limited
+    ///
+    /// EAN bar-coding.
+    #[strum(serialize = "136")]
+    _136,
+    /// AT, Verband oesterreichischer Banken und Bankiers
+    ///
+    /// Austrian bankers association.
+    #[strum(serialize = "137")]
+    _137,
+    /// FR, CFONB (Comite francais d'organ. et de normalisation bancaires)
+    ///
+    /// National body responsible for the French codification in banking activity.
+    #[strum(serialize = "138")]
+    _138,
+    /// UPU (Universal Postal Union)
+    ///
+    /// (a..3 country code).
+    #[strum(serialize = "139")]
+    _139,
+    /// CEC (Commission of the European Communities), DG/XXI-01
+    ///
+    /// (Computerization within Customs area).
+    #[strum(serialize = "140")]
+    _140,
+    /// CEC (Commission of the European Communities), DG/XXI-B-1
+    ///
+    /// Description to be provided.
+    #[strum(serialize = "141")]
+    _141,
+    /// CEC (Commission of the European Communities), DG/XXXIV
+    ///
+    /// Statistical Office of the European Communities: e.g. Geonomenclature.
+    #[strum(serialize = "142")]
+    _142,
+    /// NZ, New Zealand Customs
+    ///
+    #[strum(serialize = "143")]
+    _143,
+    /// NL, Netherlands Customs
+    ///
+    #[strum(serialize = "144")]
+    _144,
+    /// SE, Swedish Customs
+    ///
+    #[strum(serialize = "145")]
+    _145,
+    /// DE, German Customs
+    ///
+    #[strum(serialize = "146")]
+    _146,
+    /// BE, Belgian Customs
+    ///
+    #[strum(serialize = "147")]
+    _147,
+    /// ES, Spanish Customs
+    ///
+    #[strum(serialize = "148")]
+    _148,
+    /// IL, Israel Customs
+    ///
+    #[strum(serialize = "149")]
+    _149,
+    /// HK, Hong Kong Customs
+    ///
+    #[strum(serialize = "150")]
+    _150,
+    /// JP, Japan Customs
+    ///
+    #[strum(serialize = "151")]
+    _151,
+    /// SA, Saudi Arabia Customs
+    ///
+    #[strum(serialize = "152")]
+    _152,
+    /// IT, Italian Customs
+    ///
+    #[strum(serialize = "153")]
+    _153,
+    /// GR, Greek Customs
+    ///
+    #[strum(serialize = "154")]
+    _154,
+    /// PT, Portuguese Customs
+    ///
+    #[strum(serialize = "155")]
+    _155,
+    /// LU, Luxembourg Customs
+    ///
+    #[strum(serialize = "156")]
+    _156,
+    /// NO, Norwegian Customs
+    ///
+    #[strum(serialize = "157")]
+    _157,
+    /// FI, Finnish Customs
+    ///
+    #[strum(serialize = "158")]
+    _158,
+    /// IS, Iceland Customs
+    ///
+    #[strum(serialize = "159")]
+    _159,
+    /// LI, Liechtenstein authority
+    ///
+    /// (Identification of relevant responsible agency for e.g. banking/financial matters still pending. For e.g. Customs, currency, post/telephone: see relevant CH entry).
+    #[strum(serialize = "160")]
+    _160,
+    /// UNCTAD (United Nations - Conference on Trade And Development)
+    ///
+    #[strum(serialize = "161")]
+    _161,
+    /// CEC (Commission of the European Communities), DG/XIII-D-5
+    ///
+    /// (TEDIS - incl. CEBIS -, INSIS and CADDIA projects).
+    #[strum(serialize = "162")]
+    _162,
+    /// US, FMC (Federal Maritime Commission)
+    ///
+    #[strum(serialize = "163")]
+    _163,
+    /// US, DEA (Drug Enforcement Agency)
+    ///
+    #[strum(serialize = "164")]
+    _164,
+    /// US, DCI (Distribution Codes, INC.)
+    ///
+    #[strum(serialize = "165")]
+    _165,
+    /// US, National Motor Freight Classification Association
+    ///
+    /// The organisation in the USA which is responsible for code maintenance in the trucking industry.
+    #[strum(serialize = "166")]
+    _166,
+    /// US, AIAG (Automotive Industry Action Group)
+    ///
+    #[strum(serialize = "167")]
+    _167,
+    /// US, FIPS (Federal Information Publishing Standard)
+    ///
+    #[strum(serialize = "168")]
+    _168,
+    /// CA, SCC (Standards Council of Canada)
+    ///
+    #[strum(serialize = "169")]
+    _169,
+    /// CA, CPA (Canadian Payment Association)
+    ///
+    #[strum(serialize = "170")]
+    _170,
+    /// NL, Interpay Girale Services
+    ///
+    /// Interpay Girale Services.
+    #[strum(serialize = "171")]
+    _171,
+    /// NL, Interpay Debit Card Services
+    ///
+    /// Interpay Debit Card Services.
+    #[strum(serialize = "172")]
+    _172,
+    /// NO, NORPRO
+    ///
+    #[strum(serialize = "173")]
+    _173,
+    /// DE, DIN (Deutsches Institut fuer Normung)
+    ///
+    /// German standardization institute.
+    #[strum(serialize = "174")]
+    _174,
+    /// FCI (Factors Chain International)
+    ///
+    #[strum(serialize = "175")]
+    _175,
+    /// BR, Banco Central do Brazil
+    ///
+    /// Self-explanatory.
+    #[strum(serialize = "176")]
+    _176,
+    /// AU, LIFA (Life Insurance Federation of Australia)
+    ///
+    /// Life Insurance Federation of Australia.
+    #[strum(serialize = "177")]
+    _177,
+    /// AU, SAA (Standards Association of Australia)
+    ///
+    /// Standards Association of Australia.
+    #[strum(serialize = "178")]
+    _178,
+    /// US, Air transport association of America
+    ///
+    /// U.S. -based trade association representing the major North American scheduled airlines.
+    #[strum(serialize = "179")]
+    _179,
+    /// DE, BIA (Berufsgenossenschaftliches Institut fuer Arbeitssicherheit)
+    ///
+    /// German institute of the workmen's compensation board.
+    #[strum(serialize = "180")]
+    _180,
+    /// Edibuild
+    ///
+    /// EDI organization for companies in the construction industry.
+    #[strum(serialize = "181")]
+    _181,
+    /// US, Standard Carrier Alpha Code (Motor)
+    ///
+    /// Organisation maintaining the SCAC lists and transportation operating in North America.
+    #[strum(serialize = "182")]
+    _182,
+    /// US, American Petroleum Institute
+    ///
+    /// US-based trade association representing oil and natural gas producers, shippers, refineries, marketers, and major suppliers to the industry.
+    #[strum(serialize = "183")]
+    _183,
+    /// AU, ACOS (Australian Chamber of Shipping)
+    ///
+    /// The national organisation for the maritime industry in Australia.
+    #[strum(serialize = "184")]
+    _184,
+    /// DE, BDI (Bundesverband der Deutschen Industrie e.V.)
+    ///
+    /// German industry association.
+    #[strum(serialize = "185")]
+    _185,
+    /// US, GSA (General Services Administration)
+    ///
+    /// The US General Services Administration.
+    #[strum(serialize = "186")]
+    _186,
+    /// US, DLMSO (Defense Logistics Management Standards Office)
+    ///
+    /// The Defense Logistics Management Standards Office.
+    #[strum(serialize = "187")]
+    _187,
+    /// US, NIST (National Institute of Standards and Technology)
+    ///
+    /// The US National Institute of Standards and Technology.
+    #[strum(serialize = "188")]
+    _188,
+    /// US, DoD (Department of Defense)
+    ///
+    /// The US Department of Defense.
+    #[strum(serialize = "189")]
+    _189,
+    /// US, VA (Department of Veterans Affairs)
+    ///
+    /// The Department of Veterans Affairs.
+    #[strum(serialize = "190")]
+    _190,
+    /// IAPSO (United Nations Inter-Agency Procurement Services Office)
+    ///
+    /// United Nations organization responsible for maintaining the United Nations Common Coding System (UNCCS) which is used extensively by UN agencies in procurement and statistical analysis.
+    #[strum(serialize = "191")]
+    _191,
+    /// Shipper's association
+    ///
+    /// Code assigned by a shipper's association.
+    #[strum(serialize = "192")]
+    _192,
+    /// EU, European Telecommunications Informatics Services (ETIS)
+    ///
+    /// European Telecommunications Informatics Services is a non-profit cooperative organisation owned by European public network operators, working in the field of information technology.
+    #[strum(serialize = "193")]
+    _193,
+    /// AU, AQIS (Australian Quarantine and Inspection Service)
+    ///
+    /// Australian Quarantine and Inspection Service.
+    #[strum(serialize = "194")]
+    _194,
+    /// CO, DIAN (Direccion de Impuestos y Aduanas Nacionales)
+    ///
+    /// The Colombian customs organization.
+    #[strum(serialize = "195")]
+    _195,
+    /// US, COPAS (Council of Petroleum Accounting Society)
+    ///
+    /// Organization supplying codes of oil field equipment and tubular goods used by joint operators in the petroleum industry.
+    #[strum(serialize = "196")]
+    _196,
+    /// US, DISA (Data Interchange Standards Association)
+    ///
+    /// The organization maintaining code lists under the administration of the data interchange standards association.
+    #[strum(serialize = "197")]
+    _197,
+    /// CO, Superintendencia Bancaria De Colombia
+    ///
+    /// The organization which assigns identification numbers to financial institutions conducting business in Colombia.
+    #[strum(serialize = "198")]
+    _198,
+    /// FR, Direction de la Comptabilite Publique
+    ///
+    /// The French public accounting office.
+    #[strum(serialize = "199")]
+    _199,
+    /// NL, EAN Netherlands
+    ///
+    /// Netherlands based European Article Numbering association (EAN).
+    #[strum(serialize = "200")]
+    _200,
+    /// US, WSSA(Wine and Spirits Shippers Association)
+    ///
+    /// United States based Wine and Spirits Shippers association.
+    #[strum(serialize = "201")]
+    _201,
+    /// PT, Banco de Portugal
+    ///
+    /// Portuguese Central Bank.
+    #[strum(serialize = "202")]
+    _202,
+    /// FR, GALIA (Groupement pour l'Amelioration des Liaisons dans l'Industrie Automobile)
+    ///
+    /// The national organisation representing France in ODETTE (Organisation for Data Exchanges through Tele- Transmission in Europe).
+    #[strum(serialize = "203")]
+    _203,
+    /// DE, VDA (Verband der Automobilindustrie E.V.)
+    ///
+    /// The national organisation representing Germany in ODETTE (Organisation for Data Exchange through Tele- Transmission in Europe).
+    #[strum(serialize = "204")]
+    _204,
+    /// IT, ODETTE Italy
+    ///
+    /// The national organisation representing Italy in ODETTE (Organisation for Data Exchange through Tele- Transmission in Europe).
+    #[strum(serialize = "205")]
+    _205,
+    /// NL, ODETTE Netherlands
+    ///
+    /// The national organisation representing Netherlands in ODETTE (Organisation for Data Exchange through Tele- Transmission in Europe).
+    #[strum(serialize = "206")]
+    _206,
+    /// ES, ODETTE Spain
+    ///
+    /// The national organisation representing Spain in ODETTE (Organisation for Data Exchange through Tele- Transmission in Europe).
+    #[strum(serialize = "207")]
+    _207,
+    /// SE, ODETTE Sweden
+    ///
+    /// The national organisation representing Scandinavian countries in ODETTE (Organisation for Data Exchange through Tele-Transmission in Europe).
+    #[strum(serialize = "208")]
+    _208,
+    /// GB, ODETTE United Kingdom
+    ///
+    /// The national organisation representing UK in ODETTE (Organisation for Data Exchange through Tele- Transmission in Europe).
+    #[strum(serialize = "209")]
+    _209,
+    /// EU, EDI for financial, informational, cost, accounting, auditing and social areas (EDIFICAS) - Europe
+    ///
+    /// European association dealing with accounting and auditing.
+    #[strum(serialize = "210")]
+    _210,
+    /// FR, EDI for financial, informational, cost, accounting, auditing and social areas (EDIFICAS) - France
+    ///
+    /// French association dealing with accounting and auditing.
+    #[strum(serialize = "211")]
+    _211,
+    /// DE, Deutsch Telekom AG
+    ///
+    /// German telecommunication services agency.
+    #[strum(serialize = "212")]
+    _212,
+    /// JP, NACCS Center (Nippon Automated Cargo Clearance System Operations Organization)
+    ///
+    /// NACCS (Nippon Automated Cargo Clearance System Operation Organization) Center is the operations organization of the automated cargo clearance system in Japan.
+    #[strum(serialize = "213")]
+    _213,
+    /// US, AISI (American Iron and Steel Institute)
+    ///
+    /// American iron and steel institute.
+    #[strum(serialize = "214")]
+    _214,
+    /// AU, APCA (Australian Payments Clearing Association)
+    ///
+    /// Australian association responsible for the management of payment clearing.
+    #[strum(serialize = "215")]
+    _215,
+    /// US, Department of Labor
+    ///
+    /// To identify the United States department of labour.
+    #[strum(serialize = "216")]
+    _216,
+    /// US, N.A.I.C. (National Association of Insurance Commissioners)
+    ///
+    /// To identify the United States, National Association of Insurance Commissioners.
+    #[strum(serialize = "217")]
+    _217,
+    /// GB, The Association of British Insurers
+    ///
+    /// An association that administers code lists on behalf of the UK insurance community.
+    #[strum(serialize = "218")]
+    _218,
+    /// FR, d'ArvA
+    ///
+    /// Value added network administering insurance code lists on behalf of the French insurance community.
+    #[strum(serialize = "219")]
+    _219,
+    /// FI, Finnish tax board
+    ///
+    /// Finnish tax board.
+    #[strum(serialize = "220")]
+    _220,
+    /// FR, CNAMTS (Caisse Nationale de l'Assurance Maladie des Travailleurs Salaries)
+    ///
+    /// The French public institution funding health-care for salaried workers.
+    #[strum(serialize = "221")]
+    _221,
+    /// DK, Danish National Board of Health
+    ///
+    /// The national authority responsible for the supervision of health activities in Denmark.
+    #[strum(serialize = "222")]
+    _222,
+    /// DK, Danish Ministry of Home Affairs
+    ///
+    /// The ministry responsible for all interior affairs concerning the Danish people.
+    #[strum(serialize = "223")]
+    _223,
+    /// US, Aluminum Association
+    ///
+    /// Organization that assigns identification numbers for the aluminum industry.
+    #[strum(serialize = "224")]
+    _224,
+    /// US, CIDX (Chemical Industry Data Exchange)
+    ///
+    /// Organization that assigns identification numbers for the chemical Industry.
+    #[strum(serialize = "225")]
+    _225,
+    /// US, Carbide Manufacturers
+    ///
+    /// Organization that assigns identification numbers for the iron and carbide manufacturing industry.
+    #[strum(serialize = "226")]
+    _226,
+    /// US, NWDA (National Wholesale Druggist Association)
+    ///
+    /// Organization that assigns identification numbers for the wholesale drug industry.
+    #[strum(serialize = "227")]
+    _227,
+    /// US, EIA (Electronic Industry Association)
+    ///
+    /// Organization that assigns identification numbers for the electronic industry.
+    #[strum(serialize = "228")]
+    _228,
+    /// US, American Paper Institute
+    ///
+    /// Organization that assigns identification numbers for the American paper industry.
+    #[strum(serialize = "229")]
+    _229,
+    /// US, VICS (Voluntary Inter-Industry Commerce Standards)
+    ///
+    /// Organization that assigns identification numbers for the retail industry.
+    #[strum(serialize = "230")]
+    _230,
+    /// Copper and Brass Fabricators Council
+    ///
+    /// Organization that assigns identification numbers for the copper and brass fabricators industry.
+    #[strum(serialize = "231")]
+    _231,
+    /// GB, Inland Revenue
+    ///
+    /// Code identifying the government department responsible for assessing and collecting revenue consisting of taxes and inland duties in Great Britain.
+    #[strum(serialize = "232")]
+    _232,
+    /// US, OMB (Office of Management and Budget)
+    ///
+    /// Codes are assigned by the United States Office of Management and Budget.
+    #[strum(serialize = "233")]
+    _233,
+    /// DE, Siemens AG
+    ///
+    /// Siemens AG, Germany.
+    #[strum(serialize = "234")]
+    _234,
+    /// AU, Tradegate (Electronic Commerce Australia)
+    ///
+    /// Australian industry body coordinating codes for use in local and international commerce and trade.
+    #[strum(serialize = "235")]
+    _235,
+    /// US, United States Postal Service (USPS)
+    ///
+    /// Code specifying the official postal service of the United States.
+    #[strum(serialize = "236")]
+    _236,
+    /// US, United States health industry
+    ///
+    /// Code assigned by the United States health industry.
+    #[strum(serialize = "237")]
+    _237,
+    /// US, TDCC (Transportation Data Coordinating Committee)
+    ///
+    /// United States Transportation Data Coordinating Committee.
+    #[strum(serialize = "238")]
+    _238,
+    /// US, HL7 (Health Level 7)
+    ///
+    /// United States, electronic data interchange standards- making organization, Health Level 7.
+    #[strum(serialize = "239")]
+    _239,
+    /// US, CHIPS (Clearing House Interbank Payment Systems)
+    ///
+    /// United States financial clearing house.
+    #[strum(serialize = "240")]
+    _240,
+    /// PT, SIBS (Sociedade Interbancaria de Servicos)
+    ///
+    /// Portuguese automated clearing house.
+    #[strum(serialize = "241")]
+    _241,
+    /// NL, Interpay Giraal
+    ///
+    /// Interpay Giraal.
+    #[strum(serialize = "242")]
+    _242,
+    /// NL, Interpay Cards
+    ///
+    /// Interpay Cards.
+    #[strum(serialize = "243")]
+    _243,
+    /// US, Department of Health and Human Services
+    ///
+    /// United States Department of Health and Human Services.
+    #[strum(serialize = "244")]
+    _244,
+    /// DK, EAN (European Article Numbering) Denmark
+    ///
+    /// Denmark based European Article Numbering (EAN) association.
+    #[strum(serialize = "245")]
+    _245,
+    /// DE, Centrale fuer Coorganisation GMBH
+    ///
+    /// German representation of European Article Numbering (EAN) International.
+    #[strum(serialize = "246")]
+    _246,
+    /// US, HBICC (Health Industry Business Communication Council)
+    ///
+    /// Code identifying the United States HIBCC (Health Industry Business Communication Council).
+    #[strum(serialize = "247")]
+    _247,
+    /// US, ASTM (American Society of Testing and Materials)
+    ///
+    /// A not-for-profit organization that provides a forum for producers, users, ultimate consumers, and those having a general interest (representatives of government and academia) to meet on common ground and write standards for materials, products, systems, and services.
+    #[strum(serialize = "248")]
+    _248,
+    /// IP (Institute of Petroleum)
+    ///
+    /// An independent European centre for the advancement and dissemination of technical, economic and professional knowledge relating to the international oil and gas industry.
+    #[strum(serialize = "249")]
+    _249,
+    /// US, UOP (Universal Oil Products)
+    ///
+    /// An United States based organization that provides products, services and technology primarily in the areas of petroleum refining, olefins, aromatics, and gas processing.
+    #[strum(serialize = "250")]
+    _250,
+    /// AU, HIC (Health Insurance Commission)
+    ///
+    /// Australian agency responsible for administering the Health Insurance Act.
+    #[strum(serialize = "251")]
+    _251,
+    /// AU, AIHW (Australian Institute of Health and Welfare)
+    ///
+    /// Australian statutory authority responsible for the national collection of health related statistics and health related data definitions.
+    #[strum(serialize = "252")]
+    _252,
+    /// AU, NCCH (National Centre for Classification in Health)
+    ///
+    /// Australian national authority responsible for healthcare classifications.
+    #[strum(serialize = "253")]
+    _253,
+    /// AU, DOH (Australian Department of Health)
+    ///
+    /// Australian government department responsible for administration of health policy.
+    #[strum(serialize = "254")]
+    _254,
+    /// AU, ADA (Australian Dental Association)
+    ///
+    /// Industry association responsible for the classification of dental services in Australia.
+    #[strum(serialize = "255")]
+    _255,
+    /// US, AAR (Association of American Railroads)
+    ///
+    /// The official United States organization of the railroads in North America.
+    #[strum(serialize = "256")]
+    _256,
+    /// US, UN/SPSC (United Nations Standard Products and Services Classification) association
+    ///
+    /// The agency responsible for the maintenance of the United Nations standard products and services classification code.
+    #[strum(serialize = "257")]
+    _257,
+    /// JP, Japanese Ministry of Transport
+    ///
+    /// Japanese Ministry of Transport.
+    #[strum(serialize = "258")]
+    _258,
+    /// JP, Japanese Maritime Safety Agency
+    ///
+    /// Japanese Maritime Safety Agency.
+    #[strum(serialize = "259")]
+    _259,
+    /// Ediel Nordic forum
+    ///
+    /// A code to identify Ediel Nordic forum, which is an organization standardizing the use of EDI between the participants in the Nordic power market.
+    #[strum(serialize = "260")]
+    _260,
+    /// EEG7, European Expert Group 7 (Insurance)
+    ///
+    /// European Expert Group 7 for Insurance.
+    #[strum(serialize = "261")]
+    _261,
+    /// DE, GDV (Gesamtverband der Deutschen Versicherungswirtschaft e.V.)
+    ///
+    /// Gesamtverband der Deutschen Versicherungswirtschaft e.V. (German Insurance Association).
+    #[strum(serialize = "262")]
+    _262,
+    /// CA, CSIO (Centre for Study of Insurance Operations)
+    ///
+    /// The Centre for Study of Insurance Operations (CSIO) in Canada.
+    #[strum(serialize = "263")]
+    _263,
+    /// FR, AGF (Assurances Generales de France)
+    ///
+    /// Code lists are administered by Assurances Generales de France (AGF).
+    #[strum(serialize = "264")]
+    _264,
+    /// SE, Central bank
+    ///
+    /// Swedish central bank.
+    #[strum(serialize = "265")]
+    _265,
+    /// US, DoA (Department of Agriculture)
+    ///
+    /// Department of Agriculture, United States federal agency.
+    #[strum(serialize = "266")]
+    _266,
+    /// RU, Russian Bank Identification Code (BIC)
+    ///
+    /// BIC is used for party identification in the bank of Russia payment system and is a subdivision directory for the bank of Russia.
+    #[strum(serialize = "267")]
+    _267,
+    /// FR, DGI (Direction Generale des Impots)
+    ///
+    /// French taxation authority.
+    #[strum(serialize = "268")]
+    _268,
+    /// GRE (Reference Group of Experts)
+    ///
+    /// An international association that administers code lists on behalf of business credit information users and providers.
+    #[strum(serialize = "269")]
+    _269,
+    /// Concord EDI group
+    ///
+    /// An organisation of international transport equipment leasing companies and transport equipment repair providers responsible for promoting the use of EDI standards and standard business terms.
+    #[strum(serialize = "270")]
+    _270,
+    /// InterContainer InterFrigo
+    ///
+    /// European railway associated organisation involved in the transport of containers by rail.
+    #[strum(serialize = "271")]
+    _271,
+    /// Joint Automotive Industry agency
+    ///
+    /// The Joint Automotive Industry (JAI) agency is in charge of code lists that are common to automotive industry groups.
+    #[strum(serialize = "272")]
+    _272,
+    /// CH, SCC (Swiss Chambers of Commerce)
+    ///
+    /// Swiss Chambers of Commerce.
+    #[strum(serialize = "273")]
+    _273,
+    /// ITIGG (International Transport Implementation Guidelines Group)
+    ///
+    /// ITIGG is the UN/EDIFACT transport message development group's organisation responsible for the issuance of globally harmonised transport-related codes.
+    #[strum(serialize = "274")]
+    _274,
+    /// ES, Banco de España
+    ///
+    /// The Spanish central bank.
+    #[strum(serialize = "275")]
+    _275,
+    /// Mutually defined
+    #[strum(ascii_case_insensitive)]
+    ZZZ,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// 3227  Place/location qualifier
+#[derive(
+    Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default, CodeList,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum _3227 {
+    /// Place of terms of delivery
+    #[strum(serialize = "1")]
+    _1,
+    #[strum(serialize = "2")]
+    _2,
+    /// Place of despatch
+    #[strum(serialize = "3")]
+    _3,
+    /// Goods receipt place
+    #[strum(serialize = "4")]
+    _4,
+    /// Place of departure
+    #[strum(serialize = "5")]
+    _5,
+    /// Place of delivery
+    #[strum(serialize = "7")]
+    _7,
+    /// Place of destination
+    #[strum(serialize = "8")]
+    _8,
+    /// Place/port of loading
+    #[strum(serialize = "9")]
+    _9,
+    /// Place/port of discharge
+    #[strum(serialize = "11")]
+    _11,
+    /// Place of transhipment
+    #[strum(serialize = "13")]
+    _13,
+    /// Warehouse
+    #[strum(serialize = "18")]
+    _18,
+    /// Factory/plant
+    #[strum(serialize = "19")]
+    _19,
+    /// Place of ultimate destination of goods
+    #[strum(serialize = "20")]
+    _20,
+    /// Port of entry
+    #[strum(serialize = "24")]
+    _24,
+    /// Country of origin
+    #[strum(serialize = "27")]
+    _27,
+    /// Country of destination of goods
+    #[strum(serialize = "28")]
+    _28,
+    #[strum(serialize = "29")]
+    _29,
+    /// Next port of call
+    #[strum(serialize = "61")]
+    _61,
+    /// Original port of loading
+    #[strum(serialize = "76")]
+    _76,
+    /// Place of receipt
+    #[strum(serialize = "88")]
+    _88,
+    /// Place of registration
+    #[strum(serialize = "89")]
+    _89,
+    /// Place of document issue
+    #[strum(serialize = "91")]
+    _91,
+    /// Routing
+    #[strum(serialize = "92")]
+    _92,
+    /// Previous port of call
+    #[strum(serialize = "94")]
+    _94,
+    /// Stowage cell
+    #[strum(serialize = "147")]
+    _147,
+    #[strum(serialize = "175")]
+    _175,
+    #[strum(serialize = "202")]
+    _202,
+    /// Mutually defined
+    #[default]
+    ZZZ,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// 4343  Response type code
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, EnumString, Display, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum _4343 {
+    AA,
+    AB,
+    AC,
+    AD,
+    AE,
+    AF,
+    AG,
+    AH,
+    AI,
+    AJ,
+    AP,
+    AQ,
+    AR,
+    AS,
+    CA,
+    CO,
+    NA,
+    RE,
+    UR,
+    US,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// 4451  Text subject qualifier
+#[derive(
+    Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default, CodeList,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum _4451 {
+    /// Goods item description
+    AAA,
+    /// Payment term
+    AAB,
+    /// Dangerous goods additional information
+    AAC,
+    /// Dangerous goods technical name
+    AAD,
+    /// Acknowledgement description
+    AAE,
+    /// Rate additional information
+    AAF,
+    /// Party instructions
+    AAG,
+    /// General information
+    AAI,
+    /// Additional conditions of sale/purchase
+    AAJ,
+    /// Price conditions
+    AAK,
+    /// Goods dimensions in characters
+    AAL,
+    /// Equipment re-usage restrictions
+    AAM,
+    /// Handling restriction
+    AAN,
+    /// Error description (free text)
+    AAO,
+    /// Response (free text)
+    AAP,
+    /// Package content's description
+    AAQ,
+    /// Terms of delivery
+    AAR,
+    /// Bill of lading remarks
+    AAS,
+    /// Mode of settlement information
+    AAT,
+    /// Consignment invoice information
+    AAU,
+    /// Clearance invoice information
+    AAV,
+    /// Letter of credit information
+    AAW,
+    /// License information
+    AAX,
+    /// Certification statements
+    AAY,
+    /// Additional export information
+    AAZ,
+    /// Tariff statements
+    ABA,
+    /// Medical history
+    ABB,
+    /// Conditions of sale or purchase
+    ABC,
+    /// Contract document type
+    ABD,
+    /// Additional information
+    ACB,
+    /// Reason
+    ACD,
+    /// Allowance/charge information
+    ALC,
+    /// Authentication
+    AUT,
+    /// Transport contract document clause
+    BLC,
+    /// Customs clearance instructions
+    CCI,
+    /// Customs clearance instructions export
+    CEX,
+    /// Change information
+    CHG,
+    /// Customs clearance instruction import
+    CIP,
+    /// Clearance place requested
+    CLP,
+    /// Loading remarks
+    CLR,
+    /// Order information
+    COI,
+    /// Customer remarks
+    CUR,
+    /// Customs declaration information
+    CUS,
+    /// Damage remarks
+    DAR,
+    /// Document issuance information
+    DCL,
+    /// Delivery information
+    DEL,
+    /// Delivery instructions
+    DIN,
+    /// Documentation instructions
+    DOC,
+    /// Duty declaration
+    DUT,
+    /// Effective used routing
+    EUR,
+    /// First block to be printed on the transport contract
+    FBC,
+    /// Government bill of lading information
+    GBL,
+    /// Entire transaction set
+    GEN,
+    /// Consignment handling instruction
+    HAN,
+    /// Hazard information
+    HAZ,
+    /// Consignment information for consignee
+    ICN,
+    /// Invoice mailing instructions
+    IMI,
+    /// Commercial invoice item description
+    IND,
+    /// Insurance instructions
+    INS,
+    /// Invoice instruction
+    INV,
+    /// Information for railway purpose
+    IRP,
+    /// Inland transport details
+    ITR,
+    /// Testing instructions
+    ITS,
+    /// Language
+    LAN,
+    /// Line item
+    LIN,
+    /// Loading instruction
+    LOI,
+    /// Miscellaneous charge order
+    MCO,
+    /// Additional marks/numbers information
+    MKS,
+    /// Order instruction
+    ORI,
+    /// Other service information
+    OSI,
+    /// Packing/marking information
+    PAC,
+    /// Payment instructions information
+    PAI,
+    /// Payables information
+    PAY,
+    /// Packaging information
+    PKG,
+    /// Packaging terms information
+    PKT,
+    /// Payment detail/remittance information
+    PMD,
+    /// Payment information
+    PMT,
+    /// Product information
+    PRD,
+    /// Price calculation formula
+    PRF,
+    /// Priority information
+    PRI,
+    /// Purchasing information
+    PUR,
+    /// Quarantine instructions
+    QIN,
+    /// Quality demands/requirements
+    QQD,
+    /// Quotation instruction/information
+    QUT,
+    /// Risk and handling information
+    RAH,
+    /// Regulatory information
+    REG,
+    /// Return to origin information
+    RET,
+    /// Receivables
+    REV,
+    /// Consignment route
+    RQR,
+    /// Safety information
+    SAF,
+    /// Consignment documentary instruction
+    SIC,
+    /// Special instructions
+    SIN,
+    /// Ship line requested
+    SLR,
+    /// Special permission for transport, generally
+    SPA,
+    /// Special permission concerning the goods to be transported
+    SPG,
+    /// Special handling
+    SPH,
+    /// Special permission concerning package
+    SPP,
+    /// Special permission concerning transport means
+    SPT,
+    /// Subsidiary risk number (IATA/DGR)
+    SRN,
+    /// Special service request
+    SSR,
+    /// Supplier remarks
+    SUR,
+    /// Consignment tariff
+    TCA,
+    /// Consignment transport
+    TDT,
+    /// Transportation information
+    TRA,
+    /// Requested tariff
+    TRR,
+    /// Tax declaration
+    TXD,
+    /// Warehouse instruction/information
+    WHI,
+    /// Mutually defined
+    #[default]
+    ZZZ,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// 6311  Measurement application qualifier
+#[derive(
+    Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default, CodeList,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum _6311 {
+    /// Measurement
+    #[default]
+    AAE,
+    ABT,
+    /// Counts
+    CT,
+    /// Physical dimensions (product ordered)
+    PD,
+    /// Volume
+    VOL,
+    /// Weights
+    WT,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// 6313  Measurement dimension, coded
+#[derive(Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, CodeList)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum _6313 {
+    /// Unit net weight
+    AAA,
+    /// Unit gross weight
+    AAB,
+    /// Net weight
+    AAL,
+    /// Gross volume
+    AAW,
+    /// Net volume
+    AAX,
+    /// Gross weight
+    G,
+    /// Height dimension
+    HT,
+    /// Length dimension
+    LN,
+    /// Net weight
+    N,
+    SQ,
+    /// Tare weight
+    T,
+    /// Transport equipment verified gross mass (weight)
+    VGM,
+    /// Width dimension
+    WD,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// 8051  Transport stage qualifier
+#[derive(
+    Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default, CodeList,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum _8051 {
+    /// Inland transport
+    #[strum(serialize = "1")]
+    _1,
+    /// Pre-carriage transport
+    #[strum(serialize = "10")]
+    _10,
+    /// At border
+    #[strum(serialize = "11")]
+    _11,
+    /// At departure
+    #[strum(serialize = "12")]
+    _12,
+    /// At destination
+    #[strum(serialize = "13")]
+    _13,
+    /// Main-carriage transport
+    #[default]
+    #[strum(serialize = "20")]
+    _20,
+    /// On-carriage transport
+    #[strum(serialize = "30")]
+    _30,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// 8053  Equipment qualifier
+#[derive(
+    Debug, Serialize, Deserialize, EnumString, Display, Clone, PartialEq, Eq, Default, CodeList,
+)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum _8053 {
+    /// Ground equipment
+    AA,
+    /// Chain
+    AB,
+    /// Temperature recorder
+    AD,
+    /// Temperature probe
+    AE,
+    /// Slipsheet
+    AG,
+    /// Vessel hold
+    AI,
+    /// Flat rack
+    AJ,
+    /// Blocks
+    BL,
+    /// Barge
+    BR,
+    /// Boxcar
+    BX,
+    /// Chassis
+    CH,
+    /// Container
+    #[default]
+    CN,
+    /// Exchangeable EUR flat pallet
+    EFP,
+    /// Exchangeable EUR box pallet
+    EYP,
+    /// Load/unload device on equipment
+    LU,
+    /// Flat car
+    RF,
+    /// Reefer generator
+    RG,
+    /// Rope
+    RO,
+    /// Rail car
+    RR,
+    /// Swap body
+    SW,
+    /// Trailer
+    TE,
+    /// ULD (Unit load device)
+    UL,
+    /// A code which is not in the code list, kept as received
+    #[cfg(feature = "lenient-codes")]
+    #[strum(default)]
+    Unknown(String),
 }
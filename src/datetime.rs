@@ -0,0 +1,330 @@
+//! Dates, times and periods of C507 by their format code (2379).
+//!
+//! ```
+//! # #[cfg(feature = "d00b")]
+//! # {
+//! use edifact_types::d00b::{_2005, _2379, DTM};
+//! use edifact_types::datetime::DateTimeValue;
+//! use edifact_types::util::Parser;
+//!
+//! let (_, dtm) = DTM::parse("DTM+137:202201010021:203'").unwrap();
+//! let DateTimeValue::DateTime(issued) = dtm.value().unwrap() else { panic!() };
+//! assert_eq!(issued.to_string(), "2022-01-01 00:21:00");
+//!
+//! let dtm = DTM::new(_2005::_132, issued.date(), _2379::_102).unwrap();
+//! assert_eq!(dtm.to_string(), "DTM+132:20220101:102");
+//! # }
+//! ```
+//!
+//! Years without century (`YY`) are taken from 1969 to 2068. The alphabetic time zones `UTC`,
+//! `GMT` and `Z` of 303 and 304 are read as offset zero.
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+use std::fmt;
+
+/// A date, time or period of C507.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimeValue {
+    /// e.g. 102 `CCYYMMDD`
+    Date(NaiveDate),
+    /// e.g. 203 `CCYYMMDDHHMM`
+    DateTime(NaiveDateTime),
+    /// 205 `CCYYMMDDHHMMZHHMM`, 303 and 304 with the offset in hours or `UTC`
+    Zoned(DateTime<FixedOffset>),
+    /// 401 `HHMM` and 402 `HHMMSS`
+    Time(NaiveTime),
+    /// 718 `CCYYMMDD-CCYYMMDD`
+    DatePeriod(NaiveDate, NaiveDate),
+    /// 719 `CCYYMMDDHHMM-CCYYMMDDHHMM`
+    DateTimePeriod(NaiveDateTime, NaiveDateTime),
+}
+
+impl From<NaiveDate> for DateTimeValue {
+    fn from(date: NaiveDate) -> Self {
+        DateTimeValue::Date(date)
+    }
+}
+
+impl From<NaiveDateTime> for DateTimeValue {
+    fn from(datetime: NaiveDateTime) -> Self {
+        DateTimeValue::DateTime(datetime)
+    }
+}
+
+impl From<DateTime<FixedOffset>> for DateTimeValue {
+    fn from(datetime: DateTime<FixedOffset>) -> Self {
+        DateTimeValue::Zoned(datetime)
+    }
+}
+
+impl From<NaiveTime> for DateTimeValue {
+    fn from(time: NaiveTime) -> Self {
+        DateTimeValue::Time(time)
+    }
+}
+
+/// Error of [`parse`] and [`format`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateTimeError {
+    /// C507 has no value (2380)
+    MissingValue,
+    /// C507 has no format code (2379)
+    MissingFormat,
+    /// a format code which is not supported, e.g. 802 for a number of months
+    UnsupportedFormat(String),
+    /// the value does not match its format code
+    Invalid { value: String, format: String },
+    /// the value can not be written with the format code, e.g. a date with 203
+    Mismatch { format: String },
+}
+
+impl fmt::Display for DateTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateTimeError::MissingValue => write!(f, "missing date or time value"),
+            DateTimeError::MissingFormat => write!(f, "missing date or time format code"),
+            DateTimeError::UnsupportedFormat(format) => {
+                write!(f, "unsupported date or time format code {format}")
+            }
+            DateTimeError::Invalid { value, format } => {
+                write!(f, "value {value:?} does not match format code {format}")
+            }
+            DateTimeError::Mismatch { format } => {
+                write!(f, "value can not be written with format code {format}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateTimeError {}
+
+enum Layout {
+    Date(&'static str),
+    DateTime(&'static str),
+    /// date and time, followed by the offset with (205) or without minutes
+    Zoned(&'static str, bool),
+    Time(&'static str),
+    DatePeriod,
+    DateTimePeriod,
+}
+
+fn layout(format: &str) -> Option<Layout> {
+    Some(match format {
+        "2" => Layout::Date("%d%m%y"),
+        "3" => Layout::Date("%m%d%y"),
+        "4" => Layout::Date("%d%m%Y"),
+        "101" => Layout::Date("%y%m%d"),
+        "102" => Layout::Date("%Y%m%d"),
+        "201" => Layout::DateTime("%y%m%d%H%M"),
+        "202" => Layout::DateTime("%y%m%d%H%M%S"),
+        "203" => Layout::DateTime("%Y%m%d%H%M"),
+        "204" => Layout::DateTime("%Y%m%d%H%M%S"),
+        "205" => Layout::Zoned("%Y%m%d%H%M", true),
+        "303" => Layout::Zoned("%Y%m%d%H%M", false),
+        "304" => Layout::Zoned("%Y%m%d%H%M%S", false),
+        "401" => Layout::Time("%H%M"),
+        "402" => Layout::Time("%H%M%S"),
+        "718" => Layout::DatePeriod,
+        "719" => Layout::DateTimePeriod,
+        _ => return None,
+    })
+}
+
+/// Splits a period into start and end, the hyphen is optional.
+fn split_period(value: &str) -> Option<(&str, &str)> {
+    if !value.is_ascii() {
+        return None;
+    }
+    match value.len() % 2 {
+        0 => Some(value.split_at(value.len() / 2)),
+        _ => value.split_once('-'),
+    }
+}
+
+/// Replaces the alphabetic time zone of 303 and 304 by its offset.
+fn zone_offset(value: &str) -> Option<String> {
+    ["UTC", "GMT", "Z"]
+        .iter()
+        .find_map(|zone| value.strip_suffix(zone))
+        .map(|datetime| format!("{datetime}+00"))
+}
+
+/// Reads `value` (2380) with the format `code` (2379).
+pub fn parse(value: &str, code: &str) -> Result<DateTimeValue, DateTimeError> {
+    let layout = layout(code).ok_or_else(|| DateTimeError::UnsupportedFormat(code.into()))?;
+    let invalid = || DateTimeError::Invalid {
+        value: value.to_string(),
+        format: code.to_string(),
+    };
+    let named = match layout {
+        Layout::Zoned(_, false) => zone_offset(value),
+        _ => None,
+    };
+    let value = named.as_deref().unwrap_or(value);
+    let period = matches!(layout, Layout::DatePeriod | Layout::DateTimePeriod);
+    let parsed = match layout {
+        Layout::Date(pattern) => NaiveDate::parse_from_str(value, pattern).map(DateTimeValue::Date),
+        Layout::DateTime(pattern) => {
+            NaiveDateTime::parse_from_str(value, pattern).map(DateTimeValue::DateTime)
+        }
+        Layout::Zoned(pattern, _) => {
+            // `%#z` takes the offset with or without minutes
+            DateTime::parse_from_str(value, &format!("{pattern}%#z")).map(DateTimeValue::Zoned)
+        }
+        Layout::Time(pattern) => NaiveTime::parse_from_str(value, pattern).map(DateTimeValue::Time),
+        Layout::DatePeriod => {
+            let (start, end) = split_period(value).ok_or_else(invalid)?;
+            NaiveDate::parse_from_str(start, "%Y%m%d").and_then(|start| {
+                let end = NaiveDate::parse_from_str(end, "%Y%m%d")?;
+                Ok(DateTimeValue::DatePeriod(start, end))
+            })
+        }
+        Layout::DateTimePeriod => {
+            let (start, end) = split_period(value).ok_or_else(invalid)?;
+            NaiveDateTime::parse_from_str(start, "%Y%m%d%H%M").and_then(|start| {
+                let end = NaiveDateTime::parse_from_str(end, "%Y%m%d%H%M")?;
+                Ok(DateTimeValue::DateTimePeriod(start, end))
+            })
+        }
+    };
+    let parsed = parsed.map_err(|_| invalid())?;
+    // chrono accepts shorter numbers, e.g. `2022013` for `%Y%m%d`
+    let canonical = match period {
+        true => value.replacen('-', "", 1),
+        false => value.to_string(),
+    };
+    match format(&parsed, code) {
+        Ok(written) if written == canonical => Ok(parsed),
+        _ => Err(invalid()),
+    }
+}
+
+/// Writes `value` as 2380 with the format `code` (2379).
+pub fn format(value: &DateTimeValue, code: &str) -> Result<String, DateTimeError> {
+    let layout = layout(code).ok_or_else(|| DateTimeError::UnsupportedFormat(code.into()))?;
+    let mismatch = || DateTimeError::Mismatch {
+        format: code.to_string(),
+    };
+    Ok(match (layout, value) {
+        (Layout::Date(pattern), DateTimeValue::Date(date)) => date.format(pattern).to_string(),
+        (Layout::DateTime(pattern), DateTimeValue::DateTime(datetime)) => {
+            datetime.format(pattern).to_string()
+        }
+        (Layout::Zoned(pattern, minutes), DateTimeValue::Zoned(datetime)) => {
+            let offset = datetime.offset().local_minus_utc() / 60;
+            if !minutes && offset % 60 != 0 {
+                return Err(mismatch());
+            }
+            let sign = if offset < 0 { '-' } else { '+' };
+            let (hours, rest) = (offset.abs() / 60, offset.abs() % 60);
+            let zone = match minutes {
+                true => format!("{sign}{hours:02}{rest:02}"),
+                false => format!("{sign}{hours:02}"),
+            };
+            format!("{}{zone}", datetime.format(pattern))
+        }
+        (Layout::Time(pattern), DateTimeValue::Time(time)) => time.format(pattern).to_string(),
+        (Layout::DatePeriod, DateTimeValue::DatePeriod(start, end)) => {
+            format!("{}{}", start.format("%Y%m%d"), end.format("%Y%m%d"))
+        }
+        (Layout::DateTimePeriod, DateTimeValue::DateTimePeriod(start, end)) => {
+            format!("{}{}", start.format("%Y%m%d%H%M"), end.format("%Y%m%d%H%M"))
+        }
+        _ => return Err(mismatch()),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn roundtrip(value: &str, format_code: &str) -> DateTimeValue {
+        let parsed = parse(value, format_code).unwrap();
+        assert_eq!(format(&parsed, format_code).unwrap(), value);
+        parsed
+    }
+
+    #[test]
+    fn format_codes() {
+        let date = NaiveDate::from_ymd_opt(2022, 1, 31).unwrap();
+        let datetime = date.and_hms_opt(7, 10, 5).unwrap();
+        assert_eq!(roundtrip("310122", "2"), DateTimeValue::Date(date));
+        assert_eq!(roundtrip("013122", "3"), DateTimeValue::Date(date));
+        assert_eq!(roundtrip("31012022", "4"), DateTimeValue::Date(date));
+        assert_eq!(roundtrip("220131", "101"), DateTimeValue::Date(date));
+        assert_eq!(roundtrip("20220131", "102"), DateTimeValue::Date(date));
+        assert_eq!(
+            roundtrip("2201310710", "201"),
+            DateTimeValue::DateTime(date.and_hms_opt(7, 10, 0).unwrap())
+        );
+        assert_eq!(
+            roundtrip("220131071005", "202"),
+            DateTimeValue::DateTime(datetime)
+        );
+        assert_eq!(
+            roundtrip("20220131071005", "204"),
+            DateTimeValue::DateTime(datetime)
+        );
+        assert_eq!(
+            roundtrip("0710", "401"),
+            DateTimeValue::Time(NaiveTime::from_hms_opt(7, 10, 0).unwrap())
+        );
+        assert_eq!(
+            roundtrip("2022013120220228", "718"),
+            DateTimeValue::DatePeriod(date, NaiveDate::from_ymd_opt(2022, 2, 28).unwrap())
+        );
+        roundtrip("202201310710202202280000", "719");
+        // the hyphen of a period is accepted
+        assert_eq!(
+            parse("20220131-20220228", "718"),
+            parse("2022013120220228", "718")
+        );
+    }
+
+    #[test]
+    fn time_zones() {
+        let DateTimeValue::Zoned(zoned) = roundtrip("202201310710-0530", "205") else {
+            panic!("expected a zoned datetime");
+        };
+        assert_eq!(zoned.offset().local_minus_utc(), -(5 * 3600 + 30 * 60));
+        let DateTimeValue::Zoned(zoned) = roundtrip("20220131071005+01", "304") else {
+            panic!("expected a zoned datetime");
+        };
+        assert_eq!(zoned.to_rfc3339(), "2022-01-31T07:10:05+01:00");
+        // alphabetic time zones are offset zero
+        let utc = parse("202201010021+00", "303").unwrap();
+        assert_eq!(parse("202201010021UTC", "303"), Ok(utc));
+        assert_eq!(parse("202201010021GMT", "303"), Ok(utc));
+        assert_eq!(
+            parse("20220101002105Z", "304"),
+            parse("20220101002105+00", "304")
+        );
+        assert!(parse("202201010021CET", "303").is_err());
+        assert!(parse("202201010021UTC", "205").is_err());
+        // 303 has no minutes in its offset
+        let half_hour = "202201310710+0530";
+        let value = parse(half_hour, "205").unwrap();
+        assert_eq!(
+            format(&value, "303"),
+            Err(DateTimeError::Mismatch {
+                format: "303".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_values() {
+        assert_eq!(
+            parse("20221301", "102").unwrap_err().to_string(),
+            r#"value "20221301" does not match format code 102"#
+        );
+        assert!(parse("2022013", "102").is_err());
+        assert!(parse("202201311", "102").is_err());
+        assert_eq!(
+            parse("3", "802"),
+            Err(DateTimeError::UnsupportedFormat("802".to_string()))
+        );
+        let date = DateTimeValue::Date(NaiveDate::from_ymd_opt(2022, 1, 31).unwrap());
+        assert!(format(&date, "203").is_err());
+    }
+}
@@ -0,0 +1,90 @@
+use crate::util::{delimiters, parse_raw_line, parse_una, with_delimiters, Error};
+use nom::{combinator::opt, error::ErrorKind, Parser as _};
+use std::fmt;
+
+/// Error of a failed parse, pointing to the segment and element which caused it.
+///
+/// ```text
+/// segment 7 (DTM), element DTM/010/010: unknown `_2005` value 999
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseError {
+    /// position of the segment in the interchange, starting with 1 at UNB (UNA is not counted)
+    pub segment_index: usize,
+    /// tag of the segment, empty if the input ended
+    pub segment_tag: String,
+    /// position of the element, e.g. `LOC/020/010`
+    pub element: Option<String>,
+    /// the offending value, boxed like `expected` to keep the error small
+    pub value: Option<Box<str>>,
+    /// name of the code list, element or value which was expected
+    pub expected: Option<Box<str>>,
+    pub msg: String,
+}
+
+impl ParseError {
+    /// Creates the error from the result of a parser which was called with `input`.
+    pub fn new(input: &str, err: nom::Err<Error<&str>>) -> ParseError {
+        let err = match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => e,
+            nom::Err::Incomplete(_) => Error::new(&input[input.len()..], ErrorKind::Complete),
+        };
+        // the error input is a slice of `input`, anything else points to the end
+        let start = input.as_ptr() as usize;
+        let offset = (err.input.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| *offset <= input.len())
+            .unwrap_or(input.len());
+        let (segment_index, segment_tag) = locate_segment(input, offset);
+        ParseError {
+            segment_index,
+            segment_tag,
+            element: Some(err.path.join("/")).filter(|p| !p.is_empty()),
+            value: err.value.clone(),
+            expected: err.expected.clone(),
+            msg: err.reason(),
+        }
+    }
+}
+
+/// Finds index and tag of the segment which contains `offset`.
+fn locate_segment(input: &str, offset: usize) -> (usize, String) {
+    let (mut rest, una) = opt(parse_una).parse(input).unwrap_or((input, None));
+    with_delimiters(una.unwrap_or_else(delimiters), || {
+        let d = delimiters();
+        let mut index = 1;
+        loop {
+            if rest.trim().is_empty() {
+                return (index, String::new());
+            }
+            let tag = rest
+                .split([d.element, d.segment])
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_string();
+            match parse_raw_line(rest) {
+                Ok((next, _)) if input.len() - next.len() <= offset => {
+                    rest = next;
+                    index += 1;
+                }
+                _ => return (index, tag),
+            }
+        }
+    })
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "segment {}", self.segment_index)?;
+        if !self.segment_tag.is_empty() {
+            write!(f, " ({})", self.segment_tag)?;
+        }
+        if let Some(element) = &self.element {
+            write!(f, ", element {element}")?;
+        }
+        write!(f, ": {}", self.msg)
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -0,0 +1,803 @@
+use super::{code, is_tag, UNKNOWN};
+use crate::raw::RawSegment;
+use crate::util::{delimiters, parse_una, with_delimiters, Parser};
+use crate::ParseError;
+use nom::{combinator::opt, Parser as _};
+use serde::de::{
+    self, value::StrDeserializer, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer,
+    SeqAccess, VariantAccess, Visitor,
+};
+use serde::forward_to_deserialize_any;
+use std::{collections::HashMap, fmt};
+
+impl de::Error for ParseError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        ParseError {
+            msg: msg.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Deserializes `T` from the segments of `input`, which may start with UNA.
+///
+/// The segments have to follow the layout of `T` without gaps or additional segments.
+/// Enums of messages like `AnyMessage` can only be serialized, read the message type instead.
+pub fn from_str<T: DeserializeOwned>(input: &str) -> Result<T, ParseError> {
+    let mut schema = Schema::new();
+    T::deserialize(Probe {
+        schema: &mut schema,
+        first: &mut None,
+    })?;
+    let (rest, una) = opt(parse_una)
+        .parse(input)
+        .map_err(|e| ParseError::new(input, e))?;
+    with_delimiters(una.unwrap_or_else(delimiters), || {
+        let mut segments = vec![];
+        // the UNA segment only exists if `T` has a field for it
+        let una = una.is_some() && schema.values().flatten().any(|tag| *tag == Some("UNA"));
+        if una {
+            let characters = input[..input.len() - rest.len()].trim_start();
+            segments.push(RawSegment {
+                tag: "UNA".to_string(),
+                elements: characters
+                    .chars()
+                    .skip(3)
+                    .take(6)
+                    .map(|c| vec![c.to_string()])
+                    .collect(),
+            });
+        }
+        let mut rest = rest.trim_start();
+        while !rest.is_empty() {
+            let (next, segment) = RawSegment::parse(rest).map_err(|e| ParseError::new(input, e))?;
+            segments.push(segment);
+            rest = next.trim_start();
+        }
+        let mut state = State {
+            segments,
+            position: 0,
+            skipped: usize::from(una),
+            schema,
+        };
+        let value = T::deserialize(Segments {
+            state: &mut state,
+            expected: None,
+        })?;
+        match state.segments.get(state.position) {
+            Some(segment) => Err(ParseError {
+                segment_index: state.index(),
+                segment_tag: segment.tag.clone(),
+                msg: "unexpected segment".to_string(),
+                ..Default::default()
+            }),
+            None => Ok(value),
+        }
+    })
+}
+
+/// Tags of the first segment of each field of the messages and segment groups.
+///
+/// Keyed by the name and the fields which the derive of `Deserialize` passes for the struct.
+type Schema = HashMap<(&'static str, &'static [&'static str]), Vec<Option<&'static str>>>;
+
+/// Reads the segments of a message.
+struct State {
+    segments: Vec<RawSegment>,
+    position: usize,
+    /// segments which are not counted by the error position, i.e. UNA
+    skipped: usize,
+    schema: Schema,
+}
+
+impl State {
+    fn tag(&self) -> Option<&str> {
+        self.segments.get(self.position).map(|s| s.tag.as_str())
+    }
+
+    /// position of the current segment for errors, see [`ParseError::segment_index`]
+    fn index(&self) -> usize {
+        (self.position + 1).saturating_sub(self.skipped)
+    }
+}
+
+/// Prefixes the element position of `err` with `part`.
+fn within(mut err: ParseError, part: &str) -> ParseError {
+    err.element = Some(match err.element {
+        Some(path) => format!("{part}/{path}"),
+        None => part.to_string(),
+    });
+    err
+}
+
+/// Position of a data element or component, `010` for the first.
+fn position(index: usize) -> String {
+    format!("{:03}", (index + 1) * 10)
+}
+
+fn unexpected<T>(what: &str) -> Result<T, ParseError> {
+    Err(de::Error::custom(format!(
+        "{what} can not be read from EDIFACT"
+    )))
+}
+
+/// Deserializer of a message, segment group or segment.
+struct Segments<'a> {
+    state: &'a mut State,
+    /// the first segment of the current field
+    expected: Option<&'static str>,
+}
+
+impl<'de> de::Deserializer<'de> for Segments<'_> {
+    type Error = ParseError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, ParseError> {
+        unexpected("a value outside of a segment")
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+        match self.expected.is_some() && self.state.tag() == self.expected {
+            true => visitor.visit_some(self),
+            false => visitor.visit_none(),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ParseError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+        visitor.visit_seq(Repeated {
+            state: self.state,
+            expected: self.expected,
+        })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ParseError> {
+        let state = self.state;
+        if !is_tag(name) {
+            let Some(tags) = state.schema.get(&(name, fields)).cloned() else {
+                return unexpected(&format!("`{name}`"));
+            };
+            return visitor.visit_seq(Fields {
+                state,
+                tags: tags.into_iter(),
+            });
+        }
+        if state.tag() != Some(name) {
+            return Err(ParseError {
+                segment_index: state.index(),
+                segment_tag: state.tag().unwrap_or_default().to_string(),
+                expected: Some(name.into()),
+                msg: format!("missing segment {name}"),
+                ..Default::default()
+            });
+        };
+        let index = state.index();
+        state.position += 1;
+        let segment = &state.segments[state.position - 1];
+        let located = |err: ParseError| ParseError {
+            segment_index: index,
+            segment_tag: name.to_string(),
+            ..within(err, name)
+        };
+        let value = visitor
+            .visit_seq(Elements {
+                elements: &segment.elements,
+                index: 0,
+                count: fields.len(),
+            })
+            .map_err(located)?;
+        match segment
+            .elements
+            .iter()
+            .skip(fields.len())
+            .position(|e| !is_empty(e))
+        {
+            Some(extra) => Err(located(within(
+                de::Error::custom("unexpected data element"),
+                &position(fields.len() + extra),
+            ))),
+            None => Ok(value),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        _: &'static [&'static str],
+        _: V,
+    ) -> Result<V::Value, ParseError> {
+        unexpected(&format!("`{name}`"))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit_struct tuple tuple_struct map identifier ignored_any
+    }
+}
+
+/// The fields of a message or segment group.
+struct Fields<'a> {
+    state: &'a mut State,
+    tags: std::vec::IntoIter<Option<&'static str>>,
+}
+
+impl<'de> SeqAccess<'de> for Fields<'_> {
+    type Error = ParseError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, ParseError> {
+        let Some(expected) = self.tags.next() else {
+            return Ok(None);
+        };
+        seed.deserialize(Segments {
+            state: self.state,
+            expected,
+        })
+        .map(Some)
+    }
+}
+
+/// A repeated segment or segment group, as long as its first segment follows.
+struct Repeated<'a> {
+    state: &'a mut State,
+    expected: Option<&'static str>,
+}
+
+impl<'de> SeqAccess<'de> for Repeated<'_> {
+    type Error = ParseError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, ParseError> {
+        if self.expected.is_none() || self.state.tag() != self.expected {
+            return Ok(None);
+        }
+        seed.deserialize(Segments {
+            state: self.state,
+            expected: self.expected,
+        })
+        .map(Some)
+    }
+}
+
+fn is_empty(components: &[String]) -> bool {
+    components.iter().all(String::is_empty)
+}
+
+/// The data elements of a segment, or the components of a composite.
+struct Elements<'a> {
+    elements: &'a [Vec<String>],
+    index: usize,
+    count: usize,
+}
+
+impl<'de> SeqAccess<'de> for Elements<'_> {
+    type Error = ParseError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, ParseError> {
+        if self.index == self.count {
+            return Ok(None);
+        }
+        let index = self.index;
+        self.index += 1;
+        let components = self.elements.get(index).map_or(&[][..], Vec::as_slice);
+        seed.deserialize(Element(components))
+            .map(Some)
+            .map_err(|e| within(e, &position(index)))
+    }
+}
+
+/// A data element with its components.
+struct Element<'a>(&'a [String]);
+
+impl<'a> Element<'a> {
+    /// The value of a simple data element.
+    fn value(&self) -> Result<Value<'a>, ParseError> {
+        match self.0.iter().skip(1).position(|c| !c.is_empty()) {
+            Some(extra) => Err(within(
+                de::Error::custom("unexpected component"),
+                &position(extra + 1),
+            )),
+            None => Ok(Value(self.0.first().map_or("", String::as_str))),
+        }
+    }
+}
+
+macro_rules! simple_element {
+    ($($method:ident)*) => {
+        $(fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+            self.value()?.$method(visitor)
+        })*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Element<'_> {
+    type Error = ParseError;
+
+    simple_element! {
+        deserialize_any deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32
+        deserialize_i64 deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+        deserialize_f32 deserialize_f64 deserialize_char deserialize_str deserialize_string
+        deserialize_bytes deserialize_byte_buf deserialize_unit deserialize_seq
+        deserialize_map deserialize_identifier deserialize_ignored_any
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+        match is_empty(self.0) {
+            true => visitor.visit_none(),
+            false => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ParseError> {
+        self.value()?.deserialize_unit_struct(name, visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ParseError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _: usize, _: V) -> Result<V::Value, ParseError> {
+        unexpected("a tuple")
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: usize,
+        _: V,
+    ) -> Result<V::Value, ParseError> {
+        unexpected("a tuple struct")
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ParseError> {
+        let components = self.0.iter().map(|c| vec![c.clone()]).collect::<Vec<_>>();
+        let value = visitor.visit_seq(Elements {
+            elements: &components,
+            index: 0,
+            count: fields.len(),
+        })?;
+        match self.0.iter().skip(fields.len()).position(|c| !c.is_empty()) {
+            Some(extra) => Err(within(
+                de::Error::custom("unexpected component"),
+                &position(fields.len() + extra),
+            )),
+            None => Ok(value),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ParseError> {
+        self.value()?.deserialize_enum(name, variants, visitor)
+    }
+}
+
+/// A simple data element or component.
+struct Value<'a>(&'a str);
+
+impl Value<'_> {
+    fn invalid(&self, expected: &str) -> ParseError {
+        ParseError {
+            value: Some(self.0.into()),
+            expected: Some(expected.into()),
+            msg: format!("invalid {expected} {}", self.0),
+            ..Default::default()
+        }
+    }
+}
+
+macro_rules! number {
+    ($($method:ident $visit:ident $ty:ty)*) => {
+        $(fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+            let value = self.0.replace(delimiters().decimal, ".");
+            match value.parse::<$ty>() {
+                Ok(number) => visitor.$visit(number),
+                Err(_) => Err(self.invalid(stringify!($ty))),
+            }
+        })*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Value<'_> {
+    type Error = ParseError;
+
+    number! {
+        deserialize_i8 visit_i64 i64
+        deserialize_i16 visit_i64 i64
+        deserialize_i32 visit_i64 i64
+        deserialize_i64 visit_i64 i64
+        deserialize_u8 visit_u64 u64
+        deserialize_u16 visit_u64 u64
+        deserialize_u32 visit_u64 u64
+        deserialize_u64 visit_u64 u64
+        deserialize_f32 visit_f64 f64
+        deserialize_f64 visit_f64 f64
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+        visitor.visit_str(self.0)
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, _: V) -> Result<V::Value, ParseError> {
+        unexpected("`bool`")
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+        let mut chars = self.0.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => Err(self.invalid("char")),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+        match self.0.is_empty() {
+            true => visitor.visit_none(),
+            false => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        _: &'static [&'static str],
+        _: V,
+    ) -> Result<V::Value, ParseError> {
+        unexpected(&format!("`{name}` within a composite"))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ParseError> {
+        match variants.iter().find(|v| code(v) == self.0) {
+            Some(variant) => {
+                let variant: StrDeserializer<ParseError> = variant.into_deserializer();
+                visitor.visit_enum(variant)
+            }
+            None if variants.contains(&UNKNOWN) => visitor.visit_enum(self),
+            None => Err(ParseError {
+                value: Some(self.0.into()),
+                expected: Some(name.into()),
+                msg: format!("unknown `{name}` value {}", self.0),
+                ..Default::default()
+            }),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 str string bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map identifier ignored_any
+    }
+}
+
+/// A code which is not in the code list, see `lenient-codes`.
+impl<'de, 'a> EnumAccess<'de> for Value<'a> {
+    type Error = ParseError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self), ParseError> {
+        let variant: StrDeserializer<ParseError> = UNKNOWN.into_deserializer();
+        Ok((seed.deserialize(variant)?, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for Value<'_> {
+    type Error = ParseError;
+
+    fn unit_variant(self) -> Result<(), ParseError> {
+        unexpected("a unit variant")
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, ParseError> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _: usize, _: V) -> Result<V::Value, ParseError> {
+        unexpected("a tuple variant")
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _: &'static [&'static str],
+        _: V,
+    ) -> Result<V::Value, ParseError> {
+        unexpected("a struct variant")
+    }
+}
+
+/// Collects the [`Schema`] by deserializing a value with one of each repeated segment,
+/// the first variant of each enum and empty data elements.
+struct Probe<'a> {
+    schema: &'a mut Schema,
+    /// tag of the first segment of the value
+    first: &'a mut Option<&'static str>,
+}
+
+impl<'de> de::Deserializer<'de> for Probe<'_> {
+    type Error = ParseError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, ParseError> {
+        unexpected("a value outside of a segment")
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ParseError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+        let mut access = ProbeFields {
+            schema: self.schema,
+            firsts: vec![],
+            count: 1,
+        };
+        let value = visitor.visit_seq(&mut access)?;
+        *self.first = access.firsts.first().copied().flatten();
+        Ok(value)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ParseError> {
+        if is_tag(name) {
+            *self.first = Some(name);
+            return visitor.visit_seq(ProbeElements(fields.len()));
+        }
+        let mut access = ProbeFields {
+            schema: &mut *self.schema,
+            firsts: vec![],
+            count: fields.len(),
+        };
+        let value = visitor.visit_seq(&mut access)?;
+        let firsts = access.firsts;
+        // a segment group starts with its trigger segment
+        *self.first = firsts.first().copied().flatten();
+        self.schema.insert((name, fields), firsts);
+        Ok(value)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ParseError> {
+        visitor.visit_enum(ProbeVariant {
+            variant: variants.first().copied().unwrap_or_default(),
+            probe: self,
+        })
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit_struct tuple tuple_struct map identifier ignored_any
+    }
+}
+
+/// Probes `count` fields of a message or segment group, or one item of a `Vec`.
+struct ProbeFields<'a> {
+    schema: &'a mut Schema,
+    firsts: Vec<Option<&'static str>>,
+    count: usize,
+}
+
+impl<'de> SeqAccess<'de> for ProbeFields<'_> {
+    type Error = ParseError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, ParseError> {
+        if self.firsts.len() == self.count {
+            return Ok(None);
+        }
+        let mut first = None;
+        let value = seed.deserialize(Probe {
+            schema: self.schema,
+            first: &mut first,
+        })?;
+        self.firsts.push(first);
+        Ok(Some(value))
+    }
+}
+
+/// The first variant of an enum of messages.
+struct ProbeVariant<'a> {
+    variant: &'static str,
+    probe: Probe<'a>,
+}
+
+impl<'de, 'a> EnumAccess<'de> for ProbeVariant<'a> {
+    type Error = ParseError;
+    type Variant = Probe<'a>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Probe<'a>), ParseError> {
+        let variant: StrDeserializer<ParseError> = self.variant.into_deserializer();
+        Ok((seed.deserialize(variant)?, self.probe))
+    }
+}
+
+impl<'de> VariantAccess<'de> for Probe<'_> {
+    type Error = ParseError;
+
+    fn unit_variant(self) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, ParseError> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _: usize, _: V) -> Result<V::Value, ParseError> {
+        unexpected("a tuple variant")
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ParseError> {
+        de::Deserializer::deserialize_struct(self, "", fields, visitor)
+    }
+}
+
+/// Empty data elements of a segment or components of a composite.
+struct ProbeElements(usize);
+
+impl<'de> SeqAccess<'de> for ProbeElements {
+    type Error = ParseError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, ParseError> {
+        if self.0 == 0 {
+            return Ok(None);
+        }
+        self.0 -= 1;
+        seed.deserialize(ProbeValue).map(Some)
+    }
+}
+
+/// Some value of a data element.
+struct ProbeValue;
+
+macro_rules! probe_number {
+    ($($method:ident)*) => {
+        $(fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+            visitor.visit_u64(0)
+        })*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for ProbeValue {
+    type Error = ParseError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+        visitor.visit_str("")
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+        visitor.visit_bool(false)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+        visitor.visit_char(' ')
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+        visitor.visit_none()
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+        visitor.visit_seq(ProbeElements(0))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ParseError> {
+        visitor.visit_seq(ProbeElements(fields.len()))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ParseError> {
+        let variant: StrDeserializer<ParseError> = variants
+            .first()
+            .copied()
+            .unwrap_or_default()
+            .into_deserializer();
+        visitor.visit_enum(variant)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+        visitor.visit_u64(0)
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 str string bytes byte_buf unit_struct newtype_struct tuple
+        tuple_struct map identifier ignored_any
+    }
+
+    probe_number! {
+        deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64 deserialize_u8
+        deserialize_u16 deserialize_u32 deserialize_f32 deserialize_f64
+    }
+}
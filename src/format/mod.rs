@@ -0,0 +1,62 @@
+//! Serde data format for the EDIFACT syntax.
+//!
+//! Works with the `Serialize` and `Deserialize` derives of the bindings and of any other
+//! struct which follows their layout, e.g. the subset of a message implementation guide:
+//!
+//! * a struct named by a segment tag (three upper case letters, e.g. `DTM` or a struct
+//!   with `#[serde(rename = "DTM")]`) is a segment, its fields are the data elements
+//! * a struct within a segment is a composite, its fields are the components
+//! * any other struct is a message or segment group, its fields are segments, groups,
+//!   `Option` or `Vec` of them
+//! * code lists are unit variants, named by their code with an optional `_` prefix, and
+//!   an optional `Unknown(String)` variant for any other code
+//!
+//! The fields are taken by position, their names are not used.
+//!
+//! ```
+//! use edifact_types::format;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct DTM {
+//!     date: Period,
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Period {
+//!     qualifier: String,
+//!     value: Option<String>,
+//!     format: Option<String>,
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Dates {
+//!     dtm: Vec<DTM>,
+//! }
+//!
+//! let dates: Dates = format::from_str("DTM+137:20220101:102'DTM+132:20220102:102'").unwrap();
+//! assert_eq!(dates.dtm[1].date.value.as_deref(), Some("20220102"));
+//! assert_eq!(
+//!     format::to_string(&dates).unwrap(),
+//!     "DTM+137:20220101:102'\nDTM+132:20220102:102'"
+//! );
+//! ```
+
+mod de;
+mod ser;
+
+pub use de::from_str;
+pub use ser::{to_string, SerializeError, Serializer};
+
+/// A segment tag like `DTM`, structs with other names are composites or segment groups.
+fn is_tag(name: &str) -> bool {
+    name.len() == 3 && name.bytes().all(|b| b.is_ascii_uppercase())
+}
+
+/// Variant of the codes which are not in the code list.
+const UNKNOWN: &str = "Unknown";
+
+/// The code of a code list variant, `_137` is written as `137`.
+fn code(variant: &str) -> &str {
+    variant.strip_prefix('_').unwrap_or(variant)
+}
@@ -0,0 +1,458 @@
+use super::{code, is_tag, UNKNOWN};
+use crate::util::{delimiters, escape, parse_una, with_delimiters, Delimiters};
+use serde::ser::{self, Impossible, Serialize};
+use std::fmt;
+
+/// Error of [`to_string`], the value has a shape which can not be written as EDIFACT.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerializeError {
+    pub msg: String,
+}
+
+impl fmt::Display for SerializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.msg)
+    }
+}
+
+impl std::error::Error for SerializeError {}
+
+impl ser::Error for SerializeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        SerializeError {
+            msg: msg.to_string(),
+        }
+    }
+}
+
+fn unsupported<T>(what: &str) -> Result<T, SerializeError> {
+    Err(SerializeError {
+        msg: format!("{what} can not be written as EDIFACT"),
+    })
+}
+
+/// Serializes `value` with the current delimiters, one segment per line.
+pub fn to_string<T: Serialize + ?Sized>(value: &T) -> Result<String, SerializeError> {
+    let mut serializer = Serializer::new();
+    value.serialize(&mut serializer)?;
+    Ok(serializer.into_inner())
+}
+
+/// Serializer of messages, segment groups and segments.
+///
+/// A UNA segment sets the delimiters of the following segments.
+#[derive(Debug, Clone)]
+pub struct Serializer {
+    delimiters: Delimiters,
+    segments: Vec<String>,
+}
+
+impl Default for Serializer {
+    fn default() -> Self {
+        Serializer::new()
+    }
+}
+
+impl Serializer {
+    /// Creates a serializer with the current delimiters.
+    pub fn new() -> Self {
+        Serializer {
+            delimiters: delimiters(),
+            segments: vec![],
+        }
+    }
+
+    /// The serialized segments, one per line.
+    pub fn into_inner(self) -> String {
+        self.segments.join("\n")
+    }
+}
+
+macro_rules! outside_segment {
+    ($($method:ident: $ty:ty),*) => {
+        $(fn $method(self, _: $ty) -> Result<(), SerializeError> {
+            unsupported(concat!("`", stringify!($ty), "` outside of a segment"))
+        })*
+    };
+}
+
+impl<'a> ser::Serializer for &'a mut Serializer {
+    type Ok = ();
+    type Error = SerializeError;
+    type SerializeSeq = Self;
+    type SerializeTuple = Impossible<(), SerializeError>;
+    type SerializeTupleStruct = Impossible<(), SerializeError>;
+    type SerializeTupleVariant = Impossible<(), SerializeError>;
+    type SerializeMap = Impossible<(), SerializeError>;
+    type SerializeStruct = StructSerializer<'a>;
+    type SerializeStructVariant = Impossible<(), SerializeError>;
+
+    outside_segment!(
+        serialize_bool: bool, serialize_i8: i8, serialize_i16: i16, serialize_i32: i32,
+        serialize_i64: i64, serialize_u8: u8, serialize_u16: u16, serialize_u32: u32,
+        serialize_u64: u64, serialize_f32: f32, serialize_f64: f64, serialize_char: char,
+        serialize_str: &str, serialize_bytes: &[u8]
+    );
+
+    fn serialize_none(self) -> Result<(), SerializeError> {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), SerializeError> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), SerializeError> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<(), SerializeError> {
+        unsupported("a code outside of a segment")
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        // e.g. `AnyMessage::IFTSTA`
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self, SerializeError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, SerializeError> {
+        unsupported("a tuple")
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, SerializeError> {
+        unsupported("a tuple struct")
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, SerializeError> {
+        unsupported("a tuple variant")
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, SerializeError> {
+        unsupported("a map")
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        _: usize,
+    ) -> Result<StructSerializer<'a>, SerializeError> {
+        let segment = is_tag(name).then(|| (name, vec![]));
+        Ok(StructSerializer {
+            serializer: self,
+            segment,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, SerializeError> {
+        unsupported("a struct variant")
+    }
+}
+
+impl ser::SerializeSeq for &mut Serializer {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), SerializeError> {
+        Ok(())
+    }
+}
+
+/// A segment with its data elements, or a message or segment group.
+pub struct StructSerializer<'a> {
+    serializer: &'a mut Serializer,
+    /// tag and data elements of a segment
+    segment: Option<(&'static str, Vec<String>)>,
+}
+
+impl ser::SerializeStruct for StructSerializer<'_> {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        match &mut self.segment {
+            Some((tag, elements)) => {
+                // the service characters of UNA are not escaped
+                let serializer = ValueSerializer {
+                    composite: false,
+                    escape: *tag != "UNA",
+                };
+                let element =
+                    with_delimiters(self.serializer.delimiters, || value.serialize(serializer))?;
+                elements.push(element);
+                Ok(())
+            }
+            None => value.serialize(&mut *self.serializer),
+        }
+    }
+
+    fn end(self) -> Result<(), SerializeError> {
+        let Some((tag, mut elements)) = self.segment else {
+            return Ok(());
+        };
+        let d = self.serializer.delimiters;
+        if tag == "UNA" {
+            // the service characters follow the tag without separators
+            let una = format!("UNA{}", elements.concat());
+            let (_, delimiters) = parse_una(&una).map_err(|_| SerializeError {
+                msg: format!("invalid {una}"),
+            })?;
+            self.serializer.delimiters = delimiters;
+            self.serializer.segments.push(una);
+            return Ok(());
+        }
+        // skip empty trailing elements, like the segments of the bindings
+        while elements.last().is_some_and(|e| e.is_empty()) {
+            elements.pop();
+        }
+        if !elements.is_empty() {
+            let separator = d.element.to_string();
+            let segment = format!("{tag}{separator}{}{}", elements.join(&separator), d.segment);
+            self.serializer.segments.push(segment);
+        }
+        Ok(())
+    }
+}
+
+/// Serializes a data element, or a component within a composite.
+#[derive(Clone, Copy)]
+struct ValueSerializer {
+    composite: bool,
+    escape: bool,
+}
+
+macro_rules! display_value {
+    ($($method:ident: $ty:ty),*) => {
+        $(fn $method(self, value: $ty) -> Result<String, SerializeError> {
+            Ok(value.to_string())
+        })*
+    };
+}
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = String;
+    type Error = SerializeError;
+    type SerializeSeq = Impossible<String, SerializeError>;
+    type SerializeTuple = Impossible<String, SerializeError>;
+    type SerializeTupleStruct = Impossible<String, SerializeError>;
+    type SerializeTupleVariant = Impossible<String, SerializeError>;
+    type SerializeMap = Impossible<String, SerializeError>;
+    type SerializeStruct = CompositeSerializer;
+    type SerializeStructVariant = Impossible<String, SerializeError>;
+
+    display_value!(
+        serialize_i8: i8, serialize_i16: i16, serialize_i32: i32, serialize_i64: i64,
+        serialize_u8: u8, serialize_u16: u16, serialize_u32: u32, serialize_u64: u64
+    );
+
+    fn serialize_bool(self, _: bool) -> Result<String, SerializeError> {
+        unsupported("`bool`")
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<String, SerializeError> {
+        self.serialize_f64(value.into())
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<String, SerializeError> {
+        Ok(value
+            .to_string()
+            .replace('.', &delimiters().decimal.to_string()))
+    }
+
+    fn serialize_char(self, value: char) -> Result<String, SerializeError> {
+        self.serialize_str(value.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<String, SerializeError> {
+        Ok(match self.escape {
+            true => escape(value),
+            false => value.to_string(),
+        })
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<String, SerializeError> {
+        unsupported("bytes")
+    }
+
+    fn serialize_none(self) -> Result<String, SerializeError> {
+        Ok(String::new())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<String, SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<String, SerializeError> {
+        Ok(String::new())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<String, SerializeError> {
+        Ok(String::new())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<String, SerializeError> {
+        self.serialize_str(code(variant))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<String, SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<String, SerializeError> {
+        // a code which is not in the code list, see `lenient-codes`
+        if variant == UNKNOWN {
+            return value.serialize(self);
+        }
+        unsupported("a newtype variant within a segment")
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, SerializeError> {
+        unsupported("a sequence within a segment")
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, SerializeError> {
+        unsupported("a tuple")
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, SerializeError> {
+        unsupported("a tuple struct")
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, SerializeError> {
+        unsupported("a tuple variant")
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, SerializeError> {
+        unsupported("a map")
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        _: usize,
+    ) -> Result<CompositeSerializer, SerializeError> {
+        if self.composite {
+            return unsupported(&format!("`{name}` within a composite"));
+        }
+        Ok(CompositeSerializer { components: vec![] })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, SerializeError> {
+        unsupported("a struct variant")
+    }
+}
+
+/// The components of a composite data element.
+pub struct CompositeSerializer {
+    components: Vec<String>,
+}
+
+impl ser::SerializeStruct for CompositeSerializer {
+    type Ok = String;
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        let component = value.serialize(ValueSerializer {
+            composite: true,
+            escape: true,
+        })?;
+        self.components.push(component);
+        Ok(())
+    }
+
+    fn end(mut self) -> Result<String, SerializeError> {
+        // skip empty trailing components
+        while self.components.last().is_some_and(|c| c.is_empty()) {
+            self.components.pop();
+        }
+        Ok(self.components.join(&delimiters().component.to_string()))
+    }
+}
@@ -0,0 +1,304 @@
+use crate::charset::{self, CharsetViolation};
+use crate::control::Finalize;
+use crate::util::{
+    delimiters, parse_colon_section, parse_line, parse_raw_line, parse_una, peek_segment,
+    with_delimiters, Error, IResult, Parser,
+};
+use crate::validate::{Validate, Violation};
+use crate::ParseError;
+use nom::{combinator::opt, Parser as _};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Interchange of any supported directory
+///
+/// The directory is taken from the message identifier (S009) of the first message,
+/// an interchange with functional groups (UNG after UNB) is a `*Grouped` variant.
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum AnyInterchange {
+    #[cfg(feature = "d00b")]
+    D00B(crate::d00b::Interchange<crate::d00b::AnyMessage>),
+    #[cfg(feature = "d00b")]
+    D00BGrouped(crate::d00b::GroupedInterchange<crate::d00b::AnyMessage>),
+    #[cfg(feature = "d95b")]
+    D95B(crate::d95b::Interchange<crate::d95b::AnyMessage>),
+    #[cfg(feature = "d95b")]
+    D95BGrouped(crate::d95b::GroupedInterchange<crate::d95b::AnyMessage>),
+}
+
+impl fmt::Display for AnyInterchange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "d00b")]
+            AnyInterchange::D00B(interchange) => write!(f, "{interchange}"),
+            #[cfg(feature = "d00b")]
+            AnyInterchange::D00BGrouped(interchange) => write!(f, "{interchange}"),
+            #[cfg(feature = "d95b")]
+            AnyInterchange::D95B(interchange) => write!(f, "{interchange}"),
+            #[cfg(feature = "d95b")]
+            AnyInterchange::D95BGrouped(interchange) => write!(f, "{interchange}"),
+        }
+    }
+}
+
+impl AnyInterchange {
+    /// Serializes the interchange, fails with the characters which are not part of
+    /// the syntax level declared in UNB S001.
+    pub fn to_string_checked(&self) -> Result<String, Vec<CharsetViolation>> {
+        charset::checked(self.to_string())
+    }
+
+    /// Serializes the interchange, replacing the characters which are not part of
+    /// the syntax level declared in UNB S001.
+    pub fn to_string_transliterated(&self) -> String {
+        charset::transliterate(&self.to_string())
+    }
+
+    /// Serializes the interchange with the encoding of the syntax level declared in UNB S001.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Vec<CharsetViolation>> {
+        charset::encode(&self.to_string())
+    }
+}
+
+impl Finalize for AnyInterchange {
+    fn finalize(&mut self) {
+        match self {
+            #[cfg(feature = "d00b")]
+            AnyInterchange::D00B(interchange) => interchange.finalize(),
+            #[cfg(feature = "d00b")]
+            AnyInterchange::D00BGrouped(interchange) => interchange.finalize(),
+            #[cfg(feature = "d95b")]
+            AnyInterchange::D95B(interchange) => interchange.finalize(),
+            #[cfg(feature = "d95b")]
+            AnyInterchange::D95BGrouped(interchange) => interchange.finalize(),
+        }
+    }
+}
+
+impl Validate for AnyInterchange {
+    fn validate(&self) -> Vec<Violation> {
+        match self {
+            #[cfg(feature = "d00b")]
+            AnyInterchange::D00B(interchange) => interchange.validate(),
+            #[cfg(feature = "d00b")]
+            AnyInterchange::D00BGrouped(interchange) => interchange.validate(),
+            #[cfg(feature = "d95b")]
+            AnyInterchange::D95B(interchange) => interchange.validate(),
+            #[cfg(feature = "d95b")]
+            AnyInterchange::D95BGrouped(interchange) => interchange.validate(),
+        }
+    }
+}
+
+/// Parses an interchange without knowing its directory upfront.
+///
+/// Version and release of the first message (S009 `_020` and `_030`) select the directory,
+/// the messages are parsed into the `AnyMessage` of that directory.
+/// Functional groups are parsed into the `GroupedInterchange` of the directory.
+/// The whole input has to be a single interchange.
+pub fn parse_interchange(input: &str) -> Result<AnyInterchange, ParseError> {
+    let (rest, interchange) =
+        parse_any_interchange(input).map_err(|e| ParseError::new(input, e))?;
+    if !rest.trim().is_empty() {
+        let err = Error {
+            expected: Some("end of interchange".into()),
+            ..Error::new(rest, nom::error::ErrorKind::Eof)
+        };
+        return Err(ParseError::new(input, nom::Err::Error(err)));
+    }
+    Ok(interchange)
+}
+
+/// Parses an interchange from bytes, decoded with the encoding of the syntax level
+/// declared in UNB S001 (ISO 8859-1, -2, -5, -7 or UTF-8), see [`charset::decode`].
+pub fn parse_bytes(input: &[u8]) -> Result<AnyInterchange, ParseError> {
+    parse_interchange(&charset::decode(input)?)
+}
+
+fn parse_any_interchange(input: &str) -> IResult<&str, AnyInterchange> {
+    let (rest, una) = opt(parse_una).parse(input)?;
+    let ((version, release), grouped) = with_delimiters(una.unwrap_or_else(delimiters), || {
+        message_version(rest).map(|(_, version)| (version, has_groups(rest)))
+    })?;
+    match (version, release, grouped) {
+        #[cfg(feature = "d00b")]
+        ("D", "00B", false) => crate::d00b::Interchange::parse(input)
+            .map(|(rest, interchange)| (rest, AnyInterchange::D00B(interchange))),
+        #[cfg(feature = "d00b")]
+        ("D", "00B", true) => crate::d00b::GroupedInterchange::parse(input)
+            .map(|(rest, interchange)| (rest, AnyInterchange::D00BGrouped(interchange))),
+        #[cfg(feature = "d95b")]
+        ("D", "95B", false) => crate::d95b::Interchange::parse(input)
+            .map(|(rest, interchange)| (rest, AnyInterchange::D95B(interchange))),
+        #[cfg(feature = "d95b")]
+        ("D", "95B", true) => crate::d95b::GroupedInterchange::parse(input)
+            .map(|(rest, interchange)| (rest, AnyInterchange::D95BGrouped(interchange))),
+        _ => Err(nom::Err::Failure(
+            Error::unexpected_value(
+                release,
+                &format!("{version}:{release}"),
+                "a supported directory",
+            )
+            .within("020")
+            .within("UNH"),
+        )),
+    }
+}
+
+/// Whether UNG follows UNB, i.e. the messages are in functional groups.
+fn has_groups(input: &str) -> bool {
+    let input = input.trim_start();
+    peek_segment(input, "UNB")
+        && parse_raw_line(input).is_ok_and(|(rest, _)| peek_segment(rest.trim_start(), "UNG"))
+}
+
+/// Finds version and release of the first message.
+fn message_version(mut input: &str) -> IResult<&str, (&str, &str)> {
+    while !peek_segment(input, "UNH") {
+        if input.trim().is_empty() {
+            return Err(nom::Err::Failure(Error::missing(input, "UNH")));
+        }
+        (input, _) = parse_raw_line(input)?;
+    }
+    let (_, vars) = parse_line(input, "UNH")?;
+    let (_, s009) = parse_colon_section(vars.get(1).unwrap_or(&""))?;
+    match (s009.get(1), s009.get(2)) {
+        (Some(version), Some(release)) => Ok((input, (version, release))),
+        _ => Err(nom::Err::Failure(
+            Error::missing(input, "S009").within("020").within("UNH"),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "d00b")]
+    #[test]
+    fn parse_interchange_d00b() {
+        let input_str = std::fs::read_to_string("./test-data/d00b_iftsta.edi").unwrap();
+        let obj = parse_interchange(&input_str).unwrap();
+        let AnyInterchange::D00B(interchange) = &obj else {
+            panic!("IFTSTA D00B has to be parsed with d00b");
+        };
+        assert!(matches!(
+            interchange.messages[0],
+            crate::d00b::AnyMessage::IFTSTA(_)
+        ));
+        assert_eq!(format!("{obj}"), input_str.trim());
+    }
+
+    #[cfg(feature = "d00b")]
+    #[test]
+    fn parse_bytes_latin2() {
+        let input_str = "UNB+UNOD:3+SENDER+RECEIVER+200101:1200+1'
+UNH+1+IFTSTA:D:00B:UN'
+BGM+23+1+9'
+NAD+CA+++PRZEDSIĘBIORSTWO ŁÓDŹ'
+UNT+4+1'
+UNZ+1+1'";
+        let (bytes, _, unmappable) = encoding_rs::ISO_8859_2.encode(input_str);
+        assert!(!unmappable);
+        let obj = parse_bytes(&bytes).unwrap();
+        let AnyInterchange::D00B(interchange) = &obj else {
+            panic!("IFTSTA D00B has to be parsed with d00b");
+        };
+        let crate::d00b::AnyMessage::IFTSTA(message) = &interchange.messages[0] else {
+            panic!("IFTSTA expected");
+        };
+        let name = message.segment_group_1[0].nad._040.as_ref().unwrap();
+        assert_eq!(name._010, "PRZEDSIĘBIORSTWO ŁÓDŹ");
+        assert_eq!(obj.to_bytes().unwrap(), bytes.as_ref());
+    }
+
+    #[cfg(feature = "d95b")]
+    #[test]
+    fn parse_interchange_d95b() {
+        let msg = std::fs::read_to_string("./test-data/d95b_coprar.edi").unwrap();
+        let input_str = format!(
+            "UNA:+.? '\nUNB+UNOA:2+SENDER:ZZZ+RECEIVER:ZZZ+121121:0841+638'\n{}\nUNZ+1+638'",
+            msg.trim()
+        );
+        let obj = parse_interchange(&input_str).unwrap();
+        let AnyInterchange::D95B(interchange) = &obj else {
+            panic!("COPRAR D95B has to be parsed with d95b");
+        };
+        assert!(matches!(
+            interchange.messages[0],
+            crate::d95b::AnyMessage::COPRAR(_)
+        ));
+    }
+
+    #[test]
+    fn parse_interchange_unsupported() {
+        let input_str = std::fs::read_to_string("./test-data/d04b_baplie.edi").unwrap();
+        let err = parse_interchange(&input_str).unwrap_err();
+        assert_eq!(err.segment_tag, "UNH");
+        assert_eq!(err.element.as_deref(), Some("UNH/020"));
+        assert_eq!(err.value.as_deref(), Some("D:04B"));
+    }
+
+    #[cfg(all(feature = "d00b", not(feature = "lenient-codes")))]
+    #[test]
+    fn parse_interchange_error_position() {
+        let input_str = "UNB+UNOC:3+SENDER+RECEIVER+200101:1200+1'
+UNH+1+IFTSTA:D:00B:UN'
+BGM+23+1+9'
+DTM+999:20200101:102'
+UNT+4+1'
+UNZ+1+1'";
+        let err = parse_interchange(input_str).unwrap_err();
+        assert_eq!(err.segment_index, 4);
+        assert_eq!(err.segment_tag, "DTM");
+        assert_eq!(err.element.as_deref(), Some("DTM/010/010"));
+        assert_eq!(err.value.as_deref(), Some("999"));
+        assert_eq!(err.expected.as_deref(), Some("_2005"));
+        assert_eq!(
+            err.to_string(),
+            "segment 4 (DTM), element DTM/010/010: unknown `_2005` value 999"
+        );
+    }
+
+    #[cfg(feature = "d00b")]
+    #[test]
+    fn parse_interchange_error_missing_unz() {
+        let input_str = "UNB+UNOC:3+SENDER+RECEIVER+200101:1200+1'
+UNH+1+IFTSTA:D:00B:UN'
+BGM+23+1+9'
+UNT+3+1'
+";
+        let err = parse_interchange(input_str).unwrap_err();
+        assert_eq!(err.segment_index, 5);
+        assert_eq!(err.segment_tag, "");
+    }
+
+    #[cfg(feature = "d00b")]
+    #[test]
+    fn parse_interchange_grouped() {
+        let input_str = "UNB+UNOC:3+SENDER+RECEIVER+200101:1200+1'
+UNG+IFTSTA+SENDER-APP+RECEIVER-APP+200101:1200+17+UN+D:00B'
+UNH+1+IFTSTA:D:00B:UN'
+BGM+23+1+9'
+UNT+3+1'
+UNE+1+17'
+UNZ+1+1'";
+        let obj = parse_interchange(input_str).unwrap();
+        let AnyInterchange::D00BGrouped(interchange) = &obj else {
+            panic!("UNG has to be parsed as functional group");
+        };
+        assert_eq!(interchange.groups[0].messages.len(), 1);
+        assert_eq!(format!("{obj}"), input_str);
+        assert!(obj.validate().is_empty());
+    }
+
+    #[test]
+    fn parse_interchange_missing_unh() {
+        let input_str = "UNB+UNOC:3+SENDER+RECEIVER+200101:1200+1'
+UNZ+0+1'";
+        let err = parse_interchange(input_str).unwrap_err();
+        assert_eq!(err.expected.as_deref(), Some("UNH"));
+        assert_eq!(err.to_string(), "segment 3: missing mandatory `UNH`");
+    }
+}
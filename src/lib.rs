@@ -1,13 +1,32 @@
 #![allow(dead_code)]
 
+pub mod charset;
+pub mod codes;
+pub mod control;
+#[cfg(feature = "chrono")]
+pub mod datetime;
+mod error;
+pub mod format;
+#[cfg(feature = "rust_decimal")]
+pub mod numeric;
+pub mod raw;
+pub mod reader;
 pub mod util;
+pub mod validate;
+#[cfg(feature = "xml")]
+pub mod xml;
+
+pub use error::ParseError;
+
+#[cfg(any(feature = "d00b", feature = "d95b"))]
+mod interchange;
+#[cfg(any(feature = "d00b", feature = "d95b"))]
+pub use interchange::*;
 
 #[cfg(feature = "d00b")]
 pub mod d00b;
 #[cfg(feature = "d95b")]
 pub mod d95b;
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct ParseError {
-    pub msg: String,
-}
+#[cfg(all(test, feature = "d00b", feature = "d95b"))]
+mod test_fuzz;
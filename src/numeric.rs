@@ -0,0 +1,148 @@
+//! Numeric data elements as exact decimals.
+//!
+//! Values are read with a full stop or a comma as decimal mark and written with the decimal
+//! mark of the current delimiters, e.g. the one of UNA within [`crate::util::with_delimiters`].
+//! Following ISO 9735 a minus sign precedes the digits, a plus sign is not allowed and sign and
+//! decimal mark are not counted as digits of the representation.
+//!
+//! ```
+//! # #[cfg(feature = "d00b")]
+//! # {
+//! use edifact_types::d00b::MEA;
+//! use edifact_types::util::{with_delimiters, Delimiters, Parser};
+//! use rust_decimal::Decimal;
+//!
+//! let (_, mut mea) = MEA::parse("MEA+AAE+G+KGM:11740,5'").unwrap();
+//! assert_eq!(mea.measure().unwrap(), Some(Decimal::new(117405, 1)));
+//!
+//! let comma = Delimiters { decimal: ',', ..Default::default() };
+//! with_delimiters(comma, || mea.set_measure("KGM", Decimal::new(-25, 2))).unwrap();
+//! assert_eq!(mea.to_string(), "MEA+AAE+G+KGM:-0,25");
+//! # }
+//! ```
+
+use crate::util::{clean_num, delimiters};
+use crate::validate::Representation;
+use rust_decimal::Decimal;
+use std::fmt;
+
+/// Error of [`parse`] and [`format`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NumericError {
+    /// not a number, e.g. `+5`, `5-` or `1.2.3`
+    Invalid(String),
+    /// more digits than the representation allows
+    TooManyDigits {
+        value: String,
+        representation: Representation,
+    },
+}
+
+impl fmt::Display for NumericError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NumericError::Invalid(value) => write!(f, "value {value:?} is not a number"),
+            NumericError::TooManyDigits {
+                value,
+                representation,
+            } => write!(f, "value {value:?} does not match {representation}"),
+        }
+    }
+}
+
+impl std::error::Error for NumericError {}
+
+/// Reads a numeric value with a full stop or a comma as decimal mark.
+pub fn parse(value: &str) -> Result<Decimal, NumericError> {
+    let invalid = || NumericError::Invalid(value.to_string());
+    let trimmed = value.trim();
+    let (negative, digits) = match trimmed.strip_prefix('-') {
+        Some(digits) => (true, clean_num(digits)),
+        None => (false, clean_num(trimmed)),
+    };
+    // the decimal mark has a digit on both sides
+    let mut parts = digits.split(['.', ',']);
+    let valid = parts.next().is_some_and(is_digits) && parts.all(is_digits);
+    if !valid || digits.matches(['.', ',']).count() > 1 {
+        return Err(invalid());
+    }
+    let number = Decimal::from_str_exact(&digits.replace(',', ".")).map_err(|_| invalid())?;
+    Ok(if negative { -number } else { number })
+}
+
+fn is_digits(part: &str) -> bool {
+    !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Writes `value` with the current decimal mark, without trailing zeros of the fraction.
+///
+/// A fixed length representation like `n3` is filled with leading zeros.
+pub fn format(value: Decimal, representation: &Representation) -> Result<String, NumericError> {
+    let value = value.normalize();
+    let absolute = value.abs().to_string();
+    let (integer, fraction) = absolute.split_once('.').unwrap_or((&absolute, ""));
+    let digits = integer.len() + fraction.len();
+    if digits > representation.max {
+        return Err(NumericError::TooManyDigits {
+            value: value.to_string(),
+            representation: *representation,
+        });
+    }
+    let mut out = String::new();
+    if value.is_sign_negative() {
+        out.push('-');
+    }
+    out.extend(std::iter::repeat_n(
+        '0',
+        representation.min.saturating_sub(digits),
+    ));
+    out.push_str(integer);
+    if !fraction.is_empty() {
+        out.push(delimiters().decimal);
+        out.push_str(fraction);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::validate::RepresentationKind;
+
+    const N5: Representation = Representation {
+        kind: RepresentationKind::Numeric,
+        min: 0,
+        max: 5,
+    };
+
+    #[test]
+    fn parse_numbers() {
+        assert_eq!(parse("11740"), Ok(Decimal::new(11740, 0)));
+        assert_eq!(parse("0011,50"), Ok(Decimal::new(1150, 2)));
+        assert_eq!(parse("-0.5"), Ok(Decimal::new(-5, 1)));
+        for invalid in ["+5", "5-", "1.2.3", ".5", "5.", "", "-", "1 000", "1e3"] {
+            assert_eq!(parse(invalid), Err(NumericError::Invalid(invalid.into())));
+        }
+    }
+
+    #[test]
+    fn format_numbers() {
+        assert_eq!(format(Decimal::new(11740, 0), &N5).unwrap(), "11740");
+        assert_eq!(format(Decimal::new(-1150, 2), &N5).unwrap(), "-11.5");
+        assert_eq!(format(Decimal::new(-0, 2), &N5).unwrap(), "0");
+        // sign and decimal mark are not counted
+        assert_eq!(format(Decimal::new(-12345, 1), &N5).unwrap(), "-1234.5");
+        assert_eq!(
+            format(Decimal::new(123456, 1), &N5)
+                .unwrap_err()
+                .to_string(),
+            r#"value "12345.6" does not match n..5"#
+        );
+        let n3 = Representation {
+            min: 3,
+            max: 3,
+            ..N5
+        };
+        assert_eq!(format(Decimal::new(-5, 0), &n3).unwrap(), "-005");
+    }
+}
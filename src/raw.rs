@@ -0,0 +1,416 @@
+//! Untyped segments, messages and interchanges.
+//!
+//! Reads any syntactically valid EDIFACT, also of messages and directories which have
+//! no bindings in this crate, and serializes it back unchanged. The typed messages and
+//! segments can be parsed from it with `typed`.
+//!
+//! ```
+//! use edifact_types::raw;
+//!
+//! let input = "UNB+UNOA:2+SENDER+RECEIVER+091022:0101+23'UNH+1+BAPLIE:D:04B:UN'BGM++2343+9'UNT+3+1'UNZ+1+23'";
+//! let interchange = raw::parse(input).unwrap();
+//! assert_eq!(interchange.messages[0].segments[1].get(1, 0), Some("2343"));
+//! assert_eq!(interchange.to_string(), input);
+//! ```
+
+use crate::util::{
+    delimiters, escape, parse_colon_section, parse_plus_section, parse_una, peek_segment,
+    take_unescaped, unescape, with_delimiters, Delimiters, Error, IResult, Parser,
+};
+use crate::ParseError;
+use nom::{
+    character::complete::{char, multispace0},
+    error::ErrorKind,
+    Parser as _,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A segment of any kind, the values are unescaped.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct RawSegment {
+    pub tag: String,
+    /// data elements after the tag with their components
+    pub elements: Vec<Vec<String>>,
+}
+
+impl RawSegment {
+    /// Component of a data element, both counted from 0 after the tag.
+    pub fn get(&self, element: usize, component: usize) -> Option<&str> {
+        self.elements
+            .get(element)?
+            .get(component)
+            .map(String::as_str)
+    }
+
+    /// Parses the segment into a typed segment, e.g. `d00b::NAD`.
+    pub fn typed<T>(&self) -> Result<T, ParseError>
+    where
+        T: for<'a> Parser<&'a str, T, Error<&'a str>>,
+    {
+        parse_typed(&format!("{self}{}", delimiters().segment))
+    }
+}
+
+impl<'a> Parser<&'a str, RawSegment, Error<&'a str>> for RawSegment {
+    fn parse(input: &'a str) -> IResult<&'a str, RawSegment> {
+        let d = delimiters();
+        let (rest, line) = take_unescaped(input, &[d.segment], &d)?;
+        let (rest, _) = char(d.segment).parse(rest)?;
+        let (content, tag) = take_unescaped(line, &[d.element], &d)?;
+        if tag.trim().is_empty() {
+            return Err(nom::Err::Error(Error::new(input, ErrorKind::Tag)));
+        }
+        let mut elements = vec![];
+        if let Some(content) = content.strip_prefix(d.element) {
+            let (_, vars) = parse_plus_section(content)?;
+            for var in vars {
+                let (_, components) = parse_colon_section(var)?;
+                elements.push(components.into_iter().map(unescape).collect());
+            }
+        }
+        let segment = RawSegment {
+            tag: tag.to_string(),
+            elements,
+        };
+        Ok((rest, segment))
+    }
+}
+
+impl fmt::Display for RawSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = delimiters();
+        write!(f, "{}", self.tag)?;
+        for element in &self.elements {
+            let components: Vec<String> = element.iter().map(|c| escape(c)).collect();
+            write!(
+                f,
+                "{}{}",
+                d.element,
+                components.join(&d.component.to_string())
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A message of any type, from UNH to UNT.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct RawMessage {
+    pub segments: Vec<RawSegment>,
+}
+
+impl RawMessage {
+    /// Parses the message into a typed message, e.g. `d00b::IFTSTA`.
+    pub fn typed<T>(&self) -> Result<T, ParseError>
+    where
+        T: for<'a> Parser<&'a str, T, Error<&'a str>>,
+    {
+        parse_typed(&self.to_string())
+    }
+}
+
+impl<'a> Parser<&'a str, RawMessage, Error<&'a str>> for RawMessage {
+    fn parse(input: &'a str) -> IResult<&'a str, RawMessage> {
+        let (input, _) = multispace0(input)?;
+        let mut segments = Segments::new(input);
+        let message = segments.message()?;
+        Ok((segments.input, message))
+    }
+}
+
+impl fmt::Display for RawMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_segments(f, &self.segments, "\n")
+    }
+}
+
+/// A functional group of any messages, enclosed by UNG and UNE.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct RawGroup {
+    pub ung: RawSegment,
+    pub messages: Vec<RawMessage>,
+    pub une: RawSegment,
+}
+
+/// An interchange of any messages, from UNA or UNB to UNZ.
+///
+/// Serializes back to its input, as long as the segments are separated the same way
+/// throughout (e.g. one per line or all on one line); other whitespace between segments
+/// is replaced by the first `separator`.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct RawInterchange {
+    /// the service string advice as it is, e.g. `UNA:+.? '`
+    pub una: Option<String>,
+    pub unb: RawSegment,
+    /// functional groups, empty if the messages are not grouped
+    pub groups: Vec<RawGroup>,
+    pub messages: Vec<RawMessage>,
+    pub unz: RawSegment,
+    /// whitespace between two segments, e.g. a line break
+    pub separator: String,
+}
+
+impl RawInterchange {
+    /// Delimiters of the interchange, set by UNA.
+    pub fn delimiters(&self) -> Delimiters {
+        self.una
+            .as_deref()
+            .and_then(|una| parse_una(una).ok())
+            .map_or_else(delimiters, |(_, d)| d)
+    }
+
+    /// Parses the interchange into a typed interchange, e.g. `d00b::Interchange<d00b::IFTSTA>`.
+    pub fn typed<T>(&self) -> Result<T, ParseError>
+    where
+        T: for<'a> Parser<&'a str, T, Error<&'a str>>,
+    {
+        parse_typed(&self.to_string())
+    }
+}
+
+impl<'a> Parser<&'a str, RawInterchange, Error<&'a str>> for RawInterchange {
+    fn parse(input: &'a str) -> IResult<&'a str, RawInterchange> {
+        let mut output = RawInterchange::default();
+        let mut delimiters = delimiters();
+        let mut rest = input;
+        if input.starts_with("UNA") {
+            let (_, d) = parse_una(input)?;
+            let una: String = input.chars().take(9).collect();
+            rest = &input[una.len()..];
+            output.una = Some(una);
+            delimiters = d;
+        }
+        with_delimiters(delimiters, || {
+            let mut segments = Segments::new(rest);
+            if output.una.is_some() {
+                // the separator after UNA is the first one
+                let (rest, whitespace) = multispace0(rest)?;
+                segments = Segments {
+                    input: rest,
+                    separator: Some(whitespace),
+                };
+            }
+            output.unb = segments.next("UNB")?;
+            if segments.peek("UNG") {
+                while segments.peek("UNG") {
+                    let ung = segments.next("UNG")?;
+                    let mut messages = vec![];
+                    while !segments.peek("UNE") {
+                        messages.push(segments.message()?);
+                    }
+                    let une = segments.next("UNE")?;
+                    output.groups.push(RawGroup { ung, messages, une });
+                }
+            } else {
+                while !segments.peek("UNZ") {
+                    output.messages.push(segments.message()?);
+                }
+            }
+            output.unz = segments.next("UNZ")?;
+            output.separator = segments.separator.unwrap_or_default().to_string();
+            Ok((segments.input, output))
+        })
+    }
+}
+
+impl fmt::Display for RawInterchange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        with_delimiters(self.delimiters(), || {
+            if let Some(una) = &self.una {
+                write!(f, "{una}{}", self.separator)?;
+            }
+            let mut segments = vec![&self.unb];
+            for group in &self.groups {
+                segments.push(&group.ung);
+                segments.extend(group.messages.iter().flat_map(|m| &m.segments));
+                segments.push(&group.une);
+            }
+            segments.extend(self.messages.iter().flat_map(|m| &m.segments));
+            segments.push(&self.unz);
+            write_segments(f, segments, &self.separator)
+        })
+    }
+}
+
+/// Parses an interchange of any messages, see [`RawInterchange`].
+pub fn parse(input: &str) -> Result<RawInterchange, ParseError> {
+    let (rest, interchange) =
+        RawInterchange::parse(input).map_err(|e| ParseError::new(input, e))?;
+    if !rest.trim().is_empty() {
+        let err = Error {
+            expected: Some("end of interchange".into()),
+            ..Error::new(rest, ErrorKind::Eof)
+        };
+        return Err(ParseError::new(input, nom::Err::Error(err)));
+    }
+    Ok(interchange)
+}
+
+/// Reads segments one by one and remembers the whitespace between them.
+struct Segments<'a> {
+    input: &'a str,
+    /// whitespace after the first segment
+    separator: Option<&'a str>,
+}
+
+impl<'a> Segments<'a> {
+    fn new(input: &'a str) -> Self {
+        Segments {
+            input,
+            separator: None,
+        }
+    }
+
+    fn peek(&self, tag: &str) -> bool {
+        peek_segment(self.input, tag)
+    }
+
+    /// Parses the segment `tag` and the whitespace after it.
+    fn next(&mut self, tag: &'static str) -> Result<RawSegment, nom::Err<Error<&'a str>>> {
+        if !self.peek(tag) {
+            return Err(nom::Err::Error(Error {
+                expected: Some(tag.into()),
+                ..Error::new(self.input, ErrorKind::Tag)
+            }));
+        }
+        self.any()
+    }
+
+    fn any(&mut self) -> Result<RawSegment, nom::Err<Error<&'a str>>> {
+        let (rest, segment) = RawSegment::parse(self.input)?;
+        // whitespace after the last segment is not part of the interchange
+        let (next, whitespace) = multispace0(rest)?;
+        self.input = rest;
+        if !next.is_empty() {
+            self.input = next;
+            self.separator.get_or_insert(whitespace);
+        }
+        Ok(segment)
+    }
+
+    /// Parses the segments from UNH to UNT.
+    fn message(&mut self) -> Result<RawMessage, nom::Err<Error<&'a str>>> {
+        let mut segments = vec![self.next("UNH")?];
+        while !self.peek("UNT") {
+            if self.input.is_empty() || ["UNH", "UNE", "UNZ"].iter().any(|t| self.peek(t)) {
+                return Err(nom::Err::Error(Error {
+                    expected: Some("UNT".into()),
+                    ..Error::new(self.input, ErrorKind::Tag)
+                }));
+            }
+            segments.push(self.any()?);
+        }
+        segments.push(self.next("UNT")?);
+        Ok(RawMessage { segments })
+    }
+}
+
+fn write_segments<'a>(
+    f: &mut fmt::Formatter<'_>,
+    segments: impl IntoIterator<Item = &'a RawSegment>,
+    separator: &str,
+) -> fmt::Result {
+    let terminator = delimiters().segment;
+    for (idx, segment) in segments.into_iter().enumerate() {
+        if idx > 0 {
+            write!(f, "{separator}")?;
+        }
+        write!(f, "{segment}{terminator}")?;
+    }
+    Ok(())
+}
+
+/// Parses the serialized raw layer with a typed parser, which has to take all of it.
+fn parse_typed<T>(input: &str) -> Result<T, ParseError>
+where
+    T: for<'a> Parser<&'a str, T, Error<&'a str>>,
+{
+    match T::parse(input) {
+        Ok((rest, _)) if !rest.trim().is_empty() => {
+            let err = Error {
+                expected: Some("end of input".into()),
+                ..Error::new(rest, ErrorKind::Eof)
+            };
+            Err(ParseError::new(input, nom::Err::Error(err)))
+        }
+        Ok((_, obj)) => Ok(obj),
+        Err(e) => Err(ParseError::new(input, e)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn raw_baplie_roundtrip() {
+        let input_str = std::fs::read_to_string("./test-data/d04b_baplie.edi").unwrap();
+        let obj = parse(&input_str).unwrap();
+        assert_eq!(obj.messages.len(), 1);
+        assert_eq!(obj.messages[0].segments.len(), 19);
+        let eqd = &obj.messages[0].segments[16];
+        assert_eq!(eqd.tag, "EQD");
+        assert_eq!(eqd.get(2, 3), Some("2210"));
+        // trailing empty elements are kept
+        assert_eq!(obj.unb.elements.len(), 11);
+        assert_eq!(obj.separator, "");
+        assert_eq!(obj.to_string(), input_str);
+    }
+
+    #[test]
+    fn raw_una_roundtrip() {
+        let input_str = "UNA*~,! #
+UNB~UNOC*3~SENDER~RECEIVER~200101*1200~1#
+UNG~IFTSTA~SENDER~RECEIVER~200101*1200~7~UN~D*00B#
+UNH~1~IFTSTA*D*00B*UN#
+FTX~AAI~~~PLASTICS!*ART!~ICLES!!*X#
+UNT~3~1#
+UNE~1~7#
+UNZ~1~1#
+";
+        let (rest, obj) = RawInterchange::parse(input_str).unwrap();
+        assert_eq!(rest, "\n");
+        assert_eq!(obj.delimiters().release, Some('!'));
+        assert_eq!(obj.groups[0].messages.len(), 1);
+        let ftx = &obj.groups[0].messages[0].segments[1];
+        assert_eq!(ftx.elements[3], vec!["PLASTICS*ART~ICLES!", "X"]);
+        assert_eq!(obj.to_string() + rest, input_str);
+    }
+
+    #[test]
+    fn raw_message_without_unt() {
+        let input_str = "UNB+UNOC:3+SENDER+RECEIVER+200101:1200+1'
+UNH+1+IFTSTA:D:00B:UN'
+BGM+23+1+9'
+UNZ+1+1'";
+        let err = parse(input_str).unwrap_err();
+        assert_eq!(err.segment_index, 4);
+        assert_eq!(err.segment_tag, "UNZ");
+        assert_eq!(err.expected.as_deref(), Some("UNT"));
+    }
+
+    #[cfg(feature = "d00b")]
+    #[test]
+    fn raw_to_typed() {
+        let input_str = std::fs::read_to_string("./test-data/d00b_iftsta.edi").unwrap();
+        let obj = parse(&input_str).unwrap();
+        assert_eq!(obj.separator, "\n");
+        assert_eq!(obj.to_string(), input_str.trim_end());
+        let message: crate::d00b::IFTSTA = obj.messages[0].typed().unwrap();
+        assert_eq!(message.to_string(), obj.messages[0].to_string());
+        let nad: crate::d00b::NAD = obj.messages[0].segments[3].typed().unwrap();
+        assert!(matches!(nad._010, crate::d00b::_3035::CA));
+        let interchange: crate::d00b::Interchange<crate::d00b::IFTSTA> = obj.typed().unwrap();
+        assert_eq!(interchange.to_string(), input_str.trim_end());
+        // errors point into the raw message
+        #[cfg(not(feature = "lenient-codes"))]
+        {
+            let mut bgm = obj.messages[0].clone();
+            bgm.segments[1].elements[0][0] = "XX".to_string();
+            let err = bgm.typed::<crate::d00b::IFTSTA>().unwrap_err();
+            assert_eq!(err.segment_index, 2);
+            assert_eq!(err.segment_tag, "BGM");
+        }
+    }
+}
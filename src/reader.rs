@@ -0,0 +1,427 @@
+//! Streaming access to the messages of an interchange.
+//!
+//! [`MessageReader`] reads segment by segment from any [`BufRead`] and
+//! yields one message at a time, so only a single message is held in memory.
+//!
+//! ```
+//! # #[cfg(feature = "d00b")]
+//! # {
+//! use edifact_types::d00b::IFTSTA;
+//! use edifact_types::reader::MessageReader;
+//!
+//! let input = "UNB+UNOC:3+SENDER+RECEIVER+200101:1200+1'
+//! UNH+1+IFTSTA:D:00B:UN'BGM+23+1+9'UNT+3+1'
+//! UNH+2+IFTSTA:D:00B:UN'BGM+23+2+9'UNT+3+2'
+//! UNZ+2+1'";
+//! for message in MessageReader::<_, IFTSTA>::new(input.as_bytes()) {
+//!     println!("{}", message.unwrap().bgm);
+//! }
+//! # }
+//! ```
+
+use crate::charset::{decode_as, syntax_level, SyntaxLevel};
+use crate::control::{check_count, check_reference};
+use crate::util::{delimiters, parse_line, parse_una, with_delimiters, Delimiters, Error, Parser};
+use crate::validate::{Validate, Violation};
+use crate::ParseError;
+use nom::error::ErrorKind;
+use std::{fmt, io::BufRead, marker::PhantomData};
+
+/// Error of a [`MessageReader`].
+#[derive(Debug)]
+pub enum ReadError {
+    Io(std::io::Error),
+    /// the position of the error is relative to the whole interchange
+    Parse(ParseError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "{e}"),
+            ReadError::Parse(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadError::Io(e) => Some(e),
+            ReadError::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<std::io::Error> for ReadError {
+    fn from(e: std::io::Error) -> Self {
+        ReadError::Io(e)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(e: ParseError) -> Self {
+        ReadError::Parse(e)
+    }
+}
+
+/// Reads the messages of an interchange one by one.
+///
+/// UNA sets the delimiters and UNB S001 the encoding of the following segments,
+/// like [`crate::parse_bytes`]. Functional groups are skipped, the control count and
+/// reference of UNZ are checked by [`Validate`] once the interchange is read.
+/// Input without UNB, e.g. a file of messages, is read as well.
+///
+/// A message which fails to parse is returned as error and reading continues
+/// with the next message, other errors end the iteration.
+pub struct MessageReader<R, T> {
+    reader: R,
+    delimiters: Delimiters,
+    syntax: Option<SyntaxLevel>,
+    unb: Option<String>,
+    unz: Option<String>,
+    /// index of the last segment read, starting with 1 at UNB (UNA is not counted)
+    segment_index: usize,
+    messages: usize,
+    groups: usize,
+    started: bool,
+    done: bool,
+    message: PhantomData<T>,
+}
+
+impl<R: BufRead, T> MessageReader<R, T> {
+    pub fn new(reader: R) -> Self {
+        MessageReader {
+            reader,
+            delimiters: delimiters(),
+            syntax: None,
+            unb: None,
+            unz: None,
+            segment_index: 0,
+            messages: 0,
+            groups: 0,
+            started: false,
+            done: false,
+            message: PhantomData,
+        }
+    }
+
+    /// Delimiters of the interchange, set by UNA.
+    pub fn delimiters(&self) -> Delimiters {
+        self.delimiters
+    }
+
+    /// The UNB segment once it is read, without segment terminator.
+    pub fn unb(&self) -> Option<&str> {
+        self.unb.as_deref()
+    }
+
+    /// The UNZ segment once it is read, without segment terminator.
+    pub fn unz(&self) -> Option<&str> {
+        self.unz.as_deref()
+    }
+
+    /// Reads UNA, the delimiters have to be single bytes to split the input.
+    fn read_una(&mut self) -> Result<(), ReadError> {
+        if !self.reader.fill_buf()?.starts_with(b"UNA") {
+            return Ok(());
+        }
+        let mut una = [0; 9];
+        self.reader.read_exact(&mut una)?;
+        let text: String = una.iter().map(|&b| char::from(b)).collect();
+        self.delimiters = match parse_una(&text) {
+            Ok((_, d))
+                if [d.component, d.element, d.decimal, d.segment]
+                    .into_iter()
+                    .chain(d.release)
+                    .all(|c| c.is_ascii()) =>
+            {
+                d
+            }
+            _ => return Err(self.error("UNA", "service characters of UNA")),
+        };
+        Ok(())
+    }
+
+    /// Reads up to the next segment terminator which is not released,
+    /// `None` at the end of the input.
+    fn read_segment(&mut self) -> Result<Option<String>, ReadError> {
+        let terminator = self.delimiters.segment as u8;
+        let release = self.delimiters.release.map(|r| r as u8);
+        let mut bytes = vec![];
+        loop {
+            if self.reader.read_until(terminator, &mut bytes)? == 0 {
+                break;
+            }
+            let Some(content) = bytes.strip_suffix(&[terminator]) else {
+                break;
+            };
+            let releases = content
+                .iter()
+                .rev()
+                .take_while(|&&b| Some(b) == release)
+                .count();
+            if releases % 2 == 0 {
+                break;
+            }
+        }
+        let start = bytes.iter().position(|b| !b.is_ascii_whitespace());
+        let Some(start) = start else {
+            return Ok(None);
+        };
+        self.segment_index += 1;
+        match decode_as(self.syntax, &bytes[start..]) {
+            Ok(segment) => Ok(Some(segment)),
+            Err(_) => Err(self.error("", "UTF-8")),
+        }
+    }
+
+    /// Error at the current segment.
+    fn error(&self, tag: &str, expected: &str) -> ReadError {
+        ReadError::Parse(ParseError {
+            segment_index: self.segment_index,
+            segment_tag: tag.to_string(),
+            element: None,
+            value: None,
+            expected: Some(expected.into()),
+            msg: format!("expected {expected}"),
+        })
+    }
+
+    /// Turns the error of a parser, which was called with `input` starting at segment `first_index`,
+    /// into an error relative to the interchange.
+    fn parse_error(input: &str, err: nom::Err<Error<&str>>, first_index: usize) -> ReadError {
+        let mut err = ParseError::new(input, err);
+        err.segment_index += first_index - 1;
+        ReadError::Parse(err)
+    }
+
+    /// Elements of a segment which is stored without segment terminator.
+    fn elements(&self, segment: &str, tag: &str) -> Vec<String> {
+        with_delimiters(self.delimiters, || {
+            let segment = format!("{segment}{}", self.delimiters.segment);
+            parse_line(&segment, tag)
+                .map(|(_, vars)| vars.into_iter().map(str::to_string).collect())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Reads the segments from UNH to UNT, `None` after UNZ.
+    /// Returns the message and the index of its UNH.
+    fn read_message(&mut self) -> Result<Option<(String, usize)>, ReadError> {
+        if !self.started {
+            self.started = true;
+            self.read_una()?;
+        }
+        let mut message = String::new();
+        let mut first_index = 0;
+        while let Some(segment) = self.read_segment()? {
+            let tag = segment
+                .split([self.delimiters.element, self.delimiters.segment])
+                .next()
+                .unwrap_or_default()
+                .to_string();
+            match tag.as_str() {
+                "UNB" if self.unb.is_none() && message.is_empty() => {
+                    // UNB is split with the delimiters of UNA
+                    self.syntax = with_delimiters(self.delimiters, || syntax_level(&segment));
+                    self.unb = Some(
+                        segment
+                            .trim_end_matches(self.delimiters.segment)
+                            .to_string(),
+                    );
+                }
+                "UNG" | "UNE" if message.is_empty() => self.groups += usize::from(tag == "UNG"),
+                "UNZ" if message.is_empty() && self.unb.is_some() => {
+                    self.done = true;
+                    self.unz = Some(
+                        segment
+                            .trim_end_matches(self.delimiters.segment)
+                            .to_string(),
+                    );
+                    return Ok(None);
+                }
+                "UNH" if message.is_empty() => {
+                    first_index = self.segment_index;
+                    message.push_str(&segment);
+                }
+                "UNT" if !message.is_empty() => {
+                    message.push_str(&segment);
+                    self.messages += 1;
+                    return Ok(Some((message, first_index)));
+                }
+                _ if !message.is_empty() => message.push_str(&segment),
+                _ => return Err(self.error(&tag, "UNH")),
+            }
+        }
+        self.done = true;
+        match (message.is_empty(), &self.unb) {
+            (true, None) => Ok(None),
+            (true, Some(_)) => Err(self.error("", "UNZ")),
+            (false, _) => Err(self.error("", "UNT")),
+        }
+    }
+}
+
+impl<R: BufRead, T> Iterator for MessageReader<R, T>
+where
+    T: for<'a> Parser<&'a str, T, Error<&'a str>>,
+{
+    type Item = Result<T, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let (message, first_index) = match self.read_message() {
+            Ok(Some(message)) => message,
+            Ok(None) => return None,
+            Err(e) => {
+                self.done = true;
+                return Some(Err(e));
+            }
+        };
+        let result = with_delimiters(self.delimiters, || match T::parse(&message) {
+            Ok((rest, _)) if !rest.trim().is_empty() => {
+                let err = Error {
+                    expected: Some("UNT".into()),
+                    ..Error::new(rest, ErrorKind::Eof)
+                };
+                Err(Self::parse_error(
+                    &message,
+                    nom::Err::Error(err),
+                    first_index,
+                ))
+            }
+            Ok((_, obj)) => Ok(obj),
+            Err(e) => Err(Self::parse_error(&message, e, first_index)),
+        });
+        Some(result)
+    }
+}
+
+impl<R: BufRead, T> Validate for MessageReader<R, T> {
+    /// Checks the control count and reference of UNZ once it is read,
+    /// UNZ counts the functional groups if there are any.
+    fn validate(&self) -> Vec<Violation> {
+        let (Some(unb), Some(unz)) = (&self.unb, &self.unz) else {
+            return vec![];
+        };
+        let unb = self.elements(unb, "UNB");
+        let unz = self.elements(unz, "UNZ");
+        let element = |elements: &[String], idx: usize| -> String {
+            elements.get(idx).cloned().unwrap_or_default()
+        };
+        let count = if self.groups > 0 {
+            self.groups
+        } else {
+            self.messages
+        };
+        check_count("UNZ/010", &element(&unz, 0), count)
+            .into_iter()
+            .chain(check_reference(
+                "UNZ/020",
+                &element(&unz, 1),
+                &element(&unb, 4),
+            ))
+            .collect()
+    }
+}
+
+#[cfg(all(test, feature = "d00b"))]
+mod test {
+    use super::*;
+    use crate::d00b::{AnyMessage, IFTSTA};
+
+    const INTERCHANGE: &str = "UNA:+.? '
+UNB+UNOC:3+SENDER+RECEIVER+200101:1200+1'
+UNH+1+IFTSTA:D:00B:UN'
+BGM+23+1+9'
+FTX+AAI+++RELEASED?'TERMINATOR'
+UNT+4+1'
+UNH+2+IFTSTA:D:00B:UN'
+BGM+23+2+9'
+UNT+3+2'
+UNZ+2+1'";
+
+    #[test]
+    fn read_iftsta_file() {
+        let file = std::fs::File::open("./test-data/d00b_iftsta.edi").unwrap();
+        let reader = MessageReader::<_, AnyMessage>::new(std::io::BufReader::new(file));
+        let messages: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+        let input_str = std::fs::read_to_string("./test-data/d00b_iftsta.edi").unwrap();
+        let (_, interchange) =
+            crate::d00b::Interchange::<AnyMessage>::parse(input_str.as_str()).unwrap();
+        assert_eq!(messages.len(), interchange.messages.len());
+        for (read, parsed) in messages.iter().zip(&interchange.messages) {
+            assert_eq!(read.to_string(), parsed.to_string());
+        }
+    }
+
+    #[test]
+    fn read_messages() {
+        let mut reader = MessageReader::<_, IFTSTA>::new(INTERCHANGE.as_bytes());
+        let first = reader.next().unwrap().unwrap();
+        assert_eq!(
+            reader.unb(),
+            Some("UNB+UNOC:3+SENDER+RECEIVER+200101:1200+1")
+        );
+        assert_eq!(reader.delimiters().release, Some('?'));
+        let ftx = first.ftx[0]._040.as_ref().unwrap();
+        assert_eq!(ftx._010, "RELEASED'TERMINATOR");
+        assert_eq!(
+            reader.next().unwrap().unwrap().bgm._020.unwrap()._010,
+            Some("2".to_string())
+        );
+        assert!(reader.next().is_none());
+    }
+
+    #[cfg(not(feature = "lenient-codes"))]
+    #[test]
+    fn read_message_error_position() {
+        let input = INTERCHANGE.replace("BGM+23+2+9", "BGM+23+2+XX");
+        let mut reader = MessageReader::<_, IFTSTA>::new(input.as_bytes());
+        assert!(reader.next().unwrap().is_ok());
+        let Some(Err(ReadError::Parse(err))) = reader.next() else {
+            panic!("BGM 1225 XX is unknown");
+        };
+        assert_eq!(err.segment_index, 7);
+        assert_eq!(err.segment_tag, "BGM");
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn read_control_count() {
+        let mut reader = MessageReader::<_, IFTSTA>::new(INTERCHANGE.as_bytes());
+        assert_eq!(reader.by_ref().filter(Result::is_ok).count(), 2);
+        assert!(reader.validate().is_empty());
+        // a wrong count is read and reported by `Validate`
+        let input = INTERCHANGE.replace("UNZ+2", "UNZ+3");
+        let mut reader = MessageReader::<_, IFTSTA>::new(input.as_bytes());
+        assert_eq!(reader.by_ref().filter(Result::is_ok).count(), 2);
+        let violations: Vec<String> = reader.validate().iter().map(|v| v.to_string()).collect();
+        assert_eq!(violations, [r#"UNZ/010: value "3", expected "2""#]);
+    }
+
+    #[test]
+    fn read_latin1_with_una() {
+        let input = "UNA:*.! ~UNB*UNOC:3*SENDER*RECEIVER*200101:1200*1~UNH*1*IFTSTA:D:00B:UN~\
+            BGM*23*1*9~NAD*CA***MÜLLER!*SÖHNE~UNT*4*1~UNZ*1*1~";
+        let (bytes, _, unmappable) = encoding_rs::WINDOWS_1252.encode(input);
+        assert!(!unmappable);
+        let mut reader = MessageReader::<_, IFTSTA>::new(bytes.as_ref());
+        let message = reader.next().unwrap().unwrap();
+        let name = message.segment_group_1[0].nad._040.as_ref().unwrap();
+        assert_eq!(name._010, "MÜLLER*SÖHNE");
+        assert!(reader.next().is_none());
+        assert!(reader.validate().is_empty());
+    }
+
+    #[test]
+    fn read_missing_unz() {
+        let input = INTERCHANGE.replace("UNZ+2+1'", "");
+        let results: Vec<_> = MessageReader::<_, IFTSTA>::new(input.as_bytes()).collect();
+        assert_eq!(results.len(), 3);
+        assert!(matches!(&results[2], Err(ReadError::Parse(e)) if e.msg == "expected UNZ"));
+    }
+}
@@ -0,0 +1,110 @@
+//! Mutates the interchanges of `test-data` to make sure no input makes a parser panic.
+
+use crate::util::Parser;
+use crate::{d00b, d95b, parse_interchange};
+use proptest::prelude::*;
+
+#[derive(Debug, Clone)]
+enum Mutation {
+    Delete(usize),
+    Insert(usize, char),
+    Replace(usize, char),
+    Truncate(usize),
+}
+
+fn corpus() -> Vec<String> {
+    let mut corpus: Vec<String> = std::fs::read_dir("./test-data")
+        .unwrap()
+        .map(|entry| std::fs::read_to_string(entry.unwrap().path()).unwrap())
+        .collect();
+    // the d95b message is stored without interchange envelope
+    let coprar = std::fs::read_to_string("./test-data/d95b_coprar.edi").unwrap();
+    corpus.push(format!(
+        "UNA:+.? '\nUNB+UNOA:2+SENDER:ZZZ+RECEIVER:ZZZ+121121:0841+638'\n{}\nUNZ+1+638'",
+        coprar.trim()
+    ));
+    corpus
+}
+
+fn mutation() -> impl Strategy<Value = Mutation> {
+    // service characters are the interesting ones, any other char covers the rest
+    let c = prop_oneof![
+        Just(':'),
+        Just('+'),
+        Just('.'),
+        Just('?'),
+        Just('\''),
+        Just(' '),
+        Just('\n'),
+        any::<char>(),
+    ];
+    prop_oneof![
+        any::<usize>().prop_map(Mutation::Delete),
+        (any::<usize>(), c.clone()).prop_map(|(pos, c)| Mutation::Insert(pos, c)),
+        (any::<usize>(), c).prop_map(|(pos, c)| Mutation::Replace(pos, c)),
+        any::<usize>().prop_map(Mutation::Truncate),
+    ]
+}
+
+fn apply(input: &str, mutation: &Mutation) -> String {
+    let mut chars: Vec<char> = input.chars().collect();
+    if chars.is_empty() {
+        return String::new();
+    }
+    match *mutation {
+        Mutation::Delete(pos) => {
+            chars.remove(pos % chars.len());
+        }
+        Mutation::Insert(pos, c) => chars.insert(pos % (chars.len() + 1), c),
+        Mutation::Replace(pos, c) => {
+            let len = chars.len();
+            chars[pos % len] = c;
+        }
+        Mutation::Truncate(pos) => chars.truncate(pos % chars.len()),
+    }
+    chars.into_iter().collect()
+}
+
+proptest! {
+    #[test]
+    fn mutated_interchange_does_not_panic(
+        file in any::<prop::sample::Index>(),
+        mutations in prop::collection::vec(mutation(), 1..8),
+    ) {
+        let corpus = corpus();
+        let mut input = corpus[file.index(corpus.len())].clone();
+        for mutation in &mutations {
+            input = apply(&input, mutation);
+        }
+        if let Err(e) = parse_interchange(&input) {
+            let _ = e.to_string();
+        }
+        let _ = d00b::Interchange::<d00b::IFTSTA>::parse(&input);
+        let _ = d00b::Interchange::<d00b::IFTMIN>::parse(&input);
+        let _ = d00b::GroupedInterchange::<d00b::AnyMessage>::parse(&input);
+        let _ = d95b::Interchange::<d95b::COPRAR>::parse(&input);
+        let _ = crate::charset::check(&input);
+        let _ = crate::charset::transliterate(&input);
+        let _ = crate::charset::encode(&input);
+        let _ = crate::parse_bytes(input.as_bytes());
+        let _ = crate::raw::parse(&input).map(|raw| raw.to_string());
+        let _ = crate::reader::MessageReader::<_, d00b::AnyMessage>::new(input.as_bytes()).count();
+    }
+}
+
+#[test]
+fn malformed_service_segments() {
+    // UNA with less than 6 characters
+    assert!(parse_interchange("UNA:+").is_err());
+    assert!(d00b::UNA::parse("UNA:+").is_err());
+    // the same character for two delimiters
+    assert!(d00b::UNA::parse("UNA::.? '").is_err());
+    assert!(d95b::UNA::parse("UNA:+.? +").is_err());
+    assert!(d95b::UNA::parse("UNA:+.? '\nUNB").is_ok());
+    // message identifier without version and release
+    assert!(d00b::S009::parse("IFTSTA").is_err());
+    assert!(d95b::S009::parse("COPRAR::").is_err());
+    let err = parse_interchange("UNB+UNOC:3+A+B+200101:1200+1'UNH+1+IFTSTA'UNT+2+1'UNZ+1+1'")
+        .unwrap_err();
+    assert_eq!(err.segment_tag, "UNH");
+}
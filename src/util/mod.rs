@@ -1,65 +1,375 @@
 use nom::{
-    branch::alt,
-    bytes::complete::{escaped, is_not, tag},
-    character::complete::{newline, one_of},
-    combinator::opt,
-    multi::{many0, separated_list0},
-    sequence::{delimited, terminated},
-    IResult, Parser as _,
+    bytes::complete::{tag, take},
+    character::complete::{char, newline},
+    combinator::{cut, opt},
+    error::{ErrorKind, ParseError},
+    multi::many0,
+    sequence::terminated,
+    Parser as _,
 };
+use std::{cell::Cell, fmt};
+
+/// Result of the parsers in this crate.
+pub type IResult<I, O> = nom::IResult<I, O, Error<I>>;
+
+/// Error of the parsers in this crate.
+///
+/// Works like `nom::error::Error`, but also records the element which failed.
+/// Use [`crate::ParseError::new`] to turn it into a readable error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error<I> {
+    /// input at the position of the error
+    pub input: I,
+    /// nom error kind, `OneOf` for unknown codes and `NonEmpty` for missing values
+    pub code: ErrorKind,
+    /// position of the element, e.g. `["LOC", "020", "010"]`
+    pub path: Vec<&'static str>,
+    /// the offending value, boxed like `expected` to keep the error small
+    pub value: Option<Box<str>>,
+    /// name of the code list, element or value which was expected
+    pub expected: Option<Box<str>>,
+}
+
+impl<I> Error<I> {
+    pub fn new(input: I, code: ErrorKind) -> Self {
+        Error {
+            input,
+            code,
+            path: vec![],
+            value: None,
+            expected: None,
+        }
+    }
+
+    /// A value which is not part of the code list `expected`.
+    pub fn unknown_code(input: I, value: &str, expected: &str) -> Self {
+        Error {
+            value: Some(value.into()),
+            expected: Some(expected.into()),
+            ..Error::new(input, ErrorKind::OneOf)
+        }
+    }
+
+    /// A value which does not match the `expected` one.
+    pub fn unexpected_value(input: I, value: &str, expected: &str) -> Self {
+        Error {
+            value: Some(value.into()),
+            expected: Some(expected.into()),
+            ..Error::new(input, ErrorKind::Verify)
+        }
+    }
+
+    /// A mandatory element `expected` which is empty.
+    pub fn missing(input: I, expected: &str) -> Self {
+        Error {
+            expected: Some(expected.into()),
+            ..Error::new(input, ErrorKind::NonEmpty)
+        }
+    }
+
+    /// Describes the error without its position.
+    pub fn reason(&self) -> String {
+        match (&self.code, &self.value, &self.expected) {
+            (ErrorKind::OneOf, Some(value), Some(expected)) => {
+                format!("unknown `{expected}` value {value}")
+            }
+            (ErrorKind::NonEmpty, _, Some(expected)) => format!("missing mandatory `{expected}`"),
+            (_, Some(value), Some(expected)) => {
+                format!("unexpected value {value}, expected {expected}")
+            }
+            (_, None, Some(expected)) => format!("expected {expected}"),
+            (ErrorKind::Tag, _, _) => "unexpected segment".to_string(),
+            (ErrorKind::Escaped, _, _) => "release character without service character".to_string(),
+            (code, _, _) => format!("invalid syntax ({})", code.description()),
+        }
+    }
+
+    /// Prepends the position of the enclosing element or segment to the path.
+    pub fn within(mut self, position: &'static str) -> Self {
+        self.path.insert(0, position);
+        self
+    }
+}
+
+impl<I> ParseError<I> for Error<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        Error::new(input, kind)
+    }
+
+    fn append(_: I, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<I> fmt::Display for Error<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.path.is_empty() {
+            write!(f, "{}: ", self.path.join("/"))?;
+        }
+        write!(f, "{}", self.reason())
+    }
+}
+
+/// Turns an error of a nested parser into a failure of the element at `position`.
+///
+/// A segment whose tag matched can not be something else, so its content
+/// errors must not be swallowed by `opt` or `many0`.
+pub fn fail_within<I>(err: nom::Err<Error<I>>, position: &'static str) -> nom::Err<Error<I>> {
+    match err {
+        nom::Err::Error(e) | nom::Err::Failure(e) => nom::Err::Failure(e.within(position)),
+        incomplete => incomplete,
+    }
+}
+
+/// Service characters which delimit the parts of an interchange.
+///
+/// Defaults to the values of ISO 9735 (`:+.? '`), a UNA segment
+/// can replace them for a single interchange.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Delimiters {
+    /// component data element separator
+    pub component: char,
+    /// data element separator
+    pub element: char,
+    /// decimal notation, comma or full stop
+    pub decimal: char,
+    /// release indicator, `None` if not used
+    pub release: Option<char>,
+    /// segment terminator
+    pub segment: char,
+}
+
+impl Default for Delimiters {
+    fn default() -> Self {
+        Delimiters {
+            component: ':',
+            element: '+',
+            decimal: '.',
+            release: Some('?'),
+            segment: '\'',
+        }
+    }
+}
+
+impl Delimiters {
+    /// characters which have to be escaped with the release indicator
+    fn is_service_char(&self, c: char) -> bool {
+        c == self.component || c == self.element || c == self.segment || Some(c) == self.release
+    }
+
+    /// Separators and release indicator have to be distinct to split an interchange.
+    pub fn is_valid(&self) -> bool {
+        let chars = [Some(self.component), Some(self.element), Some(self.segment)];
+        let distinct = |c: Option<char>| chars.iter().filter(|&&x| x == c).count() <= 1;
+        chars.iter().all(|c| distinct(*c)) && (self.release.is_none() || distinct(self.release))
+    }
+}
+
+thread_local! {
+    static DELIMITERS: Cell<Delimiters> = Cell::new(Delimiters::default());
+}
+
+/// Returns the delimiters currently used for parsing and serialization.
+pub fn delimiters() -> Delimiters {
+    DELIMITERS.with(|d| d.get())
+}
+
+/// Runs `f` with `delimiters` active for all parsers and `Display` implementations
+/// on the current thread; the previous delimiters are restored afterwards.
+pub fn with_delimiters<R>(delimiters: Delimiters, f: impl FnOnce() -> R) -> R {
+    struct Restore(Delimiters);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            DELIMITERS.with(|d| d.set(self.0));
+        }
+    }
+    let _restore = Restore(DELIMITERS.with(|d| d.replace(delimiters)));
+    f()
+}
+
+/// Parses the service string advice (UNA) into the delimiters it defines.
+pub fn parse_una(input: &str) -> IResult<&str, Delimiters> {
+    let (rest, _) = tag("UNA").parse(input)?;
+    let (rest, chars) = cut(take(6usize)).parse(rest)?;
+    let (rest, _) = many0(newline).parse(rest)?;
+    let mut chars = chars.chars();
+    let mut next = || chars.next().unwrap_or(' ');
+    let (component, element, decimal, release, _reserved, segment) =
+        (next(), next(), next(), next(), next(), next());
+    let delimiters = Delimiters {
+        component,
+        element,
+        decimal,
+        release: Some(release).filter(|c| *c != ' '),
+        segment,
+    };
+    if !delimiters.is_valid() {
+        return Err(nom::Err::Failure(Error::new(input, ErrorKind::Verify)));
+    }
+    Ok((rest, delimiters))
+}
+
+/// Removes the release indicator from a value, `PLASTICS?:ARTICLES` becomes `PLASTICS:ARTICLES`.
+pub fn unescape(value: &str) -> String {
+    let Some(release) = delimiters().release else {
+        return value.to_string();
+    };
+    let mut output = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == release {
+            if let Some(released) = chars.next() {
+                output.push(released);
+                continue;
+            }
+        }
+        output.push(c);
+    }
+    output
+}
+
+/// Releases the service characters of a value, `A+B` becomes `A?+B`.
+pub fn escape(value: &str) -> String {
+    let d = delimiters();
+    let Some(release) = d.release else {
+        return value.to_string();
+    };
+    let mut output = String::with_capacity(value.len());
+    for c in value.chars() {
+        if d.is_service_char(c) {
+            output.push(release);
+        }
+        output.push(c);
+    }
+    output
+}
 
 pub fn clean_num(mut input: &str) -> &str {
     // make sure whitespace is removed
     input = input.trim();
-    // make sure leading zeros are removed, up to 1 digit
-    while input.starts_with('0') && input.len() > 1 {
+    // make sure leading zeros are removed, up to 1 digit before the decimal mark
+    while input.starts_with('0') && input[1..].starts_with(|c: char| c.is_ascii_digit()) {
         input = input.strip_prefix('0').expect("Strip prefix 0 failed!");
     }
 
     input
 }
 
+/// Takes input up to the first of `stop` which is not escaped by the release indicator.
+pub(crate) fn take_unescaped<'a>(
+    input: &'a str,
+    stop: &[char],
+    delimiters: &Delimiters,
+) -> IResult<&'a str, &'a str> {
+    let mut chars = input.char_indices();
+    while let Some((idx, c)) = chars.next() {
+        if Some(c) == delimiters.release {
+            match chars.next() {
+                Some((_, escaped)) if delimiters.is_service_char(escaped) => continue,
+                _ => {
+                    return Err(nom::Err::Error(Error::new(
+                        &input[idx..],
+                        ErrorKind::Escaped,
+                    )));
+                }
+            }
+        }
+        if stop.contains(&c) {
+            return Ok((&input[idx..], &input[..idx]));
+        }
+    }
+    Ok(("", input))
+}
+
+/// Splits input on every `separator` which is not escaped by the release indicator.
+fn split_unescaped<'a>(
+    mut input: &'a str,
+    separator: char,
+    delimiters: &Delimiters,
+) -> IResult<&'a str, Vec<&'a str>> {
+    let mut vars = vec![];
+    loop {
+        let (rest, var) = take_unescaped(input, &[separator], delimiters)?;
+        vars.push(var);
+        match rest.strip_prefix(separator) {
+            Some(rest) => input = rest,
+            None => return Ok((rest, vars)),
+        }
+    }
+}
+
 pub fn parse_line<'a>(input: &'a str, segment_name: &str) -> IResult<&'a str, Vec<&'a str>> {
-    let tag_name = format!("{segment_name}+");
-    let (rest, vars) = delimited(
-        tag(tag_name.as_str()),
-        escaped(is_not("?'"), '?', one_of(r#":?+'"#)),
-        tag("'"),
-    )
-    .parse(input)?;
-    let (_, vars) = crate::util::parse_plus_section(vars)?;
+    let d = delimiters();
+    let tag_name = format!("{segment_name}{}", d.element);
+    let (_, _) = tag(tag_name.as_str()).parse(input)?;
+    let (rest, line) = parse_raw_line(input)?;
+    // separator and terminator can only be the same with invalid delimiters
+    let Some(line) = line.get(tag_name.len()..) else {
+        return Err(nom::Err::Error(Error::new(input, ErrorKind::Tag)));
+    };
+    let (_, vars) = crate::util::parse_plus_section(line)?;
+    Ok((rest, vars))
+}
+
+/// Parses a single segment of any kind, the segment terminator is not part of the output.
+pub fn parse_raw_line(input: &str) -> IResult<&str, &str> {
+    let d = delimiters();
+    let (rest, line) = take_unescaped(input, &[d.segment], &d)?;
+    let (rest, _) = char(d.segment).parse(rest)?;
     // empty lines (double '') should throw error
     // also look for trailing newline and remove it
     #[allow(unused_variables)]
-    let (rest, empty_line) = terminated(opt(tag("'")), many0(newline)).parse(rest)?;
+    let (rest, empty_line) = terminated(opt(char(d.segment)), many0(newline)).parse(rest)?;
     #[cfg(feature = "logging")]
     if empty_line.is_some() {
         log::warn!("Found empty line (ends with '') -> ignored");
     }
 
-    Ok((rest, vars))
+    Ok((rest, line))
 }
 
 pub fn parse_plus_section(input: &str) -> IResult<&str, Vec<&str>> {
-    let (rest, vars) = separated_list0(
-        tag("+"),
-        alt((escaped(is_not("?+"), '?', one_of(r#":?+'"#)), tag(""))),
-    )
-    .parse(input)?;
-    Ok((rest, vars))
+    let d = delimiters();
+    split_unescaped(input, d.element, &d)
 }
 
 pub fn parse_colon_section(input: &str) -> IResult<&str, Vec<&str>> {
-    let (rest, vars) = separated_list0(
-        tag(":"),
-        alt((escaped(is_not("?:"), '?', one_of(r#":?+'"#)), tag(""))),
-    )
-    .parse(input)?;
-    Ok((rest, vars))
+    let d = delimiters();
+    split_unescaped(input, d.component, &d)
+}
+
+/// Checks whether the input starts with the given segment.
+pub fn peek_segment(input: &str, segment_name: &str) -> bool {
+    let d = delimiters();
+    input
+        .strip_prefix(segment_name)
+        .is_some_and(|rest| rest.starts_with(d.element) || rest.starts_with(d.segment))
+}
+
+/// Applies `parser` until one of the `end` segments is reached.
+///
+/// Unlike `many0`, an error inside of `parser` is returned instead of being
+/// swallowed, which points to the actual problem and not to the end segment.
+pub fn many_until_segment<'a, O>(
+    mut input: &'a str,
+    end: &[&str],
+    parser: impl Fn(&'a str) -> IResult<&'a str, O>,
+) -> IResult<&'a str, Vec<O>> {
+    let mut output = vec![];
+    while !input.is_empty() && !end.iter().any(|e| peek_segment(input, e)) {
+        let (rest, obj) = parser(input)?;
+        // infinite loop check, like `many0`
+        if rest.len() == input.len() {
+            return Err(nom::Err::Error(Error::new(input, ErrorKind::Many0)));
+        }
+        output.push(obj);
+        input = rest;
+    }
+    Ok((input, output))
 }
 
 pub trait Parser<I, O, E> {
-    fn parse(str: I) -> IResult<I, O>;
+    fn parse(str: I) -> nom::IResult<I, O, E>;
 }
 
 pub fn unborrow_string(input: &&str) -> String {
@@ -103,26 +413,88 @@ mod test {
         assert!(rest.is_empty());
     }
 
+    #[test]
+    fn parse_line_with_delimiters_test() {
+        let partner = Delimiters {
+            component: '*',
+            element: '~',
+            decimal: ',',
+            release: Some('!'),
+            segment: '#',
+        };
+        let input_str = "FTX~AAA~~~PLASTICS!*ART!~ICLES!!*X#\nFTX~AAA#";
+        let (rest, vars) = with_delimiters(partner, || parse_line(input_str, "FTX")).unwrap();
+        assert_eq!(vars, vec!["AAA", "", "", "PLASTICS!*ART!~ICLES!!*X"]);
+        assert_eq!(rest, "FTX~AAA#");
+        let (_, vars) = with_delimiters(partner, || parse_colon_section(vars[3])).unwrap();
+        assert_eq!(vars, vec!["PLASTICS!*ART!~ICLES!!", "X"]);
+        // default delimiters are restored
+        assert_eq!(delimiters(), Delimiters::default());
+    }
+
+    #[test]
+    fn clean_num_test() {
+        assert_eq!(clean_num(" 0042 "), "42");
+        assert_eq!(clean_num("000"), "0");
+        assert_eq!(clean_num("00.5"), "0.5");
+        assert_eq!(clean_num("0,5"), "0,5");
+    }
+
+    #[test]
+    fn escape_unescape_test() {
+        assert_eq!(unescape("PLASTICS?:ARTICLES??"), "PLASTICS:ARTICLES?");
+        assert_eq!(escape("A+B:C'D?"), "A?+B?:C?'D??");
+        let partner = Delimiters {
+            component: '*',
+            element: '~',
+            decimal: ',',
+            release: Some('!'),
+            segment: '#',
+        };
+        with_delimiters(partner, || {
+            assert_eq!(escape("A+B~C!"), "A+B!~C!!");
+            assert_eq!(unescape("A+B!~C!!"), "A+B~C!");
+        });
+        let no_release = Delimiters {
+            release: None,
+            ..Default::default()
+        };
+        with_delimiters(no_release, || assert_eq!(escape("A?B"), "A?B"));
+    }
+
     fn line_parser(i: &str) -> IResult<&str, &str> {
         let (rest, _) = opt(newline).parse(i)?;
         let (rest, line) = not_line_ending(rest)?;
         Ok((rest, line))
     }
 
+    /// Repetition rules of a `Vec`, checked by `Validate`.
+    fn repetition_attr(req: &str, repeat: &str) -> String {
+        if req == "M" {
+            format!("#[edifact(min = 1, max = {repeat})]")
+        } else {
+            format!("#[edifact(max = {repeat})]")
+        }
+    }
+
     fn internal_line(i: &str, re: Regex) -> Option<String> {
         let mut new_lines = vec![];
         // println!("internal_line: {i}");
         for (_, [tag, _name, req, repeat]) in re.captures_iter(i).map(|c| c.extract()) {
-            let right_side = if repeat == "1" {
+            let handle = tag.to_lowercase();
+            let field = if repeat == "1" {
                 if req == "M" {
-                    tag.to_string()
+                    format!("pub {handle}: {tag},")
                 } else {
-                    format!("Option<{tag}>")
+                    format!("pub {handle}: Option<{tag}>,")
                 }
             } else {
-                format!("Vec<{tag}>")
+                format!(
+                    "{}\n    pub {handle}: Vec<{tag}>,",
+                    repetition_attr(req, repeat)
+                )
             };
-            new_lines.push(format!("{}: {right_side},", tag.to_lowercase()));
+            new_lines.push(field);
         }
         new_lines.first().cloned()
     }
@@ -135,12 +507,15 @@ mod test {
             let handle = name.replace(' ', "_").to_lowercase();
             let group_open = if repeat == "1" {
                 if req == "M" {
-                    format!("{handle}: {struct_name},")
+                    format!("pub {handle}: {struct_name},")
                 } else {
-                    format!("{handle}: Option<{struct_name}>,")
+                    format!("pub {handle}: Option<{struct_name}>,")
                 }
             } else {
-                format!("{handle}: Vec<{struct_name}>,")
+                format!(
+                    "{}\n    pub {handle}: Vec<{struct_name}>,",
+                    repetition_attr(req, repeat)
+                )
             };
             new_group.push((group_open, struct_name))
         }
@@ -162,7 +537,7 @@ mod test {
         let re_group = Regex::new(r".*-+ (\S+ ?\S+ ?\S+?)\s+-+ (C|M)\s+(\d{1,4}).*").unwrap();
         println!("Input:\n\n");
         let mut final_string: String = format!(
-            "#[derive(Default, Debug, Serialize, Deserialize, DisplayEdifact, ParseMsg)]\npub struct {MSG_TYPE} {{"
+            "#[derive(Default, Debug, Serialize, Deserialize, DisplayEdifact, ParseMsg, Validate)]\n#[cfg_attr(feature = \"json-schema\", derive(schemars::JsonSchema))]\npub struct {MSG_TYPE} {{"
         );
 
         let mut lines: Vec<&str> = vec![];
@@ -209,9 +584,9 @@ mod test {
                         if let Some((group_handle, name)) = parsed_group {
                             let cg = current_group.last().unwrap();
                             if let Some(g) = groups.get_mut(cg) {
-                                *g = format!("{g}\n    pub {group_handle}");
+                                *g = format!("{g}\n    {group_handle}");
                             };
-                            groups.insert(name.clone(), format!("#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]\npub struct {name} {{"));
+                            groups.insert(name.clone(), format!("#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]\n#[cfg_attr(feature = \"json-schema\", derive(schemars::JsonSchema))]\npub struct {name} {{"));
                             current_group.push(name);
                         };
                         group_level.push(true);
@@ -220,7 +595,7 @@ mod test {
                             println!("group ___end: {line}");
                             let cg = current_group.last().unwrap();
                             if let Some(g) = groups.get_mut(cg) {
-                                *g = format!("{g}\n    pub {res}");
+                                *g = format!("{g}\n    {res}");
                             };
                         };
                         // end group recording, can be more than one group
@@ -244,7 +619,7 @@ mod test {
                         if let Some(res) = parsed_line {
                             if let Some(cg) = current_group.last() {
                                 if let Some(g) = groups.get_mut(cg) {
-                                    *g = format!("{g}\n    pub {res}");
+                                    *g = format!("{g}\n    {res}");
                                 };
                             } else {
                                 println!("{line} -> this is inside a group, but not parsed")
@@ -258,8 +633,8 @@ mod test {
                     // start group recording
                     println!("group ___new: {outer_line}");
                     if let Some((group_handle, name)) = parsed_group {
-                        final_string = format!("{final_string}\n    pub {group_handle}");
-                        groups.insert(name.clone(), format!("#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg)]\npub struct {name} {{"));
+                        final_string = format!("{final_string}\n    {group_handle}");
+                        groups.insert(name.clone(), format!("#[derive(Debug, Serialize, Deserialize, DisplayEdifactSg, ParseSg, Validate)]\n#[cfg_attr(feature = \"json-schema\", derive(schemars::JsonSchema))]\npub struct {name} {{"));
                         current_group.push(name);
                     };
                     group_level.push(true);
@@ -268,13 +643,13 @@ mod test {
                 // outside group
                 if let Some(res) = parsed_line {
                     println!("normal _____: {line}");
-                    final_string = format!("{final_string}\n    pub {res}");
+                    final_string = format!("{final_string}\n    {res}");
                 }
             }
         }
         let u = format!(
             "use crate::{VERSION}::*;
-use edifact_types_macros::{{DisplayEdifact, DisplayEdifactSg, ParseMsg, ParseSg}};
+use edifact_types_macros::{{DisplayEdifact, DisplayEdifactSg, ParseMsg, ParseSg, Validate}};
 use serde::{{Deserialize, Serialize}};
 use std::fmt;"
         );
@@ -0,0 +1,219 @@
+//! Validation of parsed messages against the rules of their directory.
+//!
+//! Parsing is lenient, e.g. a `Vec<DTM>` takes any number of DTM segments and
+//! a name takes any number of characters. [`Validate`] reports what the UNECE
+//! message structure and data element directory do not allow.
+
+use std::fmt;
+
+/// Checks a message, segment group, segment or composite against its definition.
+pub trait Validate {
+    /// Returns all violations, an empty list means the structure is valid.
+    fn validate(&self) -> Vec<Violation>;
+
+    /// Returns all violations with the position of the value prepended, see [`Violation::within`].
+    fn validate_within(&self, position: &str) -> Vec<Violation> {
+        self.validate()
+            .into_iter()
+            .map(|v| v.within(position))
+            .collect()
+    }
+
+    /// Returns the value if it is valid, e.g. to check a segment while building it.
+    ///
+    /// ```
+    /// # #[cfg(feature = "d00b")]
+    /// # {
+    /// use edifact_types::d00b::C058;
+    /// use edifact_types::validate::Validate;
+    ///
+    /// let name = C058 {
+    ///     _010: "x".repeat(36),
+    ///     ..Default::default()
+    /// };
+    /// assert!(name.validated().is_err());
+    /// # }
+    /// ```
+    fn validated(self) -> Result<Self, Vec<Violation>>
+    where
+        Self: Sized,
+    {
+        let violations = self.validate();
+        if violations.is_empty() {
+            Ok(self)
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+/// A single rule which is violated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// position inside the message, e.g. `SG13[2]/DTM` or `SG4[0]/NAD/030/010`, indices start at 0
+    pub path: String,
+    pub kind: ViolationKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViolationKind {
+    /// the segment or segment group repeats more often than allowed
+    TooManyRepetitions { max: usize, found: usize },
+    /// the mandatory segment or segment group is missing
+    MissingMandatory,
+    /// the value of a simple data element has the wrong length or characters
+    InvalidRepresentation {
+        representation: Representation,
+        value: String,
+    },
+    /// a control count or reference does not match the content, e.g. the segment count in UNT
+    ControlMismatch { expected: String, found: String },
+    /// a code which is not in the code list, kept by the `lenient-codes` feature
+    UnknownCode { list: String, value: String },
+}
+
+/// Representation of a simple data element, e.g. `an..35` or `n3`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Representation {
+    pub kind: RepresentationKind,
+    /// minimum length, 0 for a variable length like `an..35`
+    pub min: usize,
+    pub max: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepresentationKind {
+    /// `a`, no digits
+    Alphabetic,
+    /// `n`, digits with an optional minus sign and decimal mark, which are not counted
+    Numeric,
+    /// `an`, any character
+    Alphanumeric,
+}
+
+impl Representation {
+    /// Checks length and characters of a value.
+    pub fn matches(&self, value: &str) -> bool {
+        let length = match self.kind {
+            RepresentationKind::Alphanumeric => value.chars().count(),
+            RepresentationKind::Alphabetic => {
+                if value.chars().any(|c| c.is_ascii_digit()) {
+                    return false;
+                }
+                value.chars().count()
+            }
+            RepresentationKind::Numeric => {
+                let digits = value.strip_prefix('-').unwrap_or(value);
+                let marks = digits.matches(['.', ',']).count();
+                if marks > 1
+                    || !digits
+                        .chars()
+                        .all(|c| c.is_ascii_digit() || c == '.' || c == ',')
+                {
+                    return false;
+                }
+                digits.len() - marks
+            }
+        };
+        (self.min..=self.max).contains(&length)
+    }
+}
+
+impl fmt::Display for Representation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            RepresentationKind::Alphabetic => "a",
+            RepresentationKind::Numeric => "n",
+            RepresentationKind::Alphanumeric => "an",
+        };
+        match (self.min, self.max) {
+            (min, max) if min == max => write!(f, "{kind}{max}"),
+            (0, max) => write!(f, "{kind}..{max}"),
+            (min, max) => write!(f, "{kind}{min}..{max}"),
+        }
+    }
+}
+
+impl Violation {
+    /// Prepends the position of the enclosing segment group or message.
+    pub fn within(mut self, position: &str) -> Self {
+        self.path = format!("{position}/{}", self.path);
+        self
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ViolationKind::TooManyRepetitions { max, found } => write!(
+                f,
+                "{}: repeats {found} times, at most {max} allowed",
+                self.path
+            ),
+            ViolationKind::MissingMandatory => write!(f, "{}: mandatory but missing", self.path),
+            ViolationKind::InvalidRepresentation {
+                representation,
+                value,
+            } => write!(
+                f,
+                "{}: value {value:?} does not match {representation}",
+                self.path
+            ),
+            ViolationKind::ControlMismatch { expected, found } => {
+                write!(f, "{}: value {found:?}, expected {expected:?}", self.path)
+            }
+            ViolationKind::UnknownCode { list, value } => write!(
+                f,
+                "{}: value {value:?} is not in code list {list}",
+                self.path
+            ),
+        }
+    }
+}
+
+/// Reports a code which is not in the code list `list`, e.g. `_1001`.
+pub fn unknown_code(path: &str, list: &str, value: &str) -> Violation {
+    Violation {
+        path: path.to_string(),
+        kind: ViolationKind::UnknownCode {
+            list: list.trim_start_matches('_').to_string(),
+            value: value.to_string(),
+        },
+    }
+}
+
+/// Checks the number of repetitions of a segment or segment group.
+pub fn check_repetitions(
+    path: &str,
+    found: usize,
+    min: usize,
+    max: Option<usize>,
+) -> Option<Violation> {
+    let kind = match max {
+        _ if found < min => ViolationKind::MissingMandatory,
+        Some(max) if found > max => ViolationKind::TooManyRepetitions { max, found },
+        _ => return None,
+    };
+    Some(Violation {
+        path: path.to_string(),
+        kind,
+    })
+}
+
+/// Checks the value of a simple data element against its representation.
+pub fn check_representation(
+    path: &str,
+    value: &str,
+    representation: &Representation,
+) -> Option<Violation> {
+    if representation.matches(value) {
+        return None;
+    }
+    Some(Violation {
+        path: path.to_string(),
+        kind: ViolationKind::InvalidRepresentation {
+            representation: *representation,
+            value: value.to_string(),
+        },
+    })
+}
@@ -0,0 +1,269 @@
+//! EDIFACT as XML, in the style of ISO 20625.
+//!
+//! The messages are written with their message type as root element, segment groups
+//! become nested elements (`SG4`), segments are named by their tag (`DTM`), composites
+//! by their number (`C507`) and simple data elements by their element number (`E2005`).
+//! Code lists are written with their code, as in the EDIFACT syntax. An absent value
+//! which is followed by one of the same name, like the reference currency of `CUX++2:EUR:4`,
+//! is written as empty element (`<CUX><C504/><C504>...`), which is read as absent value.
+//!
+//! ```
+//! # #[cfg(feature = "d00b")]
+//! # {
+//! use edifact_types::d00b::IFTSTA;
+//! use edifact_types::util::Parser;
+//! use edifact_types::xml::XmlMessage;
+//!
+//! let (_, message) = IFTSTA::parse("UNH+1+IFTSTA:D:00B:UN'BGM+23+2BOG129382+9'DTM+137:202201010021:203'UNT+4+1'").unwrap();
+//! let xml = message.to_xml();
+//! assert!(xml.contains("<DTM><C507><E2005>137</E2005><E2380>202201010021</E2380><E2379>203</E2379></C507></DTM>"));
+//! let message = IFTSTA::from_xml(&xml).unwrap();
+//! assert_eq!(message.dtm[0]._010._020.as_deref(), Some("202201010021"));
+//! # }
+//! ```
+//!
+//! The implementations are generated by the same derives as the EDIFACT syntax.
+
+use std::{fmt, str::FromStr};
+
+/// Writes a value as XML element.
+pub trait ToXml {
+    /// Appends the value as element `name` to `out`.
+    fn write_xml(&self, name: &str, out: &mut String);
+}
+
+/// Reads a value from an XML element.
+pub trait FromXml: Sized {
+    /// Reads the value from the children of `element`.
+    fn read_xml(element: &Element) -> Result<Self, XmlError>;
+}
+
+/// A message, written as XML document with its message type as root element.
+pub trait XmlMessage: ToXml + FromXml {
+    /// Name of the root element, e.g. `IFTSTA`
+    const ROOT: &'static str;
+
+    /// The message as XML document.
+    fn to_xml(&self) -> String {
+        let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        self.write_xml(Self::ROOT, &mut out);
+        out
+    }
+
+    /// Reads the message from an XML document.
+    fn from_xml(input: &str) -> Result<Self, XmlError> {
+        let root = Element::parse(input)?;
+        if root.name != Self::ROOT {
+            return Err(XmlError {
+                path: String::new(),
+                kind: XmlErrorKind::Unexpected(root.name),
+            });
+        }
+        Self::read_xml(&root).map_err(|e| e.within(Self::ROOT))
+    }
+}
+
+/// An element of an XML document, without attributes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Element {
+    pub name: String,
+    /// the unescaped text of the element, empty for segments and composites
+    pub text: String,
+    pub children: Vec<Element>,
+}
+
+impl Element {
+    /// Whether the element has neither text nor children, like the placeholder of an absent value.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty() && self.children.is_empty()
+    }
+
+    /// Parses the root element of `input`.
+    pub fn parse(input: &str) -> Result<Element, XmlError> {
+        let document = roxmltree::Document::parse(input).map_err(|e| XmlError {
+            path: String::new(),
+            kind: XmlErrorKind::Syntax(e.to_string()),
+        })?;
+        Ok(Element::from_node(document.root_element()))
+    }
+
+    fn from_node(node: roxmltree::Node) -> Element {
+        let mut element = Element {
+            name: node.tag_name().name().to_string(),
+            ..Default::default()
+        };
+        for child in node.children() {
+            if child.is_element() {
+                element.children.push(Element::from_node(child));
+            } else if let Some(text) = child.text().filter(|_| child.is_text()) {
+                element.text.push_str(text);
+            }
+        }
+        element
+    }
+}
+
+/// Error while reading XML, e.g. `IFTSTA/SG4/DTM/C507/E2005: unknown code "999"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XmlError {
+    /// position of the element, e.g. `IFTSTA/BGM`
+    pub path: String,
+    pub kind: XmlErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum XmlErrorKind {
+    /// the input is not well-formed XML
+    Syntax(String),
+    /// a mandatory child element is missing
+    Missing(String),
+    /// a child element which is not expected at its position
+    Unexpected(String),
+    /// a value which is not in the code list of the element
+    UnknownCode(String),
+}
+
+impl XmlError {
+    /// Prefixes the path with the name of the parent element.
+    pub fn within(mut self, parent: &str) -> XmlError {
+        self.path = if self.path.is_empty() {
+            parent.to_string()
+        } else {
+            format!("{parent}/{}", self.path)
+        };
+        self
+    }
+}
+
+impl fmt::Display for XmlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.path.is_empty() {
+            write!(f, "{}: ", self.path)?;
+        }
+        match &self.kind {
+            XmlErrorKind::Syntax(msg) => write!(f, "{msg}"),
+            XmlErrorKind::Missing(name) => write!(f, "missing element {name}"),
+            XmlErrorKind::Unexpected(name) => write!(f, "unexpected element {name}"),
+            XmlErrorKind::UnknownCode(value) => write!(f, "unknown code {value:?}"),
+        }
+    }
+}
+
+impl std::error::Error for XmlError {}
+
+/// Appends the element `name` with the escaped `text`.
+pub(crate) fn write_text(out: &mut String, name: &str, text: &str) {
+    out.push('<');
+    out.push_str(name);
+    out.push('>');
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            c => out.push(c),
+        }
+    }
+    out.push_str("</");
+    out.push_str(name);
+    out.push('>');
+}
+
+/// Appends the empty element `name`, which stands for an absent value.
+pub(crate) fn write_empty(out: &mut String, name: &str) {
+    out.push('<');
+    out.push_str(name);
+    out.push_str("/>");
+}
+
+/// Reads a code list value.
+pub(crate) fn code<T: FromStr>(element: &Element) -> Result<T, XmlError> {
+    T::from_str(&element.text).map_err(|_| XmlError {
+        path: String::new(),
+        kind: XmlErrorKind::UnknownCode(element.text.clone()),
+    })
+}
+
+/// The child elements, taken in the order of the fields.
+pub(crate) struct Children<'a> {
+    element: &'a Element,
+    position: usize,
+}
+
+impl<'a> Children<'a> {
+    pub(crate) fn new(element: &'a Element) -> Self {
+        Children {
+            element,
+            position: 0,
+        }
+    }
+
+    /// Takes the next child if it is named `name`.
+    pub(crate) fn next(&mut self, name: &str) -> Option<&'a Element> {
+        let child = self
+            .element
+            .children
+            .get(self.position)
+            .filter(|c| c.name == name)?;
+        self.position += 1;
+        Some(child)
+    }
+
+    /// Takes the next child, which has to be named `name`.
+    pub(crate) fn required(&mut self, name: &str) -> Result<&'a Element, XmlError> {
+        self.next(name).ok_or_else(|| XmlError {
+            path: String::new(),
+            kind: XmlErrorKind::Missing(name.to_string()),
+        })
+    }
+
+    /// Fails if there are children which were not taken.
+    pub(crate) fn finish(self) -> Result<(), XmlError> {
+        match self.element.children.get(self.position) {
+            Some(child) => Err(XmlError {
+                path: String::new(),
+                kind: XmlErrorKind::Unexpected(child.name.clone()),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_element() {
+        let root = Element::parse(
+            "<?xml version=\"1.0\"?>\n<FTX>\n  <E4451>AAA</E4451>\n  <C108><E4440>a &amp; b</E4440></C108>\n</FTX>",
+        )
+        .unwrap();
+        assert_eq!(root.name, "FTX");
+        assert_eq!(root.children.len(), 2);
+        assert_eq!(root.children[0].text, "AAA");
+        assert_eq!(root.children[1].children[0].text, "a & b");
+    }
+
+    #[test]
+    fn escape_text() {
+        let mut out = String::new();
+        write_text(&mut out, "E4440", "<a & b>");
+        assert_eq!(out, "<E4440>&lt;a &amp; b&gt;</E4440>");
+        assert_eq!(Element::parse(&out).unwrap().text, "<a & b>");
+    }
+
+    #[test]
+    fn children_in_order() {
+        let root = Element::parse("<C080><E3036>A</E3036><E3036>B</E3036><E3045>1</E3045></C080>")
+            .unwrap();
+        let mut children = Children::new(&root);
+        assert_eq!(children.required("E3036").unwrap().text, "A");
+        assert_eq!(children.next("E3036").unwrap().text, "B");
+        assert!(children.next("E3036").is_none());
+        assert_eq!(
+            children.finish().unwrap_err().within("C080").to_string(),
+            "C080: unexpected element E3045"
+        );
+    }
+}